@@ -1,6 +1,7 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
+use oxc_diagnostics::ReporterRegistry;
 use oxc_linter::{AllowWarnDeny, FixKind};
 
 use super::{
@@ -37,6 +38,10 @@ pub struct LintCommand {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// print the JSON Schemas of the options of all rules
+    #[bpaf(long("print-schemas"), switch, hide_usage)]
+    pub print_schemas: bool,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -129,13 +134,18 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions.
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Print the fixes as a unified diff instead of applying them. Implies `--fix` when no
+    /// other fix option is given.
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
 }
 
 impl FixOptions {
     pub fn fix_kind(&self) -> FixKind {
         let mut kind = FixKind::None;
 
-        if self.fix {
+        if self.fix || (self.fix_dry_run && !self.fix_suggestions && !self.fix_dangerously) {
             kind.set(FixKind::SafeFix, true);
         }
 
@@ -154,7 +164,7 @@ impl FixOptions {
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix || self.fix_suggestions || self.fix_dangerously || self.fix_dry_run
     }
 }
 
@@ -179,32 +189,41 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format (default, json, unix, checkstyle, github)
-    #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
+    #[bpaf(long, short, fallback(OutputFormat::default()), hide_usage)]
     pub format: OutputFormat,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum OutputFormat {
-    Default,
-    /// GitHub Check Annotation
-    /// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-a-notice-message>
-    Github,
-    Json,
-    Unix,
-    Checkstyle,
+/// Name of a reporter in the [`ReporterRegistry`], e.g. `json`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutputFormat(String);
+
+impl OutputFormat {
+    /// The graphical output format.
+    const DEFAULT: &'static str = "default";
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::DEFAULT
+    }
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self(Self::DEFAULT.to_string())
+    }
 }
 
 impl FromStr for OutputFormat {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "json" => Ok(Self::Json),
-            "default" => Ok(Self::Default),
-            "unix" => Ok(Self::Unix),
-            "checkstyle" => Ok(Self::Checkstyle),
-            "github" => Ok(Self::Github),
-            _ => Err(format!("'{s}' is not a known format")),
+        if ReporterRegistry::default().contains(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(format!("'{s}' is not a known format"))
         }
     }
 }
@@ -300,9 +319,9 @@ mod warning_options {
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
-    use super::{lint_command, LintCommand, OutputFormat};
+    use super::{lint_command, LintCommand};
 
     fn get_lint_options(arg: &str) -> LintCommand {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -315,7 +334,7 @@ mod lint_options {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
-        assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert!(options.output_options.format.is_default());
     }
 
     #[test]
@@ -379,6 +398,16 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.fix_dry_run);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+
+        let options = get_lint_options("--fix-dry-run --fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -397,7 +426,7 @@ mod lint_options {
     #[test]
     fn format() {
         let options = get_lint_options("-f json");
-        assert_eq!(options.output_options.format, OutputFormat::Json);
+        assert_eq!(options.output_options.format.as_str(), "json");
         assert!(options.paths.is_empty());
     }
 
@@ -415,4 +444,17 @@ mod lint_options {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn print_schemas() {
+        let options = get_lint_options("--print-schemas");
+        assert!(options.print_schemas);
+    }
+
+    #[test]
+    fn silent() {
+        assert!(!get_lint_options(".").misc_options.silent);
+        assert!(get_lint_options("--silent .").misc_options.silent);
+        assert!(get_lint_options("--no-diagnostics .").misc_options.silent);
+    }
 }
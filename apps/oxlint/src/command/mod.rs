@@ -18,8 +18,8 @@ const VERSION: &str = match option_env!("OXC_VERSION") {
 /// Miscellaneous
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
-    /// Do not display any diagnostics
-    #[bpaf(switch, hide_usage)]
+    /// Do not display any diagnostics or summary, only set the exit code
+    #[bpaf(long("silent"), long("no-diagnostics"), switch, hide_usage)]
     pub silent: bool,
 
     /// Number of threads to use. Set to 1 for using only 1 CPU core
@@ -1,7 +1,7 @@
 use std::{env, io::BufWriter, time::Instant};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, ReporterRegistry};
 use oxc_linter::{
     partial_loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintFilter,
     LintService, LintServiceOptions, Linter, OxlintOptions,
@@ -10,8 +10,7 @@ use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
     cli::{
-        CliRunResult, LintCommand, LintResult, MiscOptions, OutputFormat, OutputOptions, Runner,
-        WarningOptions,
+        CliRunResult, LintCommand, LintResult, MiscOptions, OutputOptions, Runner, WarningOptions,
     },
     walk::{Extensions, Walk},
 };
@@ -34,6 +33,12 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if self.options.print_schemas {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            Linter::print_schemas(&mut stdout);
+            return CliRunResult::None;
+        }
+
         let LintCommand {
             paths,
             filter,
@@ -98,7 +103,8 @@ impl Runner for LintRunner {
         let number_of_files = paths.len();
 
         let cwd = std::env::current_dir().unwrap();
-        let mut options = LintServiceOptions::new(cwd, paths);
+        let mut options =
+            LintServiceOptions::new(cwd, paths).with_fix_dry_run(fix_options.fix_dry_run);
         let lint_options = OxlintOptions::default()
             .with_filter(filter)
             .with_config_path(basic_options.config)
@@ -155,17 +161,17 @@ impl Runner for LintRunner {
                 lint_service.run(&tx_error);
             }
         });
-        diagnostic_service.run();
+        let counts = diagnostic_service.run();
 
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
-            number_of_warnings: diagnostic_service.warnings_count(),
-            number_of_errors: diagnostic_service.errors_count(),
-            max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+            number_of_warnings: counts.warnings,
+            number_of_errors: counts.errors,
+            max_warnings_exceeded: counts.max_warnings_exceeded,
             deny_warnings: warning_options.deny_warnings,
-            print_summary: matches!(output_options.format, OutputFormat::Default),
+            print_summary: output_options.format.is_default() && !misc_options.silent,
         })
     }
 }
@@ -179,14 +185,12 @@ impl LintRunner {
         let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
             .with_silent(misc_options.silent)
-            .with_max_warnings(warning_options.max_warnings);
-
-        match output_options.format {
-            OutputFormat::Default => {}
-            OutputFormat::Json => diagnostic_service.set_json_reporter(),
-            OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
-            OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
-            OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            .with_max_warnings(warning_options.max_warnings)
+            .with_ordered_output(true);
+
+        // The format is validated against the registry when parsing arguments.
+        if let Some(reporter) = ReporterRegistry::default().get(output_options.format.as_str()) {
+            diagnostic_service.set_reporter(reporter);
         }
         diagnostic_service
     }
@@ -365,6 +369,36 @@ mod test {
         assert_eq!(result.number_of_errors, 1);
     }
 
+    #[test]
+    fn silent() {
+        let args = &["-D", "correctness", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert!(!result.print_summary);
+        assert_eq!(result.number_of_errors, 1);
+        assert!(result.is_failure());
+
+        let args = &["fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert!(!result.print_summary);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(!result.is_failure());
+    }
+
+    #[test]
+    fn max_warnings() {
+        let args = &["--max-warnings", "1", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+        assert!(result.max_warnings_exceeded);
+        assert!(result.is_failure());
+
+        let args = &["--max-warnings", "2", "fixtures/linter"];
+        let result = test(args);
+        assert!(!result.max_warnings_exceeded);
+        assert!(!result.is_failure());
+    }
+
     #[test]
     fn eslintrc_error() {
         let args = &["-c", "fixtures/linter/eslintrc.json", "fixtures/linter/debugger.js"];
@@ -26,6 +26,16 @@ pub struct LintResult {
     pub print_summary: bool,
 }
 
+impl LintResult {
+    /// Whether the process should exit with an error status: there are errors, warnings with
+    /// `--deny-warnings`, or more warnings than allowed by `--max-warnings`.
+    pub fn is_failure(&self) -> bool {
+        self.max_warnings_exceeded
+            || (self.number_of_warnings > 0 && self.deny_warnings)
+            || self.number_of_errors > 0
+    }
+}
+
 #[derive(Debug)]
 pub struct FormatResult {
     pub duration: Duration,
@@ -45,16 +55,18 @@ impl Termination for CliRunResult {
                 println!("Path {paths:?} does not exist.");
                 ExitCode::from(1)
             }
-            Self::LintResult(LintResult {
-                duration,
-                number_of_rules,
-                number_of_files,
-                number_of_warnings,
-                number_of_errors,
-                max_warnings_exceeded,
-                deny_warnings,
-                print_summary,
-            }) => {
+            Self::LintResult(lint_result) => {
+                let is_failure = lint_result.is_failure();
+                let LintResult {
+                    duration,
+                    number_of_rules,
+                    number_of_files,
+                    number_of_warnings,
+                    number_of_errors,
+                    max_warnings_exceeded,
+                    print_summary,
+                    ..
+                } = lint_result;
                 if print_summary {
                     let threads = rayon::current_num_threads();
                     let number_of_diagnostics = number_of_warnings + number_of_errors;
@@ -83,9 +95,7 @@ impl Termination for CliRunResult {
                     );
                 }
 
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
-                ExitCode::from(exit_code)
+                ExitCode::from(u8::from(is_failure))
             }
             Self::FormatResult(FormatResult { duration, number_of_files }) => {
                 let threads = rayon::current_num_threads();
@@ -1057,7 +1057,18 @@ impl<'a> GenExpr for Expression<'a> {
 
 impl<'a> GenExpr for ParenthesizedExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        self.expression.gen_expr(p, precedence, ctx);
+        if p.options.preserve_parens {
+            p.add_source_mapping(self.span.start);
+            p.print_char(b'(');
+            self.expression.gen_expr(
+                p,
+                Precedence::Lowest,
+                ctx.and_forbid_in(false).and_forbid_call(false),
+            );
+            p.print_char(b')');
+        } else {
+            self.expression.gen_expr(p, precedence, ctx);
+        }
     }
 }
 
@@ -2115,7 +2126,9 @@ impl<'a> GenExpr for TSSatisfiesExpression<'a> {
 
 impl<'a> GenExpr for TSNonNullExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.wrap(matches!(self.expression, Expression::ParenthesizedExpression(_)), |p| {
+        let wrap = !p.options.preserve_parens
+            && matches!(self.expression, Expression::ParenthesizedExpression(_));
+        p.wrap(wrap, |p| {
             self.expression.gen_expr(p, precedence, ctx);
         });
         p.print_char(b'!');
@@ -2424,8 +2437,9 @@ impl<'a> Gen for JSXText<'a> {
 
 impl<'a> Gen for JSXSpreadChild<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_str("...");
+        p.print_str("{...");
         p.print_expression(&self.expression);
+        p.print_char(b'}');
     }
 }
 
@@ -2434,7 +2448,7 @@ impl<'a> Gen for JSXChild<'a> {
         match self {
             Self::Fragment(fragment) => fragment.gen(p, ctx),
             Self::Element(el) => el.gen(p, ctx),
-            Self::Spread(spread) => p.print_expression(&spread.expression),
+            Self::Spread(spread) => spread.gen(p, ctx),
             Self::ExpressionContainer(expr_container) => expr_container.gen(p, ctx),
             Self::Text(text) => text.gen(p, ctx),
         }
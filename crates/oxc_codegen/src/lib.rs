@@ -45,6 +45,12 @@ pub struct CodegenOptions {
 
     /// Remove whitespace.
     pub minify: bool,
+
+    /// Print `ParenthesizedExpression`s with their parentheses, reproducing the grouping of the
+    /// source code. Needs an AST parsed with `preserve_parens`.
+    ///
+    /// By default, parentheses are only printed where precedence requires them.
+    pub preserve_parens: bool,
 }
 
 #[derive(Default, Clone, Copy)]
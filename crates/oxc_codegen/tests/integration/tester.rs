@@ -1,6 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_codegen::{CodeGenerator, CodegenOptions, CommentOptions};
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
 pub fn test(source_text: &str, expected: &str) {
@@ -45,3 +45,19 @@ pub fn test_minify(source_text: &str, expected: &str) {
         "\nfor minify source {source_text}\nexpect {expected}\ngot    {result:?}"
     );
 }
+
+pub fn test_preserve_parens(source_text: &str, expected: &str) {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions { preserve_parens: true, ..ParseOptions::default() })
+        .parse();
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { preserve_parens: true, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(
+        result, expected,
+        "\nfor preserve_parens source {source_text:?}\nexpect {expected:?}\ngot    {result:?}"
+    );
+}
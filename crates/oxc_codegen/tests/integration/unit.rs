@@ -1,4 +1,4 @@
-use crate::tester::{test, test_minify, test_without_source};
+use crate::tester::{test, test_minify, test_preserve_parens, test_without_source};
 
 #[test]
 fn module_decl() {
@@ -254,3 +254,39 @@ fn equality() {
     test_minify("a, b == c , d", "a,b==c,d;");
     test_minify("(a, b) == (c , d)", "(a,b)==(c,d);");
 }
+
+#[test]
+fn preserve_parens() {
+    test_preserve_parens("(a + b) * c;", "(a + b) * c;\n");
+    test_preserve_parens("((a));", "((a));\n");
+    test_preserve_parens("(a * b) + c;", "(a * b) + c;\n");
+    test_preserve_parens("a = (b, c);", "a = (b, c);\n");
+    test_preserve_parens("(function() {})();", "(function() {})();\n");
+    test_preserve_parens("({}).toString();", "({}).toString();\n");
+    test_preserve_parens("new (foo())();", "new (foo())();\n");
+    test_preserve_parens("for ((a in b);;) {}", "for ((a in b);;) {}\n");
+    // Without the option, only necessary parentheses are printed.
+    test("((a + b)) * (c);", "(a + b) * c;\n");
+    test("(a * b) + c;", "a * b + c;\n");
+}
+
+#[test]
+fn jsx() {
+    test("<a>{...b}</a>", "<a>{...b}</a>;\n");
+    test("<a {...b} c='d'>{...e}</a>", "<a {...b} c=\"d\">{...e}</a>;\n");
+    test_minify("<a {...b}>{...c}</a>", "<a{...b}>{...c}</a>;");
+    test(
+        "function App({ items, ...props }) {
+	return <ul className=\"list\" {...props}>
+		{items.map((item) => <li key={item.id}>{item.name}</li>)}
+		<Empty />
+	</ul>;
+}",
+        "function App({ items, ...props }) {
+	return <ul className=\"list\" {...props}>
+		{items.map((item) => <li key={item.id}>{item.name}</li>)}
+		<Empty />
+	</ul>;
+}\n",
+    );
+}
@@ -14,7 +14,7 @@ use miette::{
 use owo_colors::{OwoColorize, Style};
 use unicode_width::UnicodeWidthChar;
 
-use crate::graphical_theme::GraphicalTheme;
+use crate::{graphical_theme::GraphicalTheme, CodeFrameOptions};
 
 #[derive(Debug, Clone)]
 pub struct GraphicalReportHandler {
@@ -24,6 +24,7 @@ pub struct GraphicalReportHandler {
     pub(crate) footer: Option<String>,
     pub(crate) context_lines: usize,
     pub(crate) tab_width: usize,
+    pub(crate) labels_at_end_of_file: bool,
     pub(crate) with_cause_chain: bool,
     pub(crate) wrap_lines: bool,
     pub(crate) break_words: bool,
@@ -51,6 +52,7 @@ impl GraphicalReportHandler {
             footer: None,
             context_lines: 1,
             tab_width: 4,
+            labels_at_end_of_file: false,
             with_cause_chain: false,
             wrap_lines: true,
             break_words: true,
@@ -148,6 +150,19 @@ impl GraphicalReportHandler {
         self
     }
 
+    /// Apply the context lines, colors and tab width of `options`.
+    pub fn with_code_frame_options(mut self, options: CodeFrameOptions) -> Self {
+        self.context_lines = options.context_lines;
+        self.tab_width = options.tab_width;
+        self.labels_at_end_of_file = options.labels_at_end_of_file;
+        self.theme = match options.color {
+            Some(true) => GraphicalTheme::unicode(),
+            Some(false) => GraphicalTheme::unicode_nocolor(),
+            None => GraphicalTheme::default(),
+        };
+        self
+    }
+
     // /// Enable syntax highlighting for source code snippets, using the given
     // /// [`Highlighter`]. See the [crate::highlighters] crate for more details.
     // pub fn with_syntax_highlighting(
@@ -417,7 +432,7 @@ impl GraphicalReportHandler {
         };
 
         let mut labels = labels.collect::<Vec<_>>();
-        labels.sort_unstable_by_key(|l| l.inner().offset());
+        labels.sort_by_key(|l| l.inner().offset());
 
         let mut contexts = Vec::with_capacity(labels.len());
         for right in labels.iter().cloned() {
@@ -1131,9 +1146,11 @@ impl GraphicalReportHandler {
         source: &'a dyn SourceCode,
         context_span: &'a SourceSpan,
     ) -> Result<(Box<dyn SpanContents<'a> + 'a>, Vec<Line>), fmt::Error> {
-        let context_data = source
-            .read_span(context_span, self.context_lines, self.context_lines)
-            .map_err(|_| fmt::Error)?;
+        // CHANGED: without context lines, `read_span` only returns the text of the span itself.
+        // Read one line of context instead and drop it below, so that whole lines are rendered.
+        let context_lines = self.context_lines.max(1);
+        let context_data =
+            source.read_span(context_span, context_lines, context_lines).map_err(|_| fmt::Error)?;
         let context = std::str::from_utf8(context_data.data()).expect("Bad utf8 detected");
         let mut line = context_data.line();
         let mut column = context_data.column();
@@ -1178,11 +1195,35 @@ impl GraphicalReportHandler {
                     offset: line_offset,
                     length: offset - line_offset,
                     text: line_str.clone(),
+                    at_end_of_file: self.labels_at_end_of_file && iter.peek().is_none(),
                 });
                 line_str.clear();
                 line_offset = offset;
             }
         }
+        // CHANGED: render an empty line for empty sources, so that labels on them are shown.
+        if self.labels_at_end_of_file && lines.is_empty() {
+            lines.push(Line {
+                line_number: context_data.line() + 1,
+                offset,
+                length: 0,
+                text: String::new(),
+                at_end_of_file: true,
+            });
+        }
+        if self.context_lines == 0 {
+            let start = context_span.offset();
+            let end = start + context_span.len();
+            let last = lines.len().saturating_sub(1);
+            let mut index = 0;
+            lines.retain(|line| {
+                let line_end = line.offset + line.length;
+                let applies = line.offset < end.max(start + 1)
+                    && (start < line_end || (start == line_end && index == last));
+                index += 1;
+                applies
+            });
+        }
         Ok((context_data, lines))
     }
 }
@@ -1217,6 +1258,8 @@ struct Line {
     offset: usize,
     length: usize,
     text: String,
+    /// Whether this line ends at the end of the file and labels after it are rendered.
+    at_end_of_file: bool,
 }
 
 impl Line {
@@ -1224,13 +1267,20 @@ impl Line {
         span.offset() >= self.offset && span.offset() + span.len() <= self.offset + self.length
     }
 
+    // CHANGED: a zero-width span at the end of the file, e.g. a missing `}`, is rendered after
+    // the end of the last line.
+    fn span_at_end(&self, span: &FancySpan) -> bool {
+        self.at_end_of_file && span.len() == 0 && span.offset() == self.offset + self.length
+    }
+
     /// Returns whether `span` should be visible on this line, either in the gutter or under the
     /// text on this line
     fn span_applies(&self, span: &FancySpan) -> bool {
         let spanlen = if span.len() == 0 { 1 } else { span.len() };
         // Span starts in this line
 
-        (span.offset() >= self.offset && span.offset() < self.offset + self.length)
+        self.span_at_end(span)
+            || (span.offset() >= self.offset && span.offset() < self.offset + self.length)
             // Span passes through this line
             || (span.offset() < self.offset && span.offset() + spanlen > self.offset + self.length) //todo
             // Span ends on this line
@@ -1240,6 +1290,10 @@ impl Line {
     /// Returns whether `span` should be visible on this line in the gutter (so this excludes spans
     /// that are only visible on this line and do not span multiple lines)
     fn span_applies_gutter(&self, span: &FancySpan) -> bool {
+        // CHANGED: a zero-width span at the end of the file is rendered below the last line.
+        if self.span_at_end(span) {
+            return false;
+        }
         let spanlen = if span.len() == 0 { 1 } else { span.len() };
         // Span starts in this line
         self.span_applies(span)
@@ -15,7 +15,10 @@ use std::{
 pub use crate::{
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
-    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+    reporter::{
+        CodeFrameOptions, DiagnosticReporter, GraphicalReporter, ReporterFactory, ReporterRegistry,
+    },
+    service::{DiagnosticCounts, DiagnosticSender, DiagnosticService, DiagnosticTuple},
 };
 
 pub type Error = miette::Error;
@@ -23,7 +26,7 @@ pub type Severity = miette::Severity;
 
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
-use miette::{Diagnostic, SourceCode};
+use miette::{Diagnostic, SourceCode, SourceSpan};
 pub use miette::{LabeledSpan, NamedSource};
 
 #[derive(Debug, Clone)]
@@ -218,6 +221,16 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add a secondary label pointing at a related location, e.g. the original declaration of a
+    /// redeclared variable. Labels are rendered in source order.
+    pub fn add_related_label<S: Into<SourceSpan>, M: Into<String>>(
+        self,
+        span: S,
+        message: M,
+    ) -> Self {
+        self.and_label(LabeledSpan::new_with_span(Some(message.into()), span))
+    }
+
     pub fn with_url<S: Into<Cow<'static, str>>>(mut self, url: S) -> Self {
         self.inner.url = Some(url.into());
         self
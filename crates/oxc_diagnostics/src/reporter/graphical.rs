@@ -3,6 +3,27 @@ use std::io::{BufWriter, ErrorKind, Stdout, Write};
 use super::{writer, DiagnosticReporter};
 use crate::{Error, GraphicalReportHandler};
 
+/// How the [`GraphicalReporter`] renders the source code around the labels of a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeFrameOptions {
+    /// Number of lines shown above and below the labeled lines.
+    pub context_lines: usize,
+    /// Whether to emit ANSI colors. `None` emits them if stdout and stderr are terminals and
+    /// `NO_COLOR` is not set.
+    pub color: Option<bool>,
+    /// Number of columns a tab advances to the next multiple of.
+    pub tab_width: usize,
+    /// Whether to render zero-width labels at the end of the file, e.g. a missing `}`, and
+    /// labels in empty files. These are dropped by default.
+    pub labels_at_end_of_file: bool,
+}
+
+impl Default for CodeFrameOptions {
+    fn default() -> Self {
+        Self { context_lines: 1, color: None, tab_width: 4, labels_at_end_of_file: false }
+    }
+}
+
 pub struct GraphicalReporter {
     handler: GraphicalReportHandler,
     writer: BufWriter<Stdout>,
@@ -14,6 +35,15 @@ impl Default for GraphicalReporter {
     }
 }
 
+impl GraphicalReporter {
+    pub fn new(options: CodeFrameOptions) -> Self {
+        Self {
+            handler: GraphicalReportHandler::new().with_code_frame_options(options),
+            writer: writer(),
+        }
+    }
+}
+
 impl DiagnosticReporter for GraphicalReporter {
     fn finish(&mut self) {
         self.writer
@@ -49,3 +79,86 @@ impl DiagnosticReporter for GraphicalReporter {
         Some(output)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::CodeFrameOptions;
+    use crate::{GraphicalReportHandler, LabeledSpan, NamedSource, OxcDiagnostic};
+
+    fn render(options: CodeFrameOptions, source_text: &str, diagnostic: OxcDiagnostic) -> String {
+        let handler = GraphicalReportHandler::new().with_code_frame_options(options);
+        let error =
+            diagnostic.with_source_code(NamedSource::new("test.js", source_text.to_string()));
+        let mut output = String::new();
+        handler.render_report(&mut output, error.as_ref()).unwrap();
+        output
+    }
+
+    fn no_color() -> CodeFrameOptions {
+        CodeFrameOptions { color: Some(false), ..CodeFrameOptions::default() }
+    }
+
+    #[test]
+    fn context_lines() {
+        let source_text = "a;\nb;\nc;\nd;\ne;";
+        let diagnostic =
+            || OxcDiagnostic::warn("Something").with_label(LabeledSpan::underline(6..7));
+
+        let output = render(no_color(), source_text, diagnostic());
+        assert!(output.contains(" 2 │ b;") && output.contains(" 4 │ d;"));
+        assert!(!output.contains("a;") && !output.contains("e;"));
+
+        let options = CodeFrameOptions { context_lines: 0, ..no_color() };
+        let output = render(options, source_text, diagnostic());
+        assert!(output.contains(" 3 │ c;"), "{output}");
+        assert!(!output.contains("b;") && !output.contains("d;"));
+    }
+
+    #[test]
+    fn color() {
+        let diagnostic =
+            || OxcDiagnostic::warn("Something").with_label(LabeledSpan::underline(0..1));
+        let options = CodeFrameOptions { color: Some(true), ..CodeFrameOptions::default() };
+        assert!(render(options, "a;", diagnostic()).contains('\x1b'));
+        assert!(!render(no_color(), "a;", diagnostic()).contains('\x1b'));
+    }
+
+    #[test]
+    fn tab_width() {
+        let diagnostic =
+            || OxcDiagnostic::warn("Something").with_label(LabeledSpan::underline(1..2));
+        let options = CodeFrameOptions { tab_width: 2, ..no_color() };
+        let output = render(options, "\ta;", diagnostic());
+        assert!(output.contains(" 1 │   a;\n"));
+        assert!(output.contains("   ·   ─\n"));
+    }
+
+    #[test]
+    fn end_of_file() {
+        // A label reaching the end of the file.
+        let output = render(
+            no_color(),
+            "a;\nfoo",
+            OxcDiagnostic::warn("Something").with_label(LabeledSpan::underline(3..6)),
+        );
+        assert!(output.contains(" 2 │ foo\n") && output.contains("   · ───\n"));
+
+        let at_end = CodeFrameOptions { labels_at_end_of_file: true, ..no_color() };
+        let label = |offset| LabeledSpan::new(Some("here".into()), offset, 0);
+
+        // A zero-width label at the end of the file, with and without a trailing newline.
+        for source_text in ["a;\nfoo", "a;\nfoo\n"] {
+            let diagnostic =
+                || OxcDiagnostic::warn("Something").with_label(label(source_text.len()));
+            assert!(!render(no_color(), source_text, diagnostic()).contains("here"));
+            let output = render(at_end, source_text, diagnostic());
+            assert!(output.contains("here"), "{output}");
+        }
+
+        // A zero-width label in an empty file.
+        let diagnostic = || OxcDiagnostic::warn("Something").with_label(label(0));
+        assert!(!render(no_color(), "", diagnostic()).contains("here"));
+        let output = render(at_end, "", diagnostic());
+        assert!(output.contains("here"), "{output}");
+    }
+}
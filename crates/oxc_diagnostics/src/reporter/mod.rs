@@ -7,8 +7,11 @@ mod unix;
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    graphical::{CodeFrameOptions, GraphicalReporter},
+    json::JsonReporter,
+    unix::UnixReporter,
 };
 use crate::{Error, Severity};
 
@@ -18,12 +21,75 @@ fn writer() -> BufWriter<Stdout> {
     BufWriter::new(std::io::stdout())
 }
 
+/// An output format for diagnostics, used by [`DiagnosticService`](crate::DiagnosticService).
+///
+/// Reporters are created by name through a [`ReporterRegistry`].
 pub trait DiagnosticReporter {
+    /// Called once after all diagnostics have been rendered, e.g. to print a summary and
+    /// flush output.
     fn finish(&mut self);
+
+    /// Output the rendered diagnostics of one file.
     fn render_diagnostics(&mut self, s: &[u8]);
+
+    /// Render a single diagnostic. Returning `None` skips it, e.g. for reporters that collect
+    /// all diagnostics and print them in [`DiagnosticReporter::finish`].
     fn render_error(&mut self, error: Error) -> Option<String>;
 }
 
+/// Creates a new reporter.
+pub type ReporterFactory = fn() -> Box<dyn DiagnosticReporter>;
+
+/// Reporters by name, e.g. `"json"` for the [`JsonReporter`].
+///
+/// The default registry contains all built-in reporters. Register more with
+/// [`ReporterRegistry::register`].
+#[derive(Clone)]
+pub struct ReporterRegistry {
+    reporters: Vec<(&'static str, ReporterFactory)>,
+}
+
+impl Default for ReporterRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register("default", || Box::<GraphicalReporter>::default());
+        registry.register("json", || Box::<JsonReporter>::default());
+        registry.register("unix", || Box::<UnixReporter>::default());
+        registry.register("checkstyle", || Box::<CheckstyleReporter>::default());
+        registry.register("github", || Box::<GithubReporter>::default());
+        registry
+    }
+}
+
+impl ReporterRegistry {
+    /// A registry without any reporters.
+    pub fn empty() -> Self {
+        Self { reporters: vec![] }
+    }
+
+    /// Register a reporter under `name`, replacing any reporter already registered under it.
+    pub fn register(&mut self, name: &'static str, factory: ReporterFactory) {
+        match self.reporters.iter_mut().find(|(existing, _)| *existing == name) {
+            Some(entry) => entry.1 = factory,
+            None => self.reporters.push((name, factory)),
+        }
+    }
+
+    /// Create the reporter registered under `name`.
+    pub fn get(&self, name: &str) -> Option<Box<dyn DiagnosticReporter>> {
+        self.reporters.iter().find(|(existing, _)| *existing == name).map(|(_, factory)| factory())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.reporters.iter().any(|(existing, _)| *existing == name)
+    }
+
+    /// Names of all registered reporters, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.reporters.iter().map(|(name, _)| *name)
+    }
+}
+
 struct Info {
     line: usize,
     column: usize,
@@ -66,3 +132,48 @@ impl Info {
         Self { line, column, filename, message, severity, rule_id }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{DiagnosticReporter, ReporterRegistry};
+    use crate::{Error, OxcDiagnostic};
+
+    #[derive(Default)]
+    struct MessageReporter;
+
+    impl DiagnosticReporter for MessageReporter {
+        fn finish(&mut self) {}
+
+        fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+        fn render_error(&mut self, error: Error) -> Option<String> {
+            Some(format!("message: {error}"))
+        }
+    }
+
+    #[test]
+    fn builtin_reporters() {
+        let registry = ReporterRegistry::default();
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            vec!["default", "json", "unix", "checkstyle", "github"]
+        );
+        assert!(registry.get("json").is_some());
+        assert!(registry.get("custom").is_none());
+    }
+
+    #[test]
+    fn custom_reporter() {
+        let mut registry = ReporterRegistry::default();
+        registry.register("custom", || Box::<MessageReporter>::default());
+        assert!(registry.contains("custom"));
+
+        let mut reporter = registry.get("custom").unwrap();
+        let rendered = reporter.render_error(Error::new(OxcDiagnostic::warn("Something")));
+        assert_eq!(rendered.as_deref(), Some("message: Something"));
+
+        // Registering again replaces the reporter.
+        registry.register("custom", || Box::<MessageReporter>::default());
+        assert_eq!(registry.names().filter(|name| *name == "custom").count(), 1);
+    }
+}
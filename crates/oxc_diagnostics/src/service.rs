@@ -5,10 +5,7 @@ use std::{
 };
 
 use crate::{
-    reporter::{
-        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
-    },
+    reporter::{DiagnosticReporter, GraphicalReporter},
     Error, NamedSource, OxcDiagnostic, Severity,
 };
 
@@ -16,15 +13,30 @@ pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// The number of diagnostics received by a [`DiagnosticService`], by severity.
+///
+/// Diagnostics are counted whether or not they are displayed, e.g. warnings are counted with
+/// `--quiet`, so that the caller can decide the exit status.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCounts {
+    pub warnings: usize,
+    pub errors: usize,
+    /// More warnings were received than allowed by [`DiagnosticService::with_max_warnings`].
+    pub max_warnings_exceeded: bool,
+}
+
 pub struct DiagnosticService {
     reporter: Box<dyn DiagnosticReporter>,
 
     /// Disable reporting on warnings, only errors are reported
     quiet: bool,
 
-    /// Do not display any diagnostics
+    /// Do not display any diagnostics, only count them
     silent: bool,
 
+    /// Buffer received diagnostics and output them sorted by path once all files are done
+    ordered: bool,
+
     /// Specify a warning threshold,
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
@@ -46,6 +58,7 @@ impl Default for DiagnosticService {
             reporter: Box::<GraphicalReporter>::default(),
             quiet: false,
             silent: false,
+            ordered: false,
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
@@ -56,20 +69,9 @@ impl Default for DiagnosticService {
 }
 
 impl DiagnosticService {
-    pub fn set_json_reporter(&mut self) {
-        self.reporter = Box::<JsonReporter>::default();
-    }
-
-    pub fn set_unix_reporter(&mut self) {
-        self.reporter = Box::<UnixReporter>::default();
-    }
-
-    pub fn set_checkstyle_reporter(&mut self) {
-        self.reporter = Box::<CheckstyleReporter>::default();
-    }
-
-    pub fn set_github_reporter(&mut self) {
-        self.reporter = Box::<GithubReporter>::default();
+    /// Use `reporter` to output diagnostics, instead of the graphical reporter.
+    pub fn set_reporter(&mut self, reporter: Box<dyn DiagnosticReporter>) {
+        self.reporter = reporter;
     }
 
     #[must_use]
@@ -84,6 +86,15 @@ impl DiagnosticService {
         self
     }
 
+    /// Output diagnostics sorted by path after all of them have been received, instead of
+    /// in the order files finish linting. This keeps the output stable across runs when
+    /// files are linted in parallel.
+    #[must_use]
+    pub fn with_ordered_output(mut self, yes: bool) -> Self {
+        self.ordered = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_max_warnings(mut self, max_warnings: Option<usize>) -> Self {
         self.max_warnings = max_warnings;
@@ -106,6 +117,14 @@ impl DiagnosticService {
         self.max_warnings.map_or(false, |max_warnings| self.warnings_count.get() > max_warnings)
     }
 
+    pub fn counts(&self) -> DiagnosticCounts {
+        DiagnosticCounts {
+            warnings: self.warnings_count(),
+            errors: self.errors_count(),
+            max_warnings_exceeded: self.max_warnings_exceeded(),
+        }
+    }
+
     pub fn wrap_diagnostics(
         path: &Path,
         source_text: &str,
@@ -119,54 +138,189 @@ impl DiagnosticService {
         (path.to_path_buf(), diagnostics)
     }
 
+    /// Output the diagnostics of all files, until `None` is received, and return how many
+    /// there were.
+    ///
     /// # Panics
     ///
     /// * When the writer fails to write
-    pub fn run(&mut self) {
-        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
-            for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
-                let is_warning = severity == Some(Severity::Warning);
-                let is_error = severity == Some(Severity::Error) || severity.is_none();
-                if is_warning || is_error {
-                    if is_warning {
-                        let warnings_count = self.warnings_count() + 1;
-                        self.warnings_count.set(warnings_count);
-                    }
-                    if is_error {
-                        let errors_count = self.errors_count() + 1;
-                        self.errors_count.set(errors_count);
-                    }
-                    // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
-                    // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
-                        continue;
-                    }
-                }
+    pub fn run(&mut self) -> DiagnosticCounts {
+        if self.ordered {
+            let mut received = vec![];
+            while let Ok(Some(tuple)) = self.receiver.recv() {
+                received.push(tuple);
+            }
+            // Stable sort keeps the diagnostics of multiple sources in one file in order.
+            received.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (path, diagnostics) in received {
+                self.render_file(&path, diagnostics);
+            }
+        } else {
+            while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+                self.render_file(&path, diagnostics);
+            }
+        }
 
-                if self.silent {
+        if !self.silent {
+            self.reporter.finish();
+        }
+        self.counts()
+    }
+
+    fn render_file(&mut self, path: &Path, diagnostics: Vec<Error>) {
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let severity = diagnostic.severity();
+            let is_warning = severity == Some(Severity::Warning);
+            let is_error = severity == Some(Severity::Error) || severity.is_none();
+            if is_warning || is_error {
+                if is_warning {
+                    let warnings_count = self.warnings_count() + 1;
+                    self.warnings_count.set(warnings_count);
+                }
+                if is_error {
+                    let errors_count = self.errors_count() + 1;
+                    self.errors_count.set(errors_count);
+                }
+                // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
+                // Note that it does not disable ALL diagnostics, only Warning diagnostics
+                else if self.quiet {
                     continue;
                 }
+            }
 
-                if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
-                    // Skip large output and print only once.
-                    // Setting to 1200 because graphical output may contain ansi escape codes and other decorations.
-                    if err_str.lines().any(|line| line.len() >= 1200) {
-                        let minified_diagnostic = Error::new(
-                            OxcDiagnostic::warn("File is too long to fit on the screen")
-                                .with_help(format!("{path:?} seems like a minified file")),
-                        );
-                        err_str = format!("{minified_diagnostic:?}");
-                        output = err_str;
-                        break;
-                    }
-                    output.push_str(&err_str);
+            if self.silent {
+                continue;
+            }
+
+            if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
+                // Skip large output and print only once.
+                // Setting to 1200 because graphical output may contain ansi escape codes and other decorations.
+                if err_str.lines().any(|line| line.len() >= 1200) {
+                    let minified_diagnostic = Error::new(
+                        OxcDiagnostic::warn("File is too long to fit on the screen")
+                            .with_help(format!("{path:?} seems like a minified file")),
+                    );
+                    err_str = format!("{minified_diagnostic:?}");
+                    output = err_str;
+                    break;
                 }
+                output.push_str(&err_str);
             }
+        }
+        if !self.silent {
             self.reporter.render_diagnostics(output.as_bytes());
         }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+
+    use super::{DiagnosticCounts, DiagnosticService};
+    use crate::{reporter::DiagnosticReporter, Error, OxcDiagnostic};
+
+    /// Records the rendered output of each file.
+    struct RecordingReporter(Arc<Mutex<Vec<String>>>);
+
+    impl DiagnosticReporter for RecordingReporter {
+        fn finish(&mut self) {}
+
+        fn render_diagnostics(&mut self, s: &[u8]) {
+            self.0.lock().unwrap().push(String::from_utf8_lossy(s).into_owned());
+        }
+
+        fn render_error(&mut self, error: Error) -> Option<String> {
+            Some(error.to_string())
+        }
+    }
+
+    /// Records the rendered output of each file, and `finish` once all files are done.
+    struct FinishingReporter(Arc<Mutex<Vec<String>>>);
+
+    impl DiagnosticReporter for FinishingReporter {
+        fn finish(&mut self) {
+            self.0.lock().unwrap().push("finish".to_string());
+        }
+
+        fn render_diagnostics(&mut self, s: &[u8]) {
+            self.0.lock().unwrap().push(String::from_utf8_lossy(s).into_owned());
+        }
+
+        fn render_error(&mut self, error: Error) -> Option<String> {
+            Some(error.to_string())
+        }
+    }
+
+    fn run(ordered: bool) -> Vec<String> {
+        let output = Arc::new(Mutex::new(vec![]));
+        let mut service = DiagnosticService::default().with_ordered_output(ordered);
+        service.set_reporter(Box::new(RecordingReporter(Arc::clone(&output))));
+        for name in ["b", "c", "a"] {
+            let diagnostics = vec![Error::new(OxcDiagnostic::warn(name))];
+            service.sender().send(Some((PathBuf::from(name), diagnostics))).unwrap();
+        }
+        service.sender().send(None).unwrap();
+        service.run();
+        assert_eq!(service.warnings_count(), 3);
+        drop(service);
+        Arc::try_unwrap(output).unwrap().into_inner().unwrap()
+    }
+
+    #[test]
+    fn ordered_output() {
+        assert_eq!(run(false), vec!["b", "c", "a"]);
+        assert_eq!(run(true), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn silent() {
+        let output = Arc::new(Mutex::new(vec![]));
+        let mut service = DiagnosticService::default().with_silent(true);
+        service.set_reporter(Box::new(FinishingReporter(Arc::clone(&output))));
+        let diagnostics = vec![
+            Error::new(OxcDiagnostic::warn("warning")),
+            Error::new(OxcDiagnostic::error("error")),
+        ];
+        service.sender().send(Some((PathBuf::from("a"), diagnostics))).unwrap();
+        service.sender().send(None).unwrap();
+        service.run();
+        assert_eq!(service.warnings_count(), 1);
+        assert_eq!(service.errors_count(), 1);
+        drop(service);
+        assert!(output.lock().unwrap().is_empty());
+    }
 
-        self.reporter.finish();
+    #[test]
+    fn counts() {
+        let run = |max_warnings| {
+            let mut service =
+                DiagnosticService::default().with_quiet(true).with_max_warnings(max_warnings);
+            service.set_reporter(Box::new(RecordingReporter(Arc::default())));
+            let diagnostics = vec![
+                Error::new(OxcDiagnostic::warn("a")),
+                Error::new(OxcDiagnostic::warn("b")),
+                Error::new(OxcDiagnostic::error("c")),
+            ];
+            service.sender().send(Some((PathBuf::from("a"), diagnostics))).unwrap();
+            service.sender().send(None).unwrap();
+            service.run()
+        };
+        assert_eq!(
+            run(None),
+            DiagnosticCounts { warnings: 2, errors: 1, max_warnings_exceeded: false }
+        );
+        assert_eq!(
+            run(Some(2)),
+            DiagnosticCounts { warnings: 2, errors: 1, max_warnings_exceeded: false }
+        );
+        assert_eq!(
+            run(Some(1)),
+            DiagnosticCounts { warnings: 2, errors: 1, max_warnings_exceeded: true }
+        );
     }
 }
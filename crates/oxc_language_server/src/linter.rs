@@ -17,7 +17,7 @@ use oxc_linter::{
 };
 use oxc_parser::{ParseOptions, Parser};
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
 use ropey::Rope;
 use tower_lsp::lsp_types::{
     self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
@@ -29,6 +29,7 @@ struct ErrorWithPosition {
     pub end_pos: Position,
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
 }
 
@@ -44,6 +45,7 @@ impl ErrorWithPosition {
         error: Error,
         text: &str,
         fixed_content: Option<FixedContent>,
+        suggestions: Vec<SuggestedContent>,
         start: usize,
     ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
@@ -64,7 +66,7 @@ impl ErrorWithPosition {
         let start_pos = labels_with_pos[0].start_pos;
         let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content, suggestions }
     }
 
     fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
@@ -131,6 +133,7 @@ impl ErrorWithPosition {
         DiagnosticReport {
             diagnostic: self.to_lsp_diagnostic(path),
             fixed_content: self.fixed_content,
+            suggestions: self.suggestions,
         }
     }
 }
@@ -139,11 +142,13 @@ impl ErrorWithPosition {
 pub struct DiagnosticReport {
     pub diagnostic: lsp_types::Diagnostic,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
 }
 #[derive(Debug)]
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
 }
 
 #[derive(Debug, Clone)]
@@ -152,6 +157,13 @@ pub struct FixedContent {
     pub range: Range,
 }
 
+/// A fix the user may opt into, offered as a code action titled `message`.
+#[derive(Debug, Clone)]
+pub struct SuggestedContent {
+    pub message: String,
+    pub fixed_content: FixedContent,
+}
+
 pub struct IsolatedLintHandler {
     linter: Arc<Linter>,
 }
@@ -201,6 +213,7 @@ impl IsolatedLintHandler {
                                 data: None,
                             },
                             fixed_content: None,
+                            suggestions: vec![],
                         });
                     }
                 }
@@ -283,6 +296,7 @@ impl IsolatedLintHandler {
                     .map(|diagnostic| ErrorReport {
                         error: Error::from(diagnostic),
                         fixed_content: None,
+                        suggestions: vec![],
                     })
                     .collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
@@ -302,6 +316,7 @@ impl IsolatedLintHandler {
                     .map(|diagnostic| ErrorReport {
                         error: Error::from(diagnostic),
                         fixed_content: None,
+                        suggestions: vec![],
                     })
                     .collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
@@ -309,26 +324,33 @@ impl IsolatedLintHandler {
 
             let result = linter.run(path, Rc::new(semantic_ret.semantic));
 
+            let to_fixed_content = |code: &str, span: Span| FixedContent {
+                code: code.to_string(),
+                range: Range {
+                    start: offset_to_position(span.start as usize + start, javascript_source_text)
+                        .unwrap_or_default(),
+                    end: offset_to_position(span.end as usize + start, javascript_source_text)
+                        .unwrap_or_default(),
+                },
+            };
             let reports = result
                 .into_iter()
                 .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                        },
-                    });
-
-                    ErrorReport { error: Error::from(msg.error), fixed_content }
+                    let fixed_content =
+                        msg.fix.as_ref().map(|fix| to_fixed_content(&fix.content, fix.span));
+                    let suggestions = msg
+                        .suggestions
+                        .iter()
+                        .map(|suggestion| SuggestedContent {
+                            message: suggestion.message.to_string(),
+                            fixed_content: to_fixed_content(
+                                &suggestion.fix.content,
+                                suggestion.fix.span,
+                            ),
+                        })
+                        .collect();
+
+                    ErrorReport { error: Error::from(msg.error), fixed_content, suggestions }
                 })
                 .collect::<Vec<ErrorReport>>();
             let (_, errors_with_position) =
@@ -353,6 +375,7 @@ impl IsolatedLintHandler {
                     report.error.with_source_code(Arc::clone(&source)),
                     source_text,
                     report.fixed_content,
+                    report.suggestions,
                     start,
                 )
             })
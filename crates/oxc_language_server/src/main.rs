@@ -24,7 +24,7 @@ use tower_lsp::{
     Client, LanguageServer, LspService, Server,
 };
 
-use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::linter::{DiagnosticReport, FixedContent, ServerLinter};
 
 struct Backend {
     client: Client,
@@ -236,37 +236,47 @@ impl LanguageServer for Backend {
         let uri = params.text_document.uri;
 
         if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
+            if let Some(report) = value.iter().find(|r| {
+                r.diagnostic.range == params.range
+                    && (r.fixed_content.is_some() || !r.suggestions.is_empty())
+            }) {
+                let code_action =
+                    |title: String, is_preferred: bool, fixed_content: FixedContent| {
+                        CodeActionOrCommand::CodeAction(CodeAction {
+                            title,
+                            kind: Some(CodeActionKind::QUICKFIX),
+                            is_preferred: Some(is_preferred),
+                            edit: Some(WorkspaceEdit {
+                                changes: Some(HashMap::from([(
+                                    uri.clone(),
+                                    vec![TextEdit {
+                                        range: fixed_content.range,
+                                        new_text: fixed_content.code,
+                                    }],
+                                )])),
+                                ..WorkspaceEdit::default()
+                            }),
+                            disabled: None,
+                            data: None,
+                            diagnostics: None,
+                            command: None,
+                        })
+                    };
+
+                let mut code_actions = vec![];
+                if let Some(fixed_content) = report.fixed_content.clone() {
+                    let title = report.diagnostic.message.split(':').next().map_or_else(
                         || "Fix this problem".into(),
                         |s| format!("Fix this {s} problem"),
                     );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+                    code_actions.push(code_action(title, true, fixed_content));
+                }
+                // Suggestions are never preferred, the user has to pick one.
+                code_actions.extend(report.suggestions.iter().map(|suggestion| {
+                    code_action(suggestion.message.clone(), false, suggestion.fixed_content.clone())
+                }));
+
+                return Ok(Some(code_actions));
             }
         }
 
@@ -1,7 +1,16 @@
 use oxc_ast::{ast::BindingIdentifier, AstKind};
-use oxc_semantic::{AstNode, AstNodeId, IsGlobalReference, SymbolId};
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    EdgeType, ErrorEdgeKind, InstructionKind,
+};
+use oxc_index::Idx;
+use oxc_semantic::{AstNode, AstNodeId, IsGlobalReference, Reference, SymbolId};
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    precedence::{GetPrecedence, Precedence},
+};
+use rustc_hash::FxHashSet;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
@@ -280,6 +289,104 @@ pub fn get_symbol_id_of_variable(
     reference.symbol_id()
 }
 
+/// References that write to `symbol_id`: assignments such as `x = 1` or `[x] = arr`, compound
+/// assignments such as `x += 1`, and updates such as `x++`.
+pub fn get_write_references<'b>(
+    symbol_id: SymbolId,
+    ctx: &'b LintContext<'_>,
+) -> impl Iterator<Item = &'b Reference> + 'b {
+    ctx.semantic().symbol_references(symbol_id).filter(|reference| reference.is_write())
+}
+
+/// The variables written to by the assignment `expr` and by any assignments chained on its
+/// right, e.g. `a`, `b` and `c` in `a = [b, obj.x] = c = value`. Property targets such as
+/// `obj.x` and unresolved identifiers are skipped.
+pub fn assignment_targets(expr: &AssignmentExpression, ctx: &LintContext) -> Vec<SymbolId> {
+    let mut symbols = vec![];
+    let mut expr = expr;
+    loop {
+        symbols.extend(assignment_target_symbols(&expr.left, ctx));
+        let Expression::AssignmentExpression(right) = expr.right.without_parentheses() else {
+            break;
+        };
+        expr = right;
+    }
+    symbols
+}
+
+/// The variables written to by `target`, e.g. `a` and `b` in `[a, { b }, obj.c]`, also for
+/// targets that are not part of an assignment expression, such as the head of a `for-of` loop.
+pub fn assignment_target_symbols(target: &AssignmentTarget, ctx: &LintContext) -> Vec<SymbolId> {
+    let mut identifiers = vec![];
+    collect_assignment_targets(target, &mut identifiers);
+    identifiers.into_iter().filter_map(|ident| get_symbol_id_of_variable(ident, ctx)).collect()
+}
+
+/// The identifiers assigned to by `target`, including those in destructuring patterns, or
+/// `None` if it also assigns to something else, such as `obj.x` in `[a, obj.x] = arr`.
+pub fn assignment_target_identifiers<'b, 'a>(
+    target: &'b AssignmentTarget<'a>,
+) -> Option<Vec<&'b IdentifierReference<'a>>> {
+    let mut identifiers = vec![];
+    collect_assignment_targets(target, &mut identifiers).then_some(identifiers)
+}
+
+/// Push the identifiers assigned to by `target` to `identifiers`, and return whether `target`
+/// assigns to identifiers only.
+fn collect_assignment_targets<'b, 'a>(
+    target: &'b AssignmentTarget<'a>,
+    identifiers: &mut Vec<&'b IdentifierReference<'a>>,
+) -> bool {
+    fn collect_maybe_default<'b, 'a>(
+        target: &'b AssignmentTargetMaybeDefault<'a>,
+        identifiers: &mut Vec<&'b IdentifierReference<'a>>,
+    ) -> bool {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                collect_assignment_targets(&target.binding, identifiers)
+            }
+            _ => target
+                .as_assignment_target()
+                .is_some_and(|target| collect_assignment_targets(target, identifiers)),
+        }
+    }
+
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+            identifiers.push(ident);
+            true
+        }
+        AssignmentTarget::ArrayAssignmentTarget(array) => {
+            let mut only_identifiers = true;
+            for element in array.elements.iter().flatten() {
+                only_identifiers &= collect_maybe_default(element, identifiers);
+            }
+            if let Some(rest) = &array.rest {
+                only_identifiers &= collect_assignment_targets(&rest.target, identifiers);
+            }
+            only_identifiers
+        }
+        AssignmentTarget::ObjectAssignmentTarget(object) => {
+            let mut only_identifiers = true;
+            for property in &object.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        identifiers.push(&property.binding);
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        only_identifiers &= collect_maybe_default(&property.binding, identifiers);
+                    }
+                }
+            }
+            if let Some(rest) = &object.rest {
+                only_identifiers &= collect_assignment_targets(&rest.target, identifiers);
+            }
+            only_identifiers
+        }
+        _ => false,
+    }
+}
+
 pub fn extract_regex_flags<'a>(
     args: &'a oxc_allocator::Vec<'a, Argument<'a>>,
 ) -> Option<RegExpFlags> {
@@ -384,6 +491,18 @@ pub fn get_new_expr_ident_name<'a>(new_expr: &'a NewExpression<'a>) -> Option<&'
     Some(ident.name.as_str())
 }
 
+/// Whether `node` is a reference to the global variable `name`, i.e. an identifier named `name`
+/// that does not resolve to any binding in the file.
+///
+/// Unlike [`oxc_semantic::Semantic::is_reference_to_global_variable`], this checks the
+/// reference itself instead of whether any reference to `name` is unresolved.
+pub fn is_global_reference(node: &AstNode, name: &str, ctx: &LintContext) -> bool {
+    match node.kind() {
+        AstKind::IdentifierReference(ident) => ident.is_global_reference_name(name, ctx.symbols()),
+        _ => false,
+    }
+}
+
 pub fn is_global_require_call(call_expr: &CallExpression, ctx: &LintContext) -> bool {
     if call_expr.arguments.len() != 1 {
         return false;
@@ -409,3 +528,698 @@ pub fn get_function_like_declaration<'b>(
 
     decl.id.get_binding_identifier()
 }
+
+/// Get the source text of the logical negation of the condition `expr`.
+///
+/// Double negations are removed, equality operators are flipped, and `&&` / `||` are inverted
+/// using De Morgan's laws. Everything else is wrapped in `!`. Relational operators like `<` are
+/// never flipped, since e.g. `!(a < b)` and `a >= b` differ when either side is `NaN`.
+///
+/// The result is parenthesized so that it can be used wherever a short-circuit expression is
+/// expected, such as the test of an `if` statement or a conditional expression.
+///
+/// ```text
+/// !a          -> a
+/// a === b     -> a !== b
+/// a && !b     -> !a || b
+/// a || b && c -> !a && (!b || !c)
+/// a = b       -> !(a = b)
+/// ```
+pub fn invert_condition<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> String {
+    let (inverted, precedence) = invert_expression(expr, ctx);
+    if precedence <= Precedence::Conditional {
+        format!("({inverted})")
+    } else {
+        inverted
+    }
+}
+
+/// Returns the inverted source text and the precedence of its outermost operator.
+fn invert_expression<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> (String, Precedence) {
+    let expr = expr.without_parentheses();
+    match expr {
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+            let argument = unary.argument.without_parentheses();
+            (ctx.source_range(argument.span()).to_string(), expression_precedence(argument))
+        }
+        Expression::BinaryExpression(binary) if binary.operator.is_equality() => {
+            let operator = match binary.operator {
+                BinaryOperator::Equality => BinaryOperator::Inequality,
+                BinaryOperator::Inequality => BinaryOperator::Equality,
+                BinaryOperator::StrictEquality => BinaryOperator::StrictInequality,
+                _ => BinaryOperator::StrictEquality,
+            };
+            let inverted = format!(
+                "{} {} {}",
+                ctx.source_range(binary.left.span()),
+                operator.as_str(),
+                ctx.source_range(binary.right.span())
+            );
+            (inverted, Precedence::Equals)
+        }
+        Expression::LogicalExpression(logical) if logical.operator != LogicalOperator::Coalesce => {
+            let operator = match logical.operator {
+                LogicalOperator::And => LogicalOperator::Or,
+                _ => LogicalOperator::And,
+            };
+            let operand = |expr: &Expression<'a>| {
+                let (inverted, precedence) = invert_expression(expr, ctx);
+                if precedence < operator.precedence() {
+                    format!("({inverted})")
+                } else {
+                    inverted
+                }
+            };
+            let inverted = format!(
+                "{} {} {}",
+                operand(&logical.left),
+                operator.as_str(),
+                operand(&logical.right)
+            );
+            (inverted, operator.precedence())
+        }
+        _ => {
+            let source = ctx.source_range(expr.span());
+            let inverted = if expression_precedence(expr) < Precedence::Prefix {
+                format!("!({source})")
+            } else {
+                format!("!{source}")
+            };
+            (inverted, Precedence::Prefix)
+        }
+    }
+}
+
+/// Like [`GetPrecedence::precedence`], but also covers primary expressions and arrow functions.
+pub fn expression_precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_)
+        | match_member_expression!(Expression) => expr.precedence(),
+        Expression::ArrowFunctionExpression(_) => Precedence::Assign,
+        Expression::TSAsExpression(_) | Expression::TSSatisfiesExpression(_) => Precedence::Compare,
+        _ => Precedence::Member,
+    }
+}
+
+/// The value of `expr` if it is a boolean literal, ignoring parentheses, e.g. `false` for
+/// `(false)`.
+pub fn get_boolean_literal_value(expr: &Expression) -> Option<bool> {
+    match expr.without_parentheses() {
+        Expression::BooleanLiteral(literal) => Some(literal.value),
+        _ => None,
+    }
+}
+
+/// Flatten a left-associative chain of string concatenations, e.g. `"a" + b + "c"`, into its
+/// operands, e.g. `["a", b, "c"]`.
+///
+/// An addition only counts as a concatenation if one of its sides is a string literal, a
+/// template literal or a concatenation itself. Flattening stops at the first operand that is
+/// not, so `1 + 2 + "a"` flattens into `[1 + 2, "a"]`. Right operands are never flattened, since
+/// `"a" + (b + c)` evaluates `b + c` first. Anything that isn't a concatenation is returned as
+/// its only operand.
+pub fn flatten_string_concat<'a, 'b>(expr: &'b Expression<'a>) -> Vec<&'b Expression<'a>> {
+    let mut operands = vec![];
+    let mut current = expr;
+    while let Expression::BinaryExpression(binary) = current.without_parentheses() {
+        if !is_string_concatenation(binary) {
+            break;
+        }
+        operands.push(&binary.right);
+        current = &binary.left;
+    }
+    operands.push(current);
+    operands.reverse();
+    operands
+}
+
+fn is_string_concatenation(binary: &BinaryExpression) -> bool {
+    fn is_string_like(expr: &Expression) -> bool {
+        match expr.without_parentheses() {
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+            Expression::BinaryExpression(binary) => is_string_concatenation(binary),
+            _ => false,
+        }
+    }
+    binary.operator == BinaryOperator::Addition
+        && (is_string_like(&binary.left) || is_string_like(&binary.right))
+}
+
+/// A `return` statement of a function, see [`collect_returns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnInfo {
+    /// The `ReturnStatement` node.
+    pub node_id: AstNodeId,
+    pub span: Span,
+    /// Whether a value is returned, e.g. `return a;` but not `return;`.
+    pub has_argument: bool,
+    /// Whether the statement can be reached according to the control flow graph.
+    pub is_reachable: bool,
+    /// Whether no other statement of the function can run after this one, e.g. the return
+    /// statement at the end of the function body or of both branches of a trailing `if`.
+    /// Returns in loops, in non-final switch cases or in `try` blocks with a `finally` block are
+    /// never last.
+    pub is_last: bool,
+}
+
+/// Collect the return statements of `function_node`, a `Function` or `ArrowFunctionExpression`,
+/// in source order. Returns of nested functions are not included.
+pub fn collect_returns(function_node: &AstNode, ctx: &LintContext) -> Vec<ReturnInfo> {
+    let function_span = function_node.kind().span();
+    let cfg = ctx.cfg();
+    let mut returns = vec![];
+    // Nodes are created in pre-order, so the descendants of the function are the nodes right
+    // after it, up to the first node outside of its span.
+    let descendants = ctx
+        .nodes()
+        .iter()
+        .skip(function_node.id().index() + 1)
+        .take_while(|node| function_span.contains_inclusive(node.kind().span()));
+    let mut nested_function_end = None;
+    for node in descendants {
+        let span = node.kind().span();
+        if nested_function_end.is_some_and(|end| span.end <= end) {
+            continue;
+        }
+        match node.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                nested_function_end = Some(span.end);
+            }
+            AstKind::ReturnStatement(stmt) => returns.push(ReturnInfo {
+                node_id: node.id(),
+                span: stmt.span,
+                has_argument: stmt.argument.is_some(),
+                is_reachable: !cfg.basic_block(node.cfg_id()).unreachable,
+                is_last: is_in_tail_position(node, ctx),
+            }),
+            _ => {}
+        }
+    }
+    returns
+}
+
+/// Whether `node` is a statement after which no other statement of its function runs.
+fn is_in_tail_position(node: &AstNode, ctx: &LintContext) -> bool {
+    fn is_last_statement(statements: &[Statement], span: Span) -> bool {
+        statements.last().is_some_and(|stmt| stmt.span() == span)
+    }
+
+    let mut span = node.kind().span();
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::FunctionBody(body) => return is_last_statement(&body.statements, span),
+            AstKind::BlockStatement(block) | AstKind::FinallyClause(block)
+                if !is_last_statement(&block.body, span) =>
+            {
+                return false
+            }
+            AstKind::SwitchCase(case) if !is_last_statement(&case.consequent, span) => {
+                return false
+            }
+            // Without the return, control would fall through to the next case.
+            AstKind::SwitchStatement(switch)
+                if switch.cases.last().map(|case| case.span) != Some(span) =>
+            {
+                return false
+            }
+            AstKind::TryStatement(stmt) if stmt.block.span == span && stmt.finalizer.is_some() => {
+                return false
+            }
+            AstKind::BlockStatement(_)
+            | AstKind::FinallyClause(_)
+            | AstKind::SwitchCase(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::TryStatement(_)
+            | AstKind::CatchClause(_)
+            | AstKind::IfStatement(_)
+            | AstKind::LabeledStatement(_)
+            | AstKind::WithStatement(_) => {}
+            _ => return false,
+        }
+        span = parent.kind().span();
+    }
+    false
+}
+
+/// How a path leaves the block checked by [`block_always_completes_abruptly`].
+#[derive(Clone, Copy)]
+enum Exit {
+    /// A `return`, or a `break` or `continue` that jumps out of the block.
+    Jump,
+    Throw,
+}
+
+/// Whether control can never leave `block`, a statement, normally: every path through it ends
+/// in a `return` or `throw`, or in a `break` or `continue` that jumps out of it.
+///
+/// This walks the control flow graph from the start of `block`, so any statement can be
+/// checked, e.g. `{ if (a) { return; } else { throw b; } }` or
+/// `{ try { return a(); } catch { return b; } }`. Exceptions thrown by calls are not
+/// considered abrupt completions, but the `catch` clauses inside of `block` they may reach are
+/// checked as well.
+pub fn block_always_completes_abruptly(block: &AstNode, ctx: &LintContext) -> bool {
+    let span = block.kind().span();
+    let cfg = ctx.cfg();
+    let graph = cfg.graph();
+    let is_inside = |node_id: AstNodeId| span.contains_inclusive(ctx.nodes().kind(node_id).span());
+
+    // Basic blocks are visited along with whether they were entered through an error edge,
+    // which only leads to a normal completion if it is caught inside of `block`.
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![(block.cfg_id(), false)];
+    'blocks: while let Some((block_id, mut is_exceptional)) = stack.pop() {
+        if !visited.insert((block_id, is_exceptional)) {
+            continue;
+        }
+        let mut instructions = cfg
+            .basic_block(block_id)
+            .instructions()
+            .iter()
+            .filter_map(|it| it.node_id.map(|node_id| (&it.kind, node_id)))
+            .peekable();
+        if block_id == block.cfg_id() {
+            // The basic block `block` starts in also holds the statements before it.
+            while instructions.next_if(|(_, node_id)| !is_inside(*node_id)).is_some() {}
+        }
+        // How the basic block leaves `block`, if it does. Even then, the statements before it
+        // may throw to a `catch` clause inside of `block`.
+        let mut exit = None;
+        for (kind, node_id) in instructions {
+            if !is_inside(node_id) {
+                if is_exceptional {
+                    continue 'blocks;
+                }
+                return false;
+            }
+            is_exceptional = false;
+            exit = match kind {
+                InstructionKind::Return(_) => Some(Exit::Jump),
+                InstructionKind::Throw => Some(Exit::Throw),
+                InstructionKind::Break(_) | InstructionKind::Continue(_)
+                    if !is_jump_target_inside(node_id, span, ctx) =>
+                {
+                    Some(Exit::Jump)
+                }
+                _ => None,
+            };
+            if exit.is_some() {
+                break;
+            }
+        }
+
+        let mut has_successor = false;
+        for edge in graph.edges_directed(block_id, Direction::Outgoing) {
+            let is_exceptional = match (edge.weight(), exit) {
+                (EdgeType::Error(ErrorEdgeKind::Explicit), _)
+                | (EdgeType::Finalize, None | Some(Exit::Throw)) => true,
+                (EdgeType::Normal | EdgeType::Jump | EdgeType::Backedge, None) => is_exceptional,
+                // A `finally` block is left through a join edge only if the end of its `try`
+                // block is reachable.
+                (EdgeType::Join, None) => false,
+                (EdgeType::Unreachable, None) => {
+                    has_successor = true;
+                    continue;
+                }
+                (EdgeType::Error(ErrorEdgeKind::Implicit) | EdgeType::NewFunction, None)
+                | (_, Some(_)) => continue,
+            };
+            has_successor = true;
+            stack.push((edge.target(), is_exceptional));
+        }
+        // The end of the function is reached.
+        if !has_successor && !is_exceptional && exit.is_none() {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether the `break` or `continue` statement `node_id` jumps to a statement within `span`.
+pub fn is_jump_target_inside(node_id: AstNodeId, span: Span, ctx: &LintContext) -> bool {
+    let (label, is_break) = match ctx.nodes().kind(node_id) {
+        AstKind::BreakStatement(stmt) => (stmt.label.as_ref(), true),
+        AstKind::ContinueStatement(stmt) => (stmt.label.as_ref(), false),
+        _ => return false,
+    };
+    ctx.nodes()
+        .iter_parents(node_id)
+        .skip(1)
+        .take_while(|parent| span.contains_inclusive(parent.kind().span()))
+        .any(|parent| match (label, parent.kind()) {
+            (Some(label), AstKind::LabeledStatement(stmt)) => stmt.label.name == label.name,
+            (None, AstKind::SwitchStatement(_)) => is_break,
+            (None, kind) => kind.is_iteration_statement(),
+            _ => false,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use crate::LintContext;
+
+    fn with_context<R>(source_text: &str, f: impl FnOnce(&LintContext) -> R) -> R {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(parser_ret.program);
+        let semantic = SemanticBuilder::new(source_text).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Box::from(Path::new("foo.js")), Rc::new(semantic));
+        f(&ctx)
+    }
+
+    /// Run `is_global_reference` on every `name` reference in `source_text`.
+    fn global_references(source_text: &str, name: &str) -> Vec<bool> {
+        with_context(source_text, |ctx| {
+            ctx.nodes()
+                .iter()
+                .filter(|node| {
+                    matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == name)
+                })
+                .map(|node| super::is_global_reference(node, name, ctx))
+                .collect()
+        })
+    }
+
+    /// Run `invert_condition` on the test of `if (condition) {}`.
+    fn invert(condition: &str) -> String {
+        with_context(&format!("if ({condition}) {{}}"), |ctx| {
+            ctx.nodes()
+                .iter()
+                .find_map(|node| match node.kind() {
+                    AstKind::IfStatement(if_stmt) => {
+                        Some(super::invert_condition(&if_stmt.test, ctx))
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        })
+    }
+
+    /// Run `assignment_targets` on the outermost assignment in `source_text`, returning the
+    /// names of the variables.
+    fn targets(source_text: &str) -> Vec<String> {
+        with_context(source_text, |ctx| {
+            let expr = ctx
+                .nodes()
+                .iter()
+                .find_map(|node| match node.kind() {
+                    AstKind::AssignmentExpression(expr) => Some(expr),
+                    _ => None,
+                })
+                .unwrap();
+            super::assignment_targets(expr, ctx)
+                .into_iter()
+                .map(|symbol_id| ctx.symbols().get_name(symbol_id).to_string())
+                .collect()
+        })
+    }
+
+    /// Run `collect_returns` on the first function in `source_text`, returning
+    /// `(has_argument, is_reachable, is_last)` of each return.
+    fn returns(source_text: &str) -> Vec<(bool, bool, bool)> {
+        with_context(source_text, |ctx| {
+            let function = ctx
+                .nodes()
+                .iter()
+                .find(|node| {
+                    matches!(
+                        node.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                })
+                .unwrap();
+            super::collect_returns(function, ctx)
+                .into_iter()
+                .map(|info| (info.has_argument, info.is_reachable, info.is_last))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_is_global_reference() {
+        assert_eq!(global_references("Object.keys(a)", "Object"), vec![true]);
+        assert_eq!(global_references("function foo() { arguments }", "arguments"), vec![true]);
+        assert_eq!(global_references("foo(Object)", "foo"), vec![true]);
+        assert_eq!(global_references("undefined; Object", "undefined"), vec![true]);
+    }
+
+    #[test]
+    fn test_is_global_reference_shadowed() {
+        assert_eq!(global_references("var Object = {}; Object.keys(a)", "Object"), vec![false]);
+        assert_eq!(global_references("function foo(Object) { Object }", "Object"), vec![false]);
+        assert_eq!(
+            global_references("function foo() { var arguments; arguments }", "arguments"),
+            vec![false]
+        );
+        assert_eq!(global_references("import { Map } from 'foo'; Map", "Map"), vec![false]);
+        // Only the shadowed reference is local.
+        assert_eq!(
+            global_references("Object; function foo() { let Object; Object }", "Object"),
+            vec![true, false]
+        );
+    }
+
+    /// Run `flatten_string_concat` on the expression statement in `source_text`.
+    fn flatten(source_text: &str) -> Vec<String> {
+        with_context(source_text, |ctx| {
+            ctx.nodes()
+                .iter()
+                .find_map(|node| match node.kind() {
+                    AstKind::ExpressionStatement(stmt) => Some(
+                        super::flatten_string_concat(&stmt.expression)
+                            .into_iter()
+                            .map(|operand| ctx.source_range(operand.span()).to_string())
+                            .collect(),
+                    ),
+                    _ => None,
+                })
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_flatten_string_concat() {
+        assert_eq!(flatten(r#""a" + b + "c""#), vec![r#""a""#, "b", r#""c""#]);
+        assert_eq!(flatten("a + `b` + c + d"), vec!["a", "`b`", "c", "d"]);
+        assert_eq!(flatten("(a + 'b') + c"), vec!["a", "'b'", "c"]);
+        assert_eq!(flatten("a + ('b' + c)"), vec!["a", "('b' + c)"]);
+        assert_eq!(flatten("a"), vec!["a"]);
+        assert_eq!(flatten("a + b"), vec!["a + b"]);
+    }
+
+    #[test]
+    fn test_flatten_string_concat_stops_at_non_string() {
+        assert_eq!(flatten("1 + 2 + 'a'"), vec!["1 + 2", "'a'"]);
+        assert_eq!(flatten("a + b + 'c' + d"), vec!["a + b", "'c'", "d"]);
+        assert_eq!(flatten("a - 'b' + 'c'"), vec!["a - 'b'", "'c'"]);
+    }
+
+    #[test]
+    fn test_invert_condition() {
+        assert_eq!(invert("!a"), "a");
+        assert_eq!(invert("!!a"), "!a");
+        assert_eq!(invert("!(a, b)"), "(a, b)");
+        assert_eq!(invert("a === b"), "a !== b");
+        assert_eq!(invert("a != b"), "a == b");
+        assert_eq!(invert("a < b"), "!(a < b)");
+        assert_eq!(invert("a"), "!a");
+        assert_eq!(invert("a.b()"), "!a.b()");
+        assert_eq!(invert("(a = b)"), "!(a = b)");
+        assert_eq!(invert("!(a = b)"), "(a = b)");
+        assert_eq!(invert("!(a ? b : c)"), "(a ? b : c)");
+    }
+
+    #[test]
+    fn test_invert_condition_de_morgan() {
+        assert_eq!(invert("a && b"), "!a || !b");
+        assert_eq!(invert("a || b"), "!a && !b");
+        assert_eq!(invert("!a && b !== c"), "a || b === c");
+        assert_eq!(invert("a || b && c"), "!a && (!b || !c)");
+        assert_eq!(invert("(a || b) && c"), "!a && !b || !c");
+        assert_eq!(invert("a ?? b"), "!(a ?? b)");
+        assert_eq!(invert("a && !(b ?? c)"), "!a || (b ?? c)");
+    }
+
+    /// Run `block_always_completes_abruptly` on the first block statement in `source_text`.
+    fn completes_abruptly(source_text: &str) -> bool {
+        with_context(source_text, |ctx| {
+            let block = ctx
+                .nodes()
+                .iter()
+                .find(|node| matches!(node.kind(), AstKind::BlockStatement(_)))
+                .unwrap();
+            super::block_always_completes_abruptly(block, ctx)
+        })
+    }
+
+    #[test]
+    fn test_block_always_completes_abruptly() {
+        let always = [
+            "function foo() { { return; } }",
+            "function foo() { { foo(); throw bar; } baz(); }",
+            "function foo() { if (a) { if (b) { return 1; } else { throw c; } } }",
+            "function foo() { if (a) { if (b) return; else if (c) return; else throw d; } }",
+            "function foo() { if (a) { try { return b(); } catch { return c; } } }",
+            "function foo() { if (a) { try { throw b; } catch { return c; } } }",
+            "function foo() { if (a) { try { b(); } finally { return; } } }",
+            "function foo() { if (a) { switch (b) { case 1: return; default: throw c; } } }",
+            "while (a) { if (b) { break; } else { continue; } }",
+            "for (const a of b) { c(); continue; }",
+            "outer: while (a) { if (b) { break outer; } else { continue outer; } }",
+            "function foo() { if (a) { try { if (b) return 1; return 2; } finally { c(); } } }",
+            "function foo() { if (a) { return; foo(); } }",
+        ];
+        for source_text in always {
+            assert!(completes_abruptly(source_text), "{source_text}");
+        }
+
+        let sometimes = [
+            "function foo() { { foo(); } }",
+            "function foo() { { } return; }",
+            "function foo() { if (a) { if (b) { return; } } return; }",
+            "function foo() { if (a) { if (b) return; else c(); } }",
+            "function foo() { if (a) { try { throw b; } catch { c(); } } }",
+            "function foo() { if (a) { try { return b(); } catch { c(); } } }",
+            "function foo() { if (a) { try { b(); } finally { c(); } } }",
+            "function foo() { if (a) { switch (b) { case 1: return; default: break; } } }",
+            "function foo() { if (a) { while (b) { break; } } }",
+            "function foo() { if (a) { label: { break label; } } }",
+            "function foo() { if (a) { function bar() { return; } } }",
+            "while (a) { if (b) { continue; } }",
+        ];
+        for source_text in sometimes {
+            assert!(!completes_abruptly(source_text), "{source_text}");
+        }
+    }
+
+    #[test]
+    fn test_collect_returns() {
+        let source_text = "
+            function foo(a) {
+                if (a) {
+                    return 1;
+                } else if (b) {
+                    return;
+                }
+                while (a) {
+                    return 2;
+                }
+                switch (a) {
+                    case 0:
+                        return;
+                    default:
+                        foo();
+                }
+                try {
+                    if (b) return 3;
+                } finally {
+                    bar();
+                }
+                const f = () => { return 4; };
+                label: if (c) {
+                    return;
+                } else {
+                    try {
+                        return 5;
+                    } catch {
+                        bar();
+                    }
+                }
+                return 6;
+            }
+        ";
+        assert_eq!(
+            returns(source_text),
+            vec![
+                (true, true, false),
+                (false, true, false),
+                (true, true, false),
+                (false, true, false),
+                (true, true, false),
+                (false, true, false),
+                (true, true, false),
+                (true, true, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_returns_tail_position() {
+        assert_eq!(returns("function foo() { return; }"), vec![(false, true, true)]);
+        assert_eq!(
+            returns("function foo() { return 1; return 2; }"),
+            vec![(true, true, false), (true, false, true)]
+        );
+        assert_eq!(
+            returns("function foo() { if (a) { return 1; } else { return; } }"),
+            vec![(true, true, true), (false, true, true)]
+        );
+        assert_eq!(
+            returns("function foo() { switch (a) { case 0: foo(); default: return; } }"),
+            vec![(false, true, true)]
+        );
+        assert_eq!(
+            returns("function foo() { try { return 1; } catch { return 2; } }"),
+            vec![(true, true, true), (true, true, true)]
+        );
+        assert_eq!(
+            returns("function foo() { try {} finally { return; } }"),
+            vec![(false, true, true)]
+        );
+        assert_eq!(returns("() => { return () => { return 1; }; }"), vec![(true, true, true)]);
+        assert!(returns("() => a").is_empty());
+    }
+
+    #[test]
+    fn test_assignment_targets() {
+        assert_eq!(targets("let a; a = 1;"), vec!["a"]);
+        assert_eq!(targets("let a, b, c; a = b = (c = 1);"), vec!["a", "b", "c"]);
+        assert_eq!(targets("let a, b; a += b -= 1;"), vec!["a", "b"]);
+        assert_eq!(
+            targets("let a, b, c, d; ({ a, b: [c = 1], ...d } = obj);"),
+            vec!["a", "c", "d"]
+        );
+        assert_eq!(targets("let a, b; [a, , ...b] = arr;"), vec!["a", "b"]);
+        assert_eq!(targets("let a; [a, obj.b] = arr;"), vec!["a"]);
+        assert_eq!(targets("let a; obj.b = a = 1;"), vec!["a"]);
+        assert!(targets("obj.a = obj[b] = 1;").is_empty());
+        assert!(targets("undeclared = 1;").is_empty());
+    }
+
+    #[test]
+    fn test_assignment_target_identifiers() {
+        with_context("[a, { b }] = arr; [c, obj.d] = arr; obj.e = 1;", |ctx| {
+            let names = ctx
+                .nodes()
+                .iter()
+                .filter_map(|node| match node.kind() {
+                    AstKind::AssignmentExpression(expr) => {
+                        Some(super::assignment_target_identifiers(&expr.left).map(|identifiers| {
+                            identifiers.iter().map(|ident| ident.name.as_str()).collect::<Vec<_>>()
+                        }))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec![Some(vec!["a", "b"]), None, None]);
+        });
+    }
+}
@@ -0,0 +1,612 @@
+//! Incremental re-linting.
+//!
+//! A [`LintCache`] remembers the diagnostics (and fixes and suggestions) produced for each
+//! linted file, keyed by a hash of the file's contents. When the same file is
+//! linted again with an identical configuration, the cached diagnostics are
+//! returned instead of re-parsing and re-running every rule. This is mostly
+//! useful for watch mode and editor integrations.
+//!
+//! Every cache is tied to a configuration hash (see [`Linter::config_hash`]).
+//! Loading a cache that was written with a different configuration yields an
+//! empty cache.
+//!
+//! When only rules change, e.g. when a rule is turned on in watch mode,
+//! [`LintCache::reconfigure`] carries the cache over to the new configuration.
+//! Diagnostics of removed and reconfigured rules are dropped, and only the
+//! added and reconfigured rules run again on each file.
+//!
+//! [`Linter::config_hash`]: crate::Linter::config_hash
+use std::{
+    borrow::Cow,
+    fs,
+    hash::Hasher,
+    io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fixer::{Fix, Message, Suggestion},
+    Linter,
+};
+
+/// Cached lint results for a set of files.
+///
+/// The cache is safe to share between threads, so a single instance can be
+/// used by a [`LintService`](crate::LintService) linting files in parallel.
+#[derive(Debug, Default)]
+pub struct LintCache {
+    config_hash: u64,
+    entries: DashMap<Box<Path>, CacheEntry>,
+}
+
+/// Results of linting a single file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the file's source text.
+    hash: u64,
+    messages: Vec<CachedMessage>,
+    /// Error codes of rules that have not been run on the file since the configuration
+    /// changed. The entry is complete once these have run.
+    #[serde(default)]
+    pending: Vec<String>,
+}
+
+/// Rules that differ between two configurations, identified by the error code of their
+/// diagnostics, e.g. `eslint(prefer-const)`. See [`Linter::diff_rules`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RuleChanges {
+    /// Rules that are only enabled in the new configuration.
+    pub added: Vec<String>,
+    /// Rules that are only enabled in the old configuration.
+    pub removed: Vec<String>,
+    /// Rules that are enabled in both configurations, with a different severity or options.
+    pub reconfigured: Vec<String>,
+}
+
+impl RuleChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.reconfigured.is_empty()
+    }
+}
+
+/// On-disk representation of a [`LintCache`].
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    config_hash: u64,
+    entries: Vec<(PathBuf, CacheEntry)>,
+}
+
+impl LintCache {
+    /// Create an empty cache for the configuration identified by `config_hash`.
+    pub fn new(config_hash: u64) -> Self {
+        Self { config_hash, entries: DashMap::default() }
+    }
+
+    /// Hash of the configuration the cached diagnostics were produced with.
+    pub fn config_hash(&self) -> u64 {
+        self.config_hash
+    }
+
+    /// Number of cached files.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Hash the contents of a file.
+    pub fn hash_source(source_text: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        hasher.write(source_text.as_bytes());
+        hasher.write_usize(source_text.len());
+        hasher.finish()
+    }
+
+    /// Get the cached diagnostics for `path`, if its source text has not
+    /// changed since they were stored and no rules are pending.
+    pub fn get(&self, path: &Path, source_text: &str) -> Option<Vec<Message<'static>>> {
+        let entry = self.entries.get(path)?;
+        if entry.hash != Self::hash_source(source_text) || !entry.pending.is_empty() {
+            return None;
+        }
+        Some(entry.messages.iter().map(CachedMessage::to_message).collect())
+    }
+
+    /// Get the cached diagnostics for `path` and the error codes of the rules that still
+    /// need to run on it, if its source text has not changed since they were stored.
+    ///
+    /// The list of rules is empty if the cached diagnostics are complete.
+    pub fn get_partial(
+        &self,
+        path: &Path,
+        source_text: &str,
+    ) -> Option<(Vec<Message<'static>>, Vec<String>)> {
+        let entry = self.entries.get(path)?;
+        if entry.hash != Self::hash_source(source_text) {
+            return None;
+        }
+        let messages = entry.messages.iter().map(CachedMessage::to_message).collect();
+        Some((messages, entry.pending.clone()))
+    }
+
+    /// Store the diagnostics produced for `path`, replacing any previous entry.
+    pub fn insert(&self, path: &Path, source_text: &str, messages: &[Message<'_>]) {
+        let entry = CacheEntry {
+            hash: Self::hash_source(source_text),
+            messages: messages.iter().map(CachedMessage::from_message).collect(),
+            pending: vec![],
+        };
+        self.entries.insert(path.to_path_buf().into_boxed_path(), entry);
+    }
+
+    /// Carry this cache, created for `previous`, over to `linter` after a configuration change.
+    ///
+    /// If only rules changed, cached diagnostics of unchanged rules are kept, and the added
+    /// and reconfigured rules are marked as pending for every file. Otherwise, or if this
+    /// cache does not belong to `previous`, the new cache is empty.
+    #[must_use]
+    pub fn reconfigure(&self, previous: &Linter, linter: &Linter) -> Self {
+        let config_hash = linter.config_hash();
+        if self.config_hash != previous.config_hash() {
+            return Self::new(config_hash);
+        }
+        match linter.diff_rules(previous) {
+            Some(changes) => self.with_rule_changes(&changes, config_hash),
+            None => Self::new(config_hash),
+        }
+    }
+
+    fn with_rule_changes(&self, changes: &RuleChanges, config_hash: u64) -> Self {
+        let is_stale = |code: &str| {
+            changes.removed.iter().chain(&changes.reconfigured).any(|stale| stale == code)
+        };
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let CacheEntry { hash, messages, pending } = entry.value();
+                let mut pending = pending
+                    .iter()
+                    .chain(&changes.added)
+                    .chain(&changes.reconfigured)
+                    .filter(|code| !changes.removed.contains(code))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                pending.sort_unstable();
+                pending.dedup();
+                // Parse and semantic errors have no error code. Running the pending rules
+                // reports them again, so they are only kept if no rules are pending.
+                let messages = messages
+                    .iter()
+                    .filter(|message| match message.code() {
+                        Some(code) => !is_stale(&code),
+                        None => pending.is_empty(),
+                    })
+                    .cloned()
+                    .collect();
+                (entry.key().clone(), CacheEntry { hash: *hash, messages, pending })
+            })
+            .collect();
+        Self { config_hash, entries }
+    }
+
+    /// Forget the cached diagnostics for `path`.
+    pub fn remove(&self, path: &Path) {
+        self.entries.remove(path);
+    }
+
+    /// Serialize this cache into a JSON blob.
+    ///
+    /// # Panics
+    /// If serialization fails, which should never happen.
+    pub fn to_json(&self) -> String {
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|entry| (entry.key().to_path_buf(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        // Keep the output stable between runs.
+        entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let file = CacheFile { config_hash: self.config_hash, entries };
+        serde_json::to_string(&file).unwrap()
+    }
+
+    /// Deserialize a cache previously created by [`LintCache::to_json`].
+    ///
+    /// Returns an empty cache if the blob is malformed or was produced with a
+    /// configuration other than `config_hash`.
+    pub fn from_json(json: &str, config_hash: u64) -> Self {
+        match serde_json::from_str::<CacheFile>(json) {
+            Ok(file) if file.config_hash == config_hash => Self {
+                config_hash,
+                entries: file
+                    .entries
+                    .into_iter()
+                    .map(|(path, entry)| (path.into_boxed_path(), entry))
+                    .collect(),
+            },
+            _ => Self::new(config_hash),
+        }
+    }
+
+    /// Write this cache to `path`.
+    ///
+    /// # Errors
+    /// If the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+
+    /// Load a cache from `path`.
+    ///
+    /// A missing, unreadable or stale cache file results in an empty cache.
+    pub fn load(path: &Path, config_hash: u64) -> Self {
+        fs::read_to_string(path)
+            .map_or_else(|_| Self::new(config_hash), |json| Self::from_json(&json, config_hash))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMessage {
+    message: String,
+    labels: Option<Vec<CachedLabel>>,
+    help: Option<String>,
+    severity: CachedSeverity,
+    code_scope: Option<String>,
+    code_number: Option<String>,
+    url: Option<String>,
+    fix: Option<CachedFix>,
+    #[serde(default)]
+    suggestions: Vec<CachedSuggestion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedSuggestion {
+    message: String,
+    fix: CachedFix,
+}
+
+impl CachedFix {
+    fn from_fix(fix: &Fix<'_>) -> Self {
+        Self { content: fix.content.to_string(), start: fix.span.start, end: fix.span.end }
+    }
+
+    fn to_fix(&self) -> Fix<'static> {
+        Fix::new(Cow::Owned(self.content.clone()), oxc_span::Span::new(self.start, self.end))
+    }
+}
+
+impl CachedMessage {
+    /// The error code of the rule that reported this message, e.g. `eslint(no-debugger)`.
+    fn code(&self) -> Option<String> {
+        match (&self.code_scope, &self.code_number) {
+            (Some(scope), Some(number)) => Some(format!("{scope}({number})")),
+            _ => None,
+        }
+    }
+
+    fn from_message(message: &Message<'_>) -> Self {
+        let error = &message.error;
+        Self {
+            message: error.message.to_string(),
+            labels: error.labels.as_ref().map(|labels| {
+                labels
+                    .iter()
+                    .map(|label| CachedLabel {
+                        label: label.label().map(ToString::to_string),
+                        offset: label.offset(),
+                        len: label.len(),
+                        primary: label.primary(),
+                    })
+                    .collect()
+            }),
+            help: error.help.as_ref().map(ToString::to_string),
+            severity: match error.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            code_scope: error.code.scope.as_ref().map(ToString::to_string),
+            code_number: error.code.number.as_ref().map(ToString::to_string),
+            url: error.url.as_ref().map(ToString::to_string),
+            fix: message.fix.as_ref().map(CachedFix::from_fix),
+            suggestions: message
+                .suggestions
+                .iter()
+                .map(|suggestion| CachedSuggestion {
+                    message: suggestion.message.to_string(),
+                    fix: CachedFix::from_fix(&suggestion.fix),
+                })
+                .collect(),
+        }
+    }
+
+    fn to_message(&self) -> Message<'static> {
+        let severity = match self.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        let mut error = OxcDiagnostic::warn(self.message.clone()).with_severity(severity);
+        if let Some(labels) = &self.labels {
+            error = error.with_labels(labels.iter().map(|label| {
+                let span = (label.offset, label.len);
+                if label.primary {
+                    LabeledSpan::new_primary_with_span(label.label.clone(), span)
+                } else {
+                    LabeledSpan::new_with_span(label.label.clone(), span)
+                }
+            }));
+        }
+        if let Some(help) = &self.help {
+            error = error.with_help(help.clone());
+        }
+        if let Some(scope) = &self.code_scope {
+            error = error.with_error_code_scope(scope.clone());
+        }
+        if let Some(number) = &self.code_number {
+            error = error.with_error_code_num(number.clone());
+        }
+        if let Some(url) = &self.url {
+            error = error.with_url(url.clone());
+        }
+        let suggestions = self
+            .suggestions
+            .iter()
+            .map(|suggestion| Suggestion::new(suggestion.message.clone(), suggestion.fix.to_fix()))
+            .collect();
+        Message::new(error, self.fix.as_ref().map(CachedFix::to_fix)).with_suggestions(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::{DiagnosticService, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::LintCache;
+    use crate::{
+        fixer::{Fix, Message, Suggestion},
+        rules::RULES,
+        AllowWarnDeny, LintService, LintServiceOptions, Linter, RuleWithSeverity,
+    };
+
+    fn messages() -> Vec<Message<'static>> {
+        vec![
+            Message::new(
+                OxcDiagnostic::warn("Unexpected `debugger` statement")
+                    .with_label(Span::new(0, 8))
+                    .with_help("Delete this code.")
+                    .with_error_code("eslint", "no-debugger"),
+                Some(Fix::delete(Span::new(0, 9))),
+            ),
+            Message::new(
+                OxcDiagnostic::error("Unexpected var").with_label(Span::new(10, 13)),
+                None,
+            )
+            .with_suggestions(vec![Suggestion::new(
+                "Replace `var` with `let`.",
+                Fix::new("let", Span::new(10, 13)),
+            )]),
+        ]
+    }
+
+    #[test]
+    fn returns_cached_messages_for_unchanged_source() {
+        let cache = LintCache::new(1);
+        let path = std::path::Path::new("foo.js");
+        let source_text = "debugger; var a;";
+        assert!(cache.get(path, source_text).is_none());
+
+        cache.insert(path, source_text, &messages());
+        let cached = cache.get(path, source_text).unwrap();
+        assert_eq!(cached.len(), 2);
+        assert_eq!(cached[0].error.message, "Unexpected `debugger` statement");
+        assert_eq!(cached[0].error.help.as_deref(), Some("Delete this code."));
+        assert_eq!(cached[0].error.code.to_string(), "eslint(no-debugger)");
+        let fix = cached[0].fix.as_ref().unwrap();
+        assert_eq!(fix.span, Span::new(0, 9));
+        assert!(fix.content.is_empty());
+        assert!(cached[1].fix.is_none());
+        assert!(cached[0].suggestions.is_empty());
+        assert_eq!(cached[1].suggestions[0].message, "Replace `var` with `let`.");
+
+        assert!(cache.get(path, "debugger; let a;").is_none());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let cache = LintCache::new(42);
+        let path = std::path::Path::new("foo.js");
+        cache.insert(path, "debugger; var a;", &messages());
+
+        let json = cache.to_json();
+        let loaded = LintCache::from_json(&json, 42);
+        assert_eq!(loaded.len(), 1);
+        let cached = loaded.get(path, "debugger; var a;").unwrap();
+        assert_eq!(cached.len(), 2);
+        assert_eq!(cached[1].error.severity, oxc_diagnostics::Severity::Error);
+        assert_eq!(cached[1].suggestions.len(), 1);
+        assert_eq!(cached[1].suggestions[0].message, "Replace `var` with `let`.");
+        assert_eq!(cached[1].suggestions[0].fix.content, "let");
+        assert_eq!(cached[1].suggestions[0].fix.span, Span::new(10, 13));
+
+        // A configuration change invalidates the whole cache.
+        assert!(LintCache::from_json(&json, 43).is_empty());
+        assert!(LintCache::from_json("not json", 42).is_empty());
+    }
+
+    #[test]
+    fn config_hash_tracks_rule_configuration() {
+        let linter = Linter::default();
+        assert_eq!(linter.config_hash(), Linter::default().config_hash());
+
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let warn = Linter::default()
+            .with_rules(vec![RuleWithSeverity::new(no_debugger.clone(), AllowWarnDeny::Warn)]);
+        let deny = Linter::default()
+            .with_rules(vec![RuleWithSeverity::new(no_debugger.clone(), AllowWarnDeny::Deny)]);
+        assert_ne!(linter.config_hash(), warn.config_hash());
+        assert_ne!(warn.config_hash(), deny.config_hash());
+
+        let no_console = RULES.iter().find(|rule| rule.name() == "no-console").unwrap();
+        let with_options = |options: serde_json::Value| {
+            let rule =
+                RuleWithSeverity::new(no_console.read_json(options.clone()), AllowWarnDeny::Warn)
+                    .with_options(options);
+            Linter::default().with_rules(vec![rule])
+        };
+        let allow_warn = with_options(serde_json::json!([{ "allow": ["warn"] }]));
+        assert_eq!(
+            allow_warn.config_hash(),
+            with_options(serde_json::json!([{ "allow": ["warn"] }])).config_hash()
+        );
+        assert_ne!(
+            allow_warn.config_hash(),
+            with_options(serde_json::json!([{ "allow": ["error"] }])).config_hash()
+        );
+    }
+
+    fn rule(name: &str) -> RuleWithSeverity {
+        let rule = RULES.iter().find(|rule| rule.name() == name).unwrap();
+        RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn)
+    }
+
+    /// Lint `source_text` as `fixture.js` through a [`LintService`] using `cache`, and return
+    /// the error codes of the diagnostics.
+    fn lint(linter: Linter, cache: &Arc<LintCache>, source_text: &str) -> Vec<String> {
+        let cwd = env::current_dir().unwrap().into_boxed_path();
+        let paths = vec![Path::new("fixture.js").into()];
+        let options = LintServiceOptions::new(cwd, paths).with_cache(Arc::clone(cache));
+        let service = LintService::from_linter(linter, options);
+        let allocator = Allocator::default();
+        let diagnostic_service = DiagnosticService::default();
+        let mut codes = service
+            .run_source(&allocator, source_text, false, diagnostic_service.sender())
+            .into_iter()
+            .map(|message| message.error.code.to_string())
+            .collect::<Vec<_>>();
+        codes.sort_unstable();
+        codes
+    }
+
+    #[test]
+    fn reconfigure_reruns_changed_rules() {
+        let path = Path::new("fixture.js");
+        let source_text = "debugger; let a = 1; console.log(a);";
+        let without = || Linter::default().with_rules(vec![rule("no-debugger")]);
+        let with = || Linter::default().with_rules(vec![rule("no-debugger"), rule("prefer-const")]);
+
+        let cache = Arc::new(LintCache::new(without().config_hash()));
+        assert_eq!(lint(without(), &cache, source_text), ["eslint(no-debugger)"]);
+
+        // Turning prefer-const on only runs prefer-const. The other diagnostics are cached.
+        let changes = with().diff_rules(&without()).unwrap();
+        assert_eq!(changes.added, ["eslint(prefer-const)"]);
+        assert!(changes.removed.is_empty() && changes.reconfigured.is_empty());
+        let cache = Arc::new(cache.reconfigure(&without(), &with()));
+        assert_eq!(cache.config_hash(), with().config_hash());
+        assert!(cache.get(path, source_text).is_none());
+        let (cached, pending) = cache.get_partial(path, source_text).unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(pending, ["eslint(prefer-const)"]);
+        assert_eq!(
+            lint(with(), &cache, source_text),
+            ["eslint(no-debugger)", "eslint(prefer-const)"]
+        );
+        assert_eq!(cache.get(path, source_text).unwrap().len(), 2);
+
+        // Turning it off again drops its diagnostics without linting.
+        let changes = without().diff_rules(&with()).unwrap();
+        assert_eq!(changes.removed, ["eslint(prefer-const)"]);
+        let cache = Arc::new(cache.reconfigure(&with(), &without()));
+        assert_eq!(cache.get(path, source_text).unwrap().len(), 1);
+        assert_eq!(lint(without(), &cache, source_text), ["eslint(no-debugger)"]);
+
+        // A cache that belongs to another configuration is not carried over.
+        assert!(cache.reconfigure(&with(), &without()).is_empty());
+    }
+
+    #[test]
+    fn reconfigure_reports_syntax_errors_once() {
+        let path = Path::new("fixture.js");
+        let source_text = "debugger; let a = ;";
+        let without = || Linter::default().with_rules(vec![rule("no-debugger")]);
+        let with = || Linter::default().with_rules(vec![rule("no-debugger"), rule("prefer-const")]);
+
+        let cache = Arc::new(LintCache::new(without().config_hash()));
+        let errors = lint(without(), &cache, source_text);
+        assert!(!errors.is_empty());
+
+        // The syntax errors are reported again by linting the pending rules, so they are not
+        // kept in the partial entry.
+        let cache = Arc::new(cache.reconfigure(&without(), &with()));
+        let (cached, pending) = cache.get_partial(path, source_text).unwrap();
+        assert!(cached.is_empty());
+        assert_eq!(pending, ["eslint(prefer-const)"]);
+        assert_eq!(lint(with(), &cache, source_text), errors);
+        assert_eq!(cache.get(path, source_text).unwrap().len(), errors.len());
+
+        // Without pending rules, nothing runs again and the syntax errors are kept.
+        let cache = Arc::new(cache.reconfigure(&with(), &without()));
+        assert_eq!(cache.get(path, source_text).unwrap().len(), errors.len());
+        assert_eq!(lint(without(), &cache, source_text), errors);
+    }
+
+    #[test]
+    fn reconfigured_rules_run_again() {
+        let no_debugger = || Linter::default().with_rules(vec![rule("no-debugger")]);
+        let deny = || {
+            let mut rule = rule("no-debugger");
+            rule.severity = AllowWarnDeny::Deny;
+            Linter::default().with_rules(vec![rule])
+        };
+        let changes = deny().diff_rules(&no_debugger()).unwrap();
+        assert_eq!(changes.reconfigured, ["eslint(no-debugger)"]);
+        assert!(no_debugger().diff_rules(&no_debugger()).unwrap().is_empty());
+
+        let path = Path::new("fixture.js");
+        let source_text = "debugger;";
+        let cache = Arc::new(LintCache::new(no_debugger().config_hash()));
+        lint(no_debugger(), &cache, source_text);
+        let cache = Arc::new(cache.reconfigure(&no_debugger(), &deny()));
+        let (cached, pending) = cache.get_partial(path, source_text).unwrap();
+        assert!(cached.is_empty());
+        assert_eq!(pending, ["eslint(no-debugger)"]);
+        lint(deny(), &cache, source_text);
+        let cached = cache.get(path, source_text).unwrap();
+        assert_eq!(cached[0].error.severity, oxc_diagnostics::Severity::Error);
+    }
+}
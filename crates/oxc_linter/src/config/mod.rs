@@ -66,7 +66,7 @@ pub struct OxlintConfig {
     pub globals: OxlintGlobals,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub(crate) struct LintConfig {
     pub(crate) settings: OxlintSettings,
     /// Environments enable and disable collections of global variables.
@@ -131,10 +131,22 @@ impl OxlintConfig {
         let lookup = self.rules.iter().into_group_map_by(|r| r.rule_name.as_str());
 
         for (name, rule_configs) in &lookup {
-            match rule_configs.len() {
-                0 => unreachable!(),
-                1 => {
-                    let rule_config = &rule_configs[0];
+            // Configs for rules of different plugins that share a name, e.g. `eslint/no-lonely-if`
+            // and `unicorn/no-lonely-if`, are applied to their own rule.
+            let targets_distinct_rules = rule_configs.len() == 1 || {
+                let targets = rule_configs
+                    .iter()
+                    .map(|r| transform_rule_and_plugin_name(&r.rule_name, &r.plugin_name))
+                    .collect::<Vec<_>>();
+                targets.iter().all_unique()
+                    && targets.iter().all(|(rule_name, plugin_name)| {
+                        all_rules
+                            .iter()
+                            .any(|r| r.name() == *rule_name && r.plugin_name() == *plugin_name)
+                    })
+            };
+            if targets_distinct_rules {
+                for rule_config in rule_configs {
                     let (rule_name, plugin_name) = transform_rule_and_plugin_name(
                         &rule_config.rule_name,
                         &rule_config.plugin_name,
@@ -147,8 +159,10 @@ impl OxlintConfig {
                                 .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
                             {
                                 let config = rule_config.config.clone().unwrap_or_default();
-                                let rule = rule.read_json(config);
-                                rules_to_replace.push(RuleWithSeverity::new(rule, severity));
+                                let rule = rule.read_json(config.clone());
+                                rules_to_replace.push(
+                                    RuleWithSeverity::new(rule, severity).with_options(config),
+                                );
                             }
                         }
                         AllowWarnDeny::Allow => {
@@ -162,22 +176,21 @@ impl OxlintConfig {
                         }
                     }
                 }
-                _ => {
-                    // For overlapping rule names, use the "error" one
-                    // "no-loss-of-precision": "off",
-                    // "@typescript-eslint/no-loss-of-precision": "error"
-                    if let Some(rule_config) =
-                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
-                    {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            let config = rule_config.config.clone().unwrap_or_default();
-                            rules_to_replace
-                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
-                        }
-                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_remove.push(rule.clone());
-                        }
+            } else {
+                // For overlapping rule names, use the "error" one
+                // "no-loss-of-precision": "off",
+                // "@typescript-eslint/no-loss-of-precision": "error"
+                if let Some(rule_config) = rule_configs.iter().find(|r| r.severity.is_warn_deny()) {
+                    if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                        let config = rule_config.config.clone().unwrap_or_default();
+                        rules_to_replace.push(
+                            RuleWithSeverity::new(rule.read_json(config.clone()), rule.severity)
+                                .with_options(config),
+                        );
+                    }
+                } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
+                    if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                        rules_to_remove.push(rule.clone());
                     }
                 }
             }
@@ -212,7 +225,7 @@ mod test {
     use serde::Deserialize;
 
     use super::OxlintConfig;
-    use crate::rules::RULES;
+    use crate::{rules::RULES, AllowWarnDeny};
 
     #[test]
     fn test_from_file() {
@@ -275,4 +288,27 @@ mod test {
         assert_eq!(rule.name(), "no-disabled-tests");
         assert_eq!(rule.plugin_name(), "jest");
     }
+
+    #[test]
+    fn test_override_rules_with_shared_name() {
+        let config: OxlintConfig = serde_json::from_value(serde_json::json!({
+            "rules": { "no-lonely-if": "warn", "unicorn/no-lonely-if": "error" }
+        }))
+        .unwrap();
+        let mut set = FxHashSet::default();
+        config.override_rules(&mut set, &RULES);
+
+        let mut rules = set
+            .iter()
+            .map(|rule| (rule.plugin_name(), rule.name(), rule.severity))
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|(plugin_name, ..)| *plugin_name);
+        assert_eq!(
+            rules,
+            vec![
+                ("eslint", "no-lonely-if", AllowWarnDeny::Warn),
+                ("unicorn", "no-lonely-if", AllowWarnDeny::Deny)
+            ]
+        );
+    }
 }
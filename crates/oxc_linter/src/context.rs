@@ -1,9 +1,12 @@
 #![allow(rustdoc::private_intra_doc_links)] // useful for intellisense
 use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
+use oxc_ast::AstKind;
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::{AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
+use oxc_semantic::{
+    AstNode, AstNodes, JSDocFinder, ScopeId, ScopeTree, Semantic, SymbolId, SymbolTable,
+};
 use oxc_span::{GetSpan, SourceType, Span};
 use oxc_syntax::module_record::ModuleRecord;
 
@@ -223,8 +226,15 @@ impl<'a> LintContext<'a> {
                 self.current_plugin_name,
                 self.current_rule_name
             ));
-        if message.error.severity != self.severity {
-            message.error = message.error.with_severity(self.severity);
+        let severity = if self.severity == Severity::Error
+            && self.disable_directives.is_downgraded(self.current_rule_name, message.span())
+        {
+            Severity::Warning
+        } else {
+            self.severity
+        };
+        if message.error.severity != severity {
+            message.error = message.error.with_severity(severity);
         }
 
         self.diagnostics.borrow_mut().push(message);
@@ -342,6 +352,62 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Report a lint rule violation and offer one or more suggestions for
+    /// fixing it.
+    ///
+    /// The second argument is a [closure] that takes a [`RuleFixer`] and
+    /// returns the suggestions. Each one needs a message, which is what users
+    /// pick from. The [`RuleFixer`] creates one for simple edits, otherwise
+    /// give it one with [`RuleFix::with_message`].
+    ///
+    /// Unlike [`LintContext::diagnostic_with_suggestion`], these are never
+    /// applied automatically, not even with `--fix-suggestions`. They are
+    /// attached to the diagnostic so that e.g. editors can offer them as code
+    /// actions. Their messages become the diagnostic's help, unless it already
+    /// has one. Use this when there is more than one reasonable way to fix a
+    /// violation and picking one needs a human.
+    ///
+    /// [closure]: <https://doc.rust-lang.org/book/ch13-01-closures.html>
+    #[allow(clippy::missing_panics_doc)] // only panics in debug mode
+    pub fn diagnostic_with_suggestions<F>(&self, diagnostic: OxcDiagnostic, suggestions: F)
+    where
+        F: FnOnce(RuleFixer<'_, 'a>) -> Vec<RuleFix<'a>>,
+    {
+        #[cfg(debug_assertions)]
+        {
+            assert!(
+                self.current_rule_fix_capabilities.supports_fix(FixKind::Suggestion),
+                "Rule `{}` does not support suggestions. Did you forget to update fix capabilities in declare_oxc_lint?.\n\tSupported fix kinds: {:?}",
+                self.current_rule_name,
+                FixKind::from(self.current_rule_fix_capabilities),
+            );
+        }
+        let fixer = RuleFixer::new(FixKind::Suggestion, self);
+        let suggestions = suggestions(fixer)
+            .into_iter()
+            .filter_map(|rule_fix| {
+                let suggestion = rule_fix.into_suggestion(self.source_text());
+                debug_assert!(
+                    suggestion.is_some(),
+                    "Rule `{}` offered a suggestion without a message. Give it one with `RuleFix::with_message`.",
+                    self.current_rule_name
+                );
+                suggestion
+            })
+            .collect::<Vec<_>>();
+        let diagnostic = if diagnostic.help.is_none() && !suggestions.is_empty() {
+            let help = suggestions
+                .iter()
+                .map(|suggestion| suggestion.message.as_ref())
+                .collect::<Vec<_>>()
+                .join("\n");
+            diagnostic.with_help(help)
+        } else {
+            diagnostic
+        };
+        self.add_diagnostic(Message::new(diagnostic, None).with_suggestions(suggestions));
+    }
+
     pub fn frameworks(&self) -> FrameworkFlags {
         self.frameworks
     }
@@ -353,6 +419,24 @@ impl<'a> LintContext<'a> {
         self.semantic().nodes()
     }
 
+    /// Parent of `node`, or `None` if `node` is the `Program`.
+    ///
+    /// Shorthand for `ctx.nodes().parent_node(node.id())`. Nodes are owned by the [`Semantic`]
+    /// of the file being linted, so the returned node can be kept for as long as this context,
+    /// e.g. to keep walking up the tree.
+    #[inline]
+    pub fn parent_node(&self, node: &AstNode<'a>) -> Option<&AstNode<'a>> {
+        self.nodes().parent_node(node.id())
+    }
+
+    /// [`AstKind`] of the parent of `node`, or `None` if `node` is the `Program`.
+    ///
+    /// Shorthand for `ctx.nodes().parent_kind(node.id())`.
+    #[inline]
+    pub fn parent_kind(&self, node: &AstNode<'a>) -> Option<AstKind<'a>> {
+        self.nodes().parent_kind(node.id())
+    }
+
     /// Scope tree
     ///
     /// Shorthand for `ctx.semantic().scopes()`.
@@ -360,6 +444,23 @@ impl<'a> LintContext<'a> {
         self.semantic().scopes()
     }
 
+    /// Iterate over `scope_id` and its ancestors, ending with the root scope.
+    ///
+    /// Shorthand for `ctx.scopes().ancestors(scope_id)`.
+    pub fn scope_ancestors(&self, scope_id: ScopeId) -> impl Iterator<Item = ScopeId> + '_ {
+        self.scopes().ancestors(scope_id)
+    }
+
+    /// Names and symbols bound directly in `scope_id`, in declaration order.
+    ///
+    /// Bindings of child and parent scopes are not included.
+    pub fn scope_bindings(&self, scope_id: ScopeId) -> impl Iterator<Item = (&str, SymbolId)> + '_ {
+        self.scopes()
+            .get_bindings(scope_id)
+            .iter()
+            .map(|(name, symbol_id)| (name.as_str(), *symbol_id))
+    }
+
     /// Symbol table
     ///
     /// Shorthand for `ctx.semantic().symbols()`.
@@ -393,7 +494,7 @@ impl<'a> LintContext<'a> {
 }
 
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub(crate) fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
@@ -411,3 +512,71 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
 };
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::LintContext;
+
+    #[test]
+    fn scope_ancestors_and_bindings() {
+        let allocator = Allocator::default();
+        let source_text = "let a; function foo(b) { { let c; if (c) { debugger; } } }";
+        let program = allocator
+            .alloc(Parser::new(&allocator, source_text, SourceType::default()).parse().program);
+        let semantic = SemanticBuilder::new(source_text).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Box::from(Path::new("foo.js")), Rc::new(semantic));
+
+        let debugger = ctx
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::DebuggerStatement(_)))
+            .unwrap();
+        // if block -> inner block -> function -> root
+        let ancestors = ctx.scope_ancestors(debugger.scope_id()).collect::<Vec<_>>();
+        assert_eq!(ancestors.len(), 4);
+        assert_eq!(ancestors.last(), Some(&ctx.scopes().root_scope_id()));
+
+        let names_in =
+            |scope_id| ctx.scope_bindings(scope_id).map(|(name, _)| name).collect::<Vec<_>>();
+        assert_eq!(names_in(ancestors[0]), Vec::<&str>::new());
+        assert_eq!(names_in(ancestors[1]), vec!["c"]);
+        assert_eq!(names_in(ancestors[2]), vec!["b"]);
+        assert_eq!(names_in(ancestors[3]), vec!["a", "foo"]);
+
+        let (_, c) = ctx.scope_bindings(ancestors[1]).next().unwrap();
+        assert_eq!(ctx.symbols().get_scope_id(c), ancestors[1]);
+    }
+
+    #[test]
+    fn parent_lookups() {
+        let allocator = Allocator::default();
+        let source_text = "for (let i = 0; ; ) {}";
+        let program = allocator
+            .alloc(Parser::new(&allocator, source_text, SourceType::default()).parse().program);
+        let semantic = SemanticBuilder::new(source_text).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Box::from(Path::new("foo.js")), Rc::new(semantic));
+
+        let declaration = ctx
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::VariableDeclaration(_)))
+            .unwrap();
+        assert!(matches!(ctx.parent_kind(declaration), Some(AstKind::ForStatementInit(_))));
+
+        let init = ctx.parent_node(declaration).unwrap();
+        assert!(matches!(ctx.parent_kind(init), Some(AstKind::ForStatement(_))));
+        let for_statement = ctx.parent_node(init).unwrap();
+        assert!(matches!(ctx.parent_kind(for_statement), Some(AstKind::Program(_))));
+        let program = ctx.parent_node(for_statement).unwrap();
+        assert!(ctx.parent_node(program).is_none());
+        assert!(ctx.parent_kind(program).is_none());
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+
+/// Sort diagnostics by their position in the source code and remove exact duplicates.
+///
+/// Rules report diagnostics in the order they run, so the output of a lint run depends on
+/// which rules are enabled. This orders diagnostics by the start and end of their labels, then
+/// by their error code (e.g. `eslint(no-debugger)`), so that output is deterministic.
+///
+/// Diagnostics with the same labels, message, help and error code are duplicates and only the
+/// first one is kept. These happen e.g. when two rules share an implementation.
+pub fn sort_and_dedup_diagnostics(diagnostics: &mut Vec<OxcDiagnostic>) {
+    diagnostics.sort_by(compare);
+    diagnostics.dedup_by(|a, b| {
+        compare(a, b) == Ordering::Equal && a.labels == b.labels && a.severity == b.severity
+    });
+}
+
+fn compare(a: &OxcDiagnostic, b: &OxcDiagnostic) -> Ordering {
+    range(a)
+        .cmp(&range(b))
+        .then_with(|| a.code.to_string().cmp(&b.code.to_string()))
+        .then_with(|| a.message.cmp(&b.message))
+        .then_with(|| a.help.cmp(&b.help))
+}
+
+/// The range covered by all labels of `diagnostic`. Diagnostics without labels come first.
+fn range(diagnostic: &OxcDiagnostic) -> (usize, usize) {
+    let Some(labels) = &diagnostic.labels else {
+        return (0, 0);
+    };
+    let start = labels.iter().map(LabeledSpan::offset).min().unwrap_or(0);
+    let end = labels.iter().map(|label| label.offset() + label.len()).max().unwrap_or(0);
+    (start, end)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+
+    use super::sort_and_dedup_diagnostics;
+
+    fn diagnostic(
+        rule: &'static str,
+        message: &'static str,
+        start: u32,
+        end: u32,
+    ) -> OxcDiagnostic {
+        OxcDiagnostic::warn(message)
+            .with_label(Span::new(start, end))
+            .with_error_code("eslint", rule)
+    }
+
+    fn summarize(diagnostics: &[OxcDiagnostic]) -> Vec<String> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| format!("{} {}", diagnostic.code, diagnostic.message))
+            .collect()
+    }
+
+    #[test]
+    fn sorts_by_span_then_rule() {
+        let mut diagnostics = vec![
+            diagnostic("no-debugger", "c", 10, 20),
+            diagnostic("no-console", "b", 0, 5),
+            diagnostic("no-empty", "d", 10, 15),
+            diagnostic("eqeqeq", "e", 10, 15),
+            OxcDiagnostic::warn("a"),
+        ];
+        sort_and_dedup_diagnostics(&mut diagnostics);
+        assert_eq!(
+            summarize(&diagnostics),
+            vec![
+                " a",
+                "eslint(no-console) b",
+                "eslint(eqeqeq) e",
+                "eslint(no-empty) d",
+                "eslint(no-debugger) c"
+            ]
+        );
+    }
+
+    #[test]
+    fn removes_exact_duplicates() {
+        let mut diagnostics = vec![
+            diagnostic("no-debugger", "a", 0, 5),
+            diagnostic("no-console", "a", 0, 5),
+            diagnostic("no-debugger", "b", 0, 5),
+            diagnostic("no-debugger", "a", 0, 5),
+            diagnostic("no-debugger", "a", 1, 5),
+        ];
+        sort_and_dedup_diagnostics(&mut diagnostics);
+        assert_eq!(
+            summarize(&diagnostics),
+            vec![
+                "eslint(no-console) a",
+                "eslint(no-debugger) a",
+                "eslint(no-debugger) b",
+                "eslint(no-debugger) a"
+            ]
+        );
+    }
+    #[test]
+    fn keeps_diagnostics_with_different_help() {
+        let mut diagnostics = vec![
+            diagnostic("no-debugger", "a", 0, 5).with_help("x"),
+            diagnostic("no-debugger", "a", 0, 5).with_help("y"),
+            diagnostic("no-debugger", "a", 0, 5).with_help("x"),
+        ];
+        sort_and_dedup_diagnostics(&mut diagnostics);
+        let help: Vec<_> =
+            diagnostics.iter().map(|diagnostic| diagnostic.help.as_deref().unwrap()).collect();
+        assert_eq!(help, vec!["x", "y"]);
+    }
+}
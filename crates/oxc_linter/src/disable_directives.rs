@@ -21,6 +21,9 @@ pub struct DisableRuleComment<'a> {
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
+    /// Rules downgraded from errors to warnings by `oxlint-downgrade-next-line`, with their
+    /// corresponding covering spans
+    downgrade_intervals: Lapper<u32, DisabledRule<'a>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -29,7 +32,17 @@ pub struct DisableDirectives<'a> {
 
 impl<'a> DisableDirectives<'a> {
     pub fn contains(&self, rule_name: &'static str, span: Span) -> bool {
-        self.intervals.find(span.start, span.end).any(|interval| {
+        Self::covers(&self.intervals, rule_name, span)
+    }
+
+    /// Whether errors of `rule_name` at `span` are reported as warnings, because of an
+    /// `oxlint-downgrade-next-line` comment on the line before.
+    pub fn is_downgraded(&self, rule_name: &'static str, span: Span) -> bool {
+        Self::covers(&self.downgrade_intervals, rule_name, span)
+    }
+
+    fn covers(intervals: &Lapper<u32, DisabledRule<'a>>, rule_name: &str, span: Span) -> bool {
+        intervals.find(span.start, span.end).any(|interval| {
             interval.val == DisabledRule::All
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
@@ -52,6 +65,8 @@ pub struct DisableDirectivesBuilder<'a> {
     trivias: Trivias,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
+    /// All the downgraded rules with their corresponding covering spans
+    downgrade_intervals: Lapper<u32, DisabledRule<'a>>,
     /// Start of `eslint-disable` or `oxlint-disable`
     disable_all_start: Option<u32>,
     /// Start of `eslint-disable` or `oxlint-disable` rule_name`
@@ -68,6 +83,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
             source_text,
             trivias,
             intervals: Lapper::new(vec![]),
+            downgrade_intervals: Lapper::new(vec![]),
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
@@ -79,6 +95,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.build_impl();
         DisableDirectives {
             intervals: self.intervals,
+            downgrade_intervals: self.downgrade_intervals,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
         }
@@ -112,11 +129,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                 // `eslint-disable-next-line`
                 if let Some(text) = text.strip_prefix("-next-line") {
-                    // Get the span up to the next new line
-                    let stop = self.source_text[comment.span.end as usize..]
-                        .lines()
-                        .take(2)
-                        .fold(comment.span.end, |acc, line| acc + line.len() as u32);
+                    let stop = self.next_line_end(comment.span);
                     if text.trim().is_empty() {
                         self.add_interval(comment.span.end, stop, DisabledRule::All);
                         self.disable_all_comments.push(comment.span);
@@ -174,6 +187,27 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 continue;
             }
 
+            // `oxlint-downgrade-next-line` or `oxlint-downgrade-next-line rule-name1, rule-name2`
+            if let Some(text) = text.strip_prefix("oxlint-downgrade-next-line") {
+                let stop = self.next_line_end(comment.span);
+                if text.trim().is_empty() {
+                    self.downgrade_intervals.insert(Interval {
+                        start: comment.span.end,
+                        stop,
+                        val: DisabledRule::All,
+                    });
+                } else {
+                    Self::get_rule_names(text, |rule_name| {
+                        self.downgrade_intervals.insert(Interval {
+                            start: comment.span.end,
+                            stop,
+                            val: DisabledRule::Single(rule_name),
+                        });
+                    });
+                }
+                continue;
+            }
+
             if let Some(text) =
                 text.strip_prefix("eslint-enable").or_else(|| text.strip_prefix("oxlint-enable"))
             {
@@ -210,6 +244,15 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
     }
 
+    /// Get the span up to the next new line after `comment`
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn next_line_end(&self, comment: Span) -> u32 {
+        self.source_text[comment.end as usize..]
+            .lines()
+            .take(2)
+            .fold(comment.end, |acc, line| acc + line.len() as u32)
+    }
+
     fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
         if let Some(text) = text.split_terminator("--").next() {
             text.split(',').map(str::trim).for_each(cb);
@@ -400,3 +443,45 @@ fn test() {
         Tester::new("no-debugger", pass, fail).test();
     }
 }
+
+#[test]
+fn test_downgrade_next_line() {
+    use std::{env, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::{DiagnosticService, Severity};
+    use oxc_span::GetSpan;
+
+    use crate::{
+        AllowWarnDeny, LintFilter, LintService, LintServiceOptions, Linter, OxlintOptions,
+    };
+
+    let filter = ["no-debugger", "no-empty"]
+        .map(|rule| LintFilter::new(AllowWarnDeny::Deny, rule).unwrap())
+        .to_vec();
+    let linter = Linter::from_options(OxlintOptions::default().with_filter(filter)).unwrap();
+    let cwd = env::current_dir().unwrap().into_boxed_path();
+    let paths = vec![Path::new("fixture.js").into()];
+    let service = LintService::from_linter(linter, LintServiceOptions::new(cwd, paths));
+    let allocator = Allocator::default();
+    let diagnostic_service = DiagnosticService::default();
+
+    let source_text = "
+        // oxlint-downgrade-next-line no-debugger
+        debugger; {}
+        debugger;
+        // oxlint-downgrade-next-line
+        debugger; {}
+    ";
+    let messages = service.run_source(&allocator, source_text, false, diagnostic_service.sender());
+    let mut severities = messages
+        .iter()
+        .map(|message| (message.span().start, message.error.severity))
+        .collect::<Vec<_>>();
+    severities.sort_unstable_by_key(|(start, _)| *start);
+    let severities = severities.into_iter().map(|(_, severity)| severity).collect::<Vec<_>>();
+    assert_eq!(
+        severities,
+        [Severity::Warning, Severity::Error, Severity::Error, Severity::Warning, Severity::Warning]
+    );
+}
@@ -241,6 +241,13 @@ impl<'a> RuleFix<'a> {
         self.fix.normalize_fixes(source_text)
     }
 
+    /// Turn this fix into a [`Suggestion`]. Returns [`None`] if the fix has no
+    /// message, since that is what users pick suggestions by.
+    pub fn into_suggestion(self, source_text: &str) -> Option<Suggestion<'a>> {
+        let message = self.message?;
+        Some(Suggestion::new(message, self.fix.normalize_fixes(source_text)))
+    }
+
     #[inline]
     pub fn extend<F: Into<CompositeFix<'a>>>(mut self, fix: F) -> Self {
         self.fix = self.fix.concat(fix.into());
@@ -299,6 +306,26 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// A named, normalized fix that is never applied automatically.
+///
+/// Suggestions are surfaced to users separately from [`Fix`]es (e.g. as code
+/// actions in an editor), who then pick which one, if any, to apply. Lint
+/// rules create them with [`LintContext::diagnostic_with_suggestions`].
+///
+/// [`LintContext::diagnostic_with_suggestions`]: crate::LintContext::diagnostic_with_suggestions
+#[derive(Debug, Clone)]
+pub struct Suggestion<'a> {
+    /// Describes what applying this suggestion does.
+    pub message: Cow<'a, str>,
+    pub fix: Fix<'a>,
+}
+
+impl<'a> Suggestion<'a> {
+    pub fn new<M: Into<Cow<'a, str>>>(message: M, fix: Fix<'a>) -> Self {
+        Self { message: message.into(), fix }
+    }
+}
+
 // NOTE (@DonIsaac): having these variants is effectively the same as interning
 // single or 0-element Vecs. I experimented with using smallvec here, but the
 // resulting struct size was larger (40 bytes vs 32). So, we're sticking with
@@ -528,6 +555,102 @@ impl<'a> CompositeFix<'a> {
     }
 }
 
+/// Two edits added to a [`FixBuilder`] that touch the same range of source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlappingFixes {
+    pub first: Span,
+    pub second: Span,
+}
+
+/// Collects insertions, removals and replacements for a single diagnostic and turns them
+/// into a [`CompositeFix`] once they are known not to overlap.
+///
+/// [`CompositeFix::normalize_fixes`] silently drops fixes whose edits overlap. Building them
+/// with a [`FixBuilder`] surfaces the conflict to the rule instead, so that it can fall back to
+/// reporting the diagnostic without a fix.
+///
+/// ```ignore
+/// let mut builder = FixBuilder::new();
+/// builder.remove(stmt.span).insert_before(decl.span, "const ");
+/// if let Ok(fix) = builder.build() {
+///     ctx.diagnostic_with_fix(diagnostic, |_fixer| fix);
+/// }
+/// ```
+#[derive(Debug, Default)]
+#[must_use]
+pub struct FixBuilder<'a> {
+    fixes: Vec<Fix<'a>>,
+}
+
+impl<'a> FixBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert `text` right before the start of `span`.
+    pub fn insert_before<S: Into<Cow<'a, str>>>(&mut self, span: Span, text: S) -> &mut Self {
+        self.fixes.push(Fix::new(text, Span::empty(span.start)));
+        self
+    }
+
+    /// Insert `text` right after the end of `span`.
+    pub fn insert_after<S: Into<Cow<'a, str>>>(&mut self, span: Span, text: S) -> &mut Self {
+        self.fixes.push(Fix::new(text, Span::empty(span.end)));
+        self
+    }
+
+    /// Remove the text covered by `span`.
+    pub fn remove(&mut self, span: Span) -> &mut Self {
+        self.fixes.push(Fix::delete(span));
+        self
+    }
+
+    /// Replace the text covered by `span` with `text`.
+    pub fn replace<S: Into<Cow<'a, str>>>(&mut self, span: Span, text: S) -> &mut Self {
+        self.fixes.push(Fix::new(text, span));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fixes.is_empty()
+    }
+
+    /// The smallest [`Span`] covering every edit, or an empty span if there are none.
+    pub fn span(&self) -> Span {
+        self.fixes.iter().map(|fix| fix.span).reduce(|a, b| a.merge(&b)).unwrap_or(SPAN)
+    }
+
+    /// Check that no two edits overlap.
+    ///
+    /// Edits may touch, e.g. an insertion at the end of a removed range. Two insertions at the
+    /// same position do overlap, since the order of their text would be ambiguous.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first pair of overlapping edits, in source order.
+    pub fn validate(&self) -> Result<(), OverlappingFixes> {
+        let mut spans = self.fixes.iter().map(|fix| fix.span).collect::<Vec<_>>();
+        spans.sort_unstable();
+        for pair in spans.windows(2) {
+            let (first, second) = (pair[0], pair[1]);
+            if second.start < first.end || (first == second && first.is_empty()) {
+                return Err(OverlappingFixes { first, second });
+            }
+        }
+        Ok(())
+    }
+
+    /// Turn the collected edits into a [`CompositeFix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first pair of overlapping edits if there are any. See [`FixBuilder::validate`].
+    pub fn build(self) -> Result<CompositeFix<'a>, OverlappingFixes> {
+        self.validate()?;
+        Ok(CompositeFix::from(self.fixes))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -687,4 +810,48 @@ mod test {
     fn test_emojis_invalid() {
         FixKind::Dangerous.emoji();
     }
+
+    #[test]
+    fn test_fix_builder() {
+        let source_text = "let a = 1; foo(a);";
+        let mut builder = FixBuilder::new();
+        assert!(builder.is_empty());
+        builder
+            .replace(Span::new(0, 3), "const")
+            .remove(Span::new(10, 11))
+            .insert_before(Span::new(15, 16), "+")
+            .insert_after(Span::new(15, 16), "+");
+        assert_eq!(builder.span(), Span::new(0, 16));
+        let fix = builder.build().unwrap();
+        assert_eq!(fix.len(), 4);
+        assert_eq!(
+            fix.normalize_fixes(source_text),
+            Fix::new("const a = 1;foo(+a+", Span::new(0, 16))
+        );
+    }
+
+    #[test]
+    fn test_fix_builder_overlap() {
+        let mut builder = FixBuilder::new();
+        builder.replace(Span::new(0, 5), "foo").remove(Span::new(4, 8));
+        assert_eq!(
+            builder.validate(),
+            Err(OverlappingFixes { first: Span::new(0, 5), second: Span::new(4, 8) })
+        );
+        assert!(builder.build().is_err());
+
+        let mut builder = FixBuilder::new();
+        builder.insert_before(Span::new(3, 5), "a").insert_before(Span::new(3, 4), "b");
+        assert!(builder.validate().is_err());
+
+        // touching edits don't overlap
+        let mut builder = FixBuilder::new();
+        builder
+            .remove(Span::new(0, 3))
+            .insert_after(Span::new(0, 3), "x")
+            .replace(Span::new(3, 5), "y");
+        assert!(builder.validate().is_ok());
+        assert_eq!(FixBuilder::new().span(), SPAN);
+        assert_eq!(FixBuilder::new().build().unwrap(), CompositeFix::None);
+    }
 }
@@ -2,10 +2,11 @@ mod fix;
 
 use std::borrow::Cow;
 
-pub use fix::{CompositeFix, Fix, FixKind, RuleFix};
+pub use fix::{CompositeFix, Fix, FixBuilder, FixKind, RuleFix, Suggestion};
 use oxc_codegen::{CodeGenerator, CodegenOptions};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
+use similar::TextDiff;
 
 use crate::LintContext;
 
@@ -63,6 +64,13 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         RuleFix::new(self.kind, None, CompositeFix::Multiple(Vec::with_capacity(capacity)))
     }
 
+    /// Create a [`RuleFix`] from the edits collected in a [`FixBuilder`].
+    ///
+    /// If the edits overlap, the fix is empty and the diagnostic is reported without one.
+    pub fn build_fix(&self, builder: FixBuilder<'a>) -> RuleFix<'a> {
+        self.new_fix(builder.build().unwrap_or_default(), None)
+    }
+
     #[inline]
     pub fn source_text(&self) -> &'a str {
         self.ctx.source_text()
@@ -214,6 +222,8 @@ pub struct FixResult<'a> {
 pub struct Message<'a> {
     pub error: OxcDiagnostic,
     pub fix: Option<Fix<'a>>,
+    /// Fixes the user may opt into. These are never applied by [`Fixer`].
+    pub suggestions: Vec<Suggestion<'a>>,
     span: Span,
     fixed: bool,
 }
@@ -234,7 +244,13 @@ impl<'a> Message<'a> {
         } else {
             (0, 0)
         };
-        Self { error, span: Span::new(start, end), fix, fixed: false }
+        Self { error, span: Span::new(start, end), fix, suggestions: Vec::new(), fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion<'a>>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 }
 
@@ -314,6 +330,24 @@ impl<'a> Fixer<'a> {
         filtered_messages.sort_unstable_by_key(GetSpan::span);
         FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
     }
+
+    /// Preview the fixes as a unified diff, without applying them.
+    ///
+    /// Fixes are resolved exactly as [`Fixer::fix`] does, so overlapping fixes are skipped.
+    /// `file_name` is used in the `---` and `+++` headers, and each hunk has three lines of
+    /// context. Returns an empty string if there is nothing to fix.
+    pub fn diff(self, file_name: &str) -> String {
+        let source_text = self.source_text;
+        let result = self.fix();
+        if !result.fixed {
+            return String::new();
+        }
+        TextDiff::from_lines(source_text, result.fixed_code.as_ref())
+            .unified_diff()
+            .context_radius(3)
+            .header(file_name, file_name)
+            .to_string()
+    }
 }
 
 #[cfg(test)]
@@ -712,4 +746,29 @@ mod test {
 
         assert_fixes_merged(fixes, &Fix::new("baz\nqux", Span::new(0, 7)), source_text);
     }
+
+    #[test]
+    fn diff_without_fixes() {
+        let messages = vec![create_message(no_fix(Span::new(1, 1)), None)];
+        assert_eq!(Fixer::new(TEST_CODE, messages).diff("test.js"), "");
+    }
+
+    #[test]
+    fn diff_with_fixes_on_several_lines() {
+        let source_text = "var a = 1;\nfoo();\nbar();\nbaz();\nqux();\nquux();\nvar b = 2;\n";
+        let messages = vec![
+            create_message(replace_var(), Some(Fix::new("let", Span::new(0, 3)))),
+            create_message(replace_var(), Some(Fix::new("let", Span::new(47, 50)))),
+            create_message(replace_id(), Some(Fix::new("a;\nlet c", Span::new(51, 52)))),
+            // Overlaps the fix above, so it is not part of the diff.
+            create_message(replace_num(), Some(Fix::new("5", Span::new(51, 56)))),
+        ];
+        let diff = Fixer::new(source_text, messages).diff("test.js");
+        assert_eq!(
+            diff,
+            "--- test.js\n+++ test.js\n@@ -1,7 +1,8 @@\n\
+             -var a = 1;\n+let a = 1;\n foo();\n bar();\n baz();\n qux();\n quux();\n\
+             -var b = 2;\n+let a;\n+let c = 2;\n"
+        );
+    }
 }
@@ -1,17 +1,24 @@
 #![allow(clippy::self_named_module_files)] // for rules.rs
 
+// Allows `declare_oxc_lint!` to refer to `oxc_linter` both in and outside of this crate.
+extern crate self as oxc_linter;
+
 #[cfg(test)]
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
+mod diagnostics;
 mod disable_directives;
 mod fixer;
 mod frameworks;
 mod globals;
 mod javascript_globals;
 mod options;
+mod plugin;
+mod resolver;
 mod rule;
 mod rules;
 mod service;
@@ -20,26 +27,39 @@ mod utils;
 pub mod partial_loader;
 pub mod table;
 
-use std::{io::Write, path::Path, rc::Rc, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+    io::Write,
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 
 use config::LintConfig;
 use options::LintOptions;
 use oxc_diagnostics::Error;
 use oxc_semantic::{AstNode, Semantic};
+use rustc_hash::{FxHashMap, FxHasher};
+use schemars::gen::SchemaSettings;
 
 pub use crate::{
+    cache::{LintCache, RuleChanges},
     config::OxlintConfig,
     context::LintContext,
+    diagnostics::sort_and_dedup_diagnostics,
     fixer::FixKind,
     frameworks::FrameworkFlags,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, OxlintOptions},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
+    plugin::{DynRule, LintPlugin, PluginRule, PluginRuleDefinition, RuleRegistry},
+    resolver::{ModuleResolver, NodeModuleResolver},
+    rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
     service::{LintService, LintServiceOptions},
 };
 use crate::{
     config::{OxlintEnv, OxlintGlobals, OxlintSettings},
     fixer::{Fixer, Message},
-    rules::RuleEnum,
+    rules::{RuleEnum, RULES},
     table::RuleTable,
 };
 
@@ -113,15 +133,122 @@ impl Linter {
         self.rules.len()
     }
 
+    /// Hash of the resolved configuration: enabled rules, their severities and
+    /// options, plugin settings, and the linter version.
+    ///
+    /// Used to invalidate a [`LintCache`] when the configuration changes.
+    pub fn config_hash(&self) -> u64 {
+        let mut rules = self
+            .rules
+            .iter()
+            .map(|rule| (self.rule_code(rule), Self::rule_config(rule)))
+            .collect::<Vec<_>>();
+        rules.sort_unstable();
+
+        let mut hasher = FxHasher::default();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        rules.hash(&mut hasher);
+        self.shared_config().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The rules that differ between `previous` and this linter, so that diagnostics cached
+    /// for `previous` can be reused for the rules that did not change.
+    ///
+    /// Returns `None` if anything other than the enabled rules, their severities and their
+    /// options changed, e.g. settings or globals. All rules must run again in that case.
+    pub fn diff_rules(&self, previous: &Linter) -> Option<RuleChanges> {
+        if self.shared_config() != previous.shared_config() {
+            return None;
+        }
+
+        let rules_by_code = |linter: &Linter| {
+            linter
+                .rules
+                .iter()
+                .map(|rule| (linter.rule_code(rule), Self::rule_config(rule)))
+                .collect::<FxHashMap<_, _>>()
+        };
+        let old_rules = rules_by_code(previous);
+        let new_rules = rules_by_code(self);
+
+        let mut changes = RuleChanges::default();
+        for (code, config) in &new_rules {
+            match old_rules.get(code) {
+                None => changes.added.push(code.clone()),
+                Some(old_config) if old_config != config => changes.reconfigured.push(code.clone()),
+                Some(_) => {}
+            }
+        }
+        changes.removed =
+            old_rules.into_keys().filter(|code| !new_rules.contains_key(code)).collect();
+        changes.added.sort_unstable();
+        changes.removed.sort_unstable();
+        changes.reconfigured.sort_unstable();
+        Some(changes)
+    }
+
+    /// The severity and options of `rule`, as they were configured.
+    fn rule_config(rule: &RuleWithSeverity) -> (&'static str, String) {
+        let options = rule.options().map(ToString::to_string).unwrap_or_default();
+        (rule.severity.as_str(), options)
+    }
+
+    /// Everything in the configuration that applies to all rules: fix kind, framework hints,
+    /// plugins, settings, environments and globals.
+    fn shared_config(&self) -> (u8, u32, u16, String) {
+        // Going through a `Value` sorts the keys of the hash maps in the config.
+        let config = serde_json::to_value(&*self.config)
+            .map(|config| config.to_string())
+            .unwrap_or_default();
+        (
+            self.options.fix.bits(),
+            self.options.framework_hints.bits(),
+            self.options.plugins.bits(),
+            config,
+        )
+    }
+
+    /// The error code of the diagnostics reported by `rule`, e.g. `eslint(no-debugger)`.
+    fn rule_code(&self, rule: &RuleWithSeverity) -> String {
+        let plugin_name = self.map_jest(rule.plugin_name(), rule.name());
+        format!("{}({})", context::plugin_name_to_prefix(plugin_name), rule.name())
+    }
+
+    /// Lint a file.
+    ///
+    /// Rules run in a fixed order: for each AST node, rules are run sorted by name, with the
+    /// plugin name breaking ties. Diagnostics are returned in that order. Reporters should sort
+    /// them with [`sort_and_dedup_diagnostics`] to order them by span, then rule name.
     // pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
     pub fn run<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> Vec<Message<'a>> {
+        self.run_filtered(path, semantic, |_| true)
+    }
+
+    /// Lint a file with only the rules whose error codes (see [`Linter::diff_rules`]) are in
+    /// `codes`.
+    pub(crate) fn run_rules<'a>(
+        &self,
+        path: &Path,
+        semantic: Rc<Semantic<'a>>,
+        codes: &[String],
+    ) -> Vec<Message<'a>> {
+        self.run_filtered(path, semantic, |rule| codes.contains(&self.rule_code(rule)))
+    }
+
+    fn run_filtered<'a>(
+        &self,
+        path: &Path,
+        semantic: Rc<Semantic<'a>>,
+        filter: impl Fn(&RuleWithSeverity) -> bool,
+    ) -> Vec<Message<'a>> {
         let ctx = self.create_ctx(path, semantic);
         let semantic = Rc::clone(ctx.semantic());
 
         let rules = self
             .rules
             .iter()
-            .filter(|rule| rule.should_run(&ctx))
+            .filter(|rule| filter(rule) && rule.should_run(&ctx))
             .map(|rule| (rule, self.ctx_for_rule(&ctx, rule)))
             .collect::<Vec<_>>();
 
@@ -154,6 +281,25 @@ impl Linter {
         writeln!(writer, "Total: {}", table.total).unwrap();
     }
 
+    /// Print the JSON Schemas of the options of all rules that have any, as a JSON object
+    /// keyed by `plugin/rule-name`.
+    ///
+    /// # Panics
+    pub fn print_schemas<W: Write>(writer: &mut W) {
+        let mut gen = SchemaSettings::draft07()
+            .with(|settings| settings.inline_subschemas = true)
+            .into_generator();
+        let schemas = RULES
+            .iter()
+            .filter_map(|rule| {
+                let schema = rule.schema(&mut gen)?;
+                Some((format!("{}/{}", rule.plugin_name(), rule.name()), schema))
+            })
+            .collect::<BTreeMap<_, _>>();
+        serde_json::to_writer_pretty(&mut *writer, &schemas).unwrap();
+        writeln!(writer).unwrap();
+    }
+
     fn create_ctx<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> LintContext<'a> {
         let mut ctx = LintContext::new(path.to_path_buf().into_boxed_path(), semantic)
             .with_fix(self.options.fix)
@@ -204,7 +350,69 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::{Linter, OxlintConfig};
+    use std::{env, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, GraphicalTheme, NamedSource};
+
+    use super::{
+        sort_and_dedup_diagnostics, LintFilter, LintService, LintServiceOptions, Linter,
+        OxlintConfig, OxlintOptions, RuleCategory,
+    };
+
+    /// Lint `source_text` with the rules enabled by `filter` and render the report.
+    fn render_report(filter: Vec<LintFilter>, source_text: &str) -> String {
+        let linter = Linter::from_options(OxlintOptions::default().with_filter(filter)).unwrap();
+        let cwd = env::current_dir().unwrap().into_boxed_path();
+        let paths = vec![Path::new("fixture.js").into()];
+        let service = LintService::from_linter(linter, LintServiceOptions::new(cwd, paths));
+        let allocator = Allocator::default();
+        let diagnostic_service = DiagnosticService::default();
+        let messages =
+            service.run_source(&allocator, source_text, false, diagnostic_service.sender());
+
+        let mut diagnostics = messages.into_iter().map(|message| message.error).collect();
+        sort_and_dedup_diagnostics(&mut diagnostics);
+        let handler = GraphicalReportHandler::new()
+            .with_links(false)
+            .with_theme(GraphicalTheme::unicode_nocolor());
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let diagnostic = diagnostic
+                .with_source_code(NamedSource::new("fixture.js", source_text.to_string()));
+            handler.render_report(&mut output, diagnostic.as_ref()).unwrap();
+        }
+        output
+    }
+
+    #[test]
+    fn deterministic_output() {
+        let source_text = "
+            debugger;
+            if (a == b) { var x = 1; var x = 2; }
+            while (y) {}
+            new Promise(async (resolve) => { await resolve(); });
+            [1, 2].map((x) => { x; });
+            typeof foo === 'strnig';
+        ";
+        let filter = || {
+            vec![
+                LintFilter::warn(RuleCategory::Correctness),
+                LintFilter::warn(RuleCategory::Suspicious),
+                LintFilter::warn(RuleCategory::Pedantic),
+                LintFilter::warn(RuleCategory::Style),
+            ]
+        };
+
+        let first = render_report(filter(), source_text);
+        assert!(!first.is_empty());
+        assert_eq!(first, render_report(filter(), source_text));
+
+        // The order in which rules are enabled does not matter.
+        let mut reversed = filter();
+        reversed.reverse();
+        assert_eq!(first, render_report(reversed, source_text));
+    }
 
     #[test]
     fn print_rules() {
@@ -213,6 +421,24 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn print_schemas() {
+        let mut writer = Vec::new();
+        Linter::print_schemas(&mut writer);
+        let schemas: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+
+        let destructuring = &schemas["eslint/prefer-const"]["properties"]["destructuring"];
+        let values = destructuring["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|variant| variant["enum"].as_array().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["any", "all"]);
+        // Rules without options have no schema.
+        assert!(schemas.get("eslint/no-debugger").is_none());
+    }
+
     #[test]
     fn test_schema_json() {
         use std::fs;
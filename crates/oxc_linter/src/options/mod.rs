@@ -6,7 +6,7 @@ use std::{convert::From, path::PathBuf};
 
 use filter::LintFilterKind;
 use oxc_diagnostics::Error;
-use plugins::LintPlugins;
+pub(crate) use plugins::LintPlugins;
 use rustc_hash::FxHashSet;
 
 pub use allow_warn_deny::AllowWarnDeny;
@@ -16,7 +16,7 @@ pub use plugins::LintPluginOptions;
 use crate::{
     config::{LintConfig, OxlintConfig},
     fixer::FixKind,
-    rules::RULES,
+    plugin::RuleRegistry,
     utils::is_jest_rule_adapted_to_vitest,
     FrameworkFlags, RuleCategory, RuleEnum, RuleWithSeverity,
 };
@@ -58,6 +58,11 @@ pub struct OxlintOptions {
     pub plugins: LintPluginOptions,
 
     pub framework_hints: FrameworkFlags,
+
+    /// Built-in rules and rules from registered [`LintPlugin`]s.
+    ///
+    /// [`LintPlugin`]: crate::LintPlugin
+    pub registry: RuleRegistry,
 }
 
 impl Default for OxlintOptions {
@@ -68,6 +73,7 @@ impl Default for OxlintOptions {
             fix: FixKind::None,
             plugins: LintPluginOptions::default(),
             framework_hints: FrameworkFlags::default(),
+            registry: RuleRegistry::default(),
         }
     }
 }
@@ -103,6 +109,13 @@ impl OxlintOptions {
         self
     }
 
+    /// Use the rules in `registry`, which may contain rules from third-party plugins.
+    #[must_use]
+    pub fn with_rule_registry(mut self, registry: RuleRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
     #[must_use]
     pub fn with_react_plugin(mut self, yes: bool) -> Self {
         self.plugins.react = yes;
@@ -195,6 +208,15 @@ impl OxlintOptions {
         let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
 
+        // Plugin rules start out with their default severity and are then filtered like any
+        // other rule.
+        rules.extend(self.registry.plugin_rules().iter().filter_map(|rule| match rule {
+            RuleEnum::Plugin(plugin_rule) if plugin_rule.default_severity().is_warn_deny() => {
+                Some(RuleWithSeverity::new(rule.clone(), plugin_rule.default_severity()))
+            }
+            _ => None,
+        }));
+
         for (severity, filter) in self.filter.iter().map(Into::into) {
             match severity {
                 AllowWarnDeny::Deny | AllowWarnDeny::Warn => match filter {
@@ -206,11 +228,11 @@ impl OxlintOptions {
                                 .map(|rule| RuleWithSeverity::new(rule.clone(), severity)),
                         );
                     }
-                    LintFilterKind::Rule(_, name) => {
+                    LintFilterKind::Rule(plugin, name) => {
                         rules.extend(
                             all_rules
                                 .iter()
-                                .filter(|rule| rule.name() == name)
+                                .filter(|rule| is_filtered_rule(rule, *plugin, name))
                                 .map(|rule| RuleWithSeverity::new(rule.clone(), severity)),
                         );
                     }
@@ -236,8 +258,8 @@ impl OxlintOptions {
                     LintFilterKind::Category(category) => {
                         rules.retain(|rule| rule.category() != *category);
                     }
-                    LintFilterKind::Rule(_, name) => {
-                        rules.retain(|rule| rule.name() != name);
+                    LintFilterKind::Rule(plugin, name) => {
+                        rules.retain(|rule| !is_filtered_rule(rule, *plugin, name));
                     }
                     LintFilterKind::Generic(name_or_category) => {
                         if name_or_category == "all" {
@@ -256,17 +278,19 @@ impl OxlintOptions {
 
         let mut rules = rules.into_iter().collect::<Vec<_>>();
 
-        // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(|rule| rule.id());
+        // Rules run in order of their names, see `Linter::run`.
+        rules.sort_unstable_by_key(|rule| (rule.name(), rule.plugin_name()));
 
         Ok((rules, config.map(Into::into).unwrap_or_default()))
     }
 
     /// Get final filtered rules by reading `self.xxx_plugin`
     fn get_filtered_rules(&self) -> Vec<RuleEnum> {
-        RULES
-            .iter()
+        self.registry
+            .rules()
             .filter(|rule| match rule.plugin_name() {
+                // Registering a plugin enables it.
+                _ if matches!(rule, RuleEnum::Plugin(_)) => true,
                 "react" => self.plugins.react,
                 "unicorn" => self.plugins.unicorn,
                 "typescript" => self.plugins.typescript,
@@ -295,3 +319,16 @@ impl OxlintOptions {
             .collect::<Vec<_>>()
     }
 }
+
+/// Whether a `<plugin>/<rule>` filter selects `rule`. Several plugins can have a rule with the
+/// same name, e.g. `eslint/no-nested-ternary` and `unicorn/no-nested-ternary`.
+fn is_filtered_rule(rule: &RuleEnum, plugin: LintPlugins, name: &str) -> bool {
+    if rule.name() != name {
+        return false;
+    }
+    let rule_plugin = LintPlugins::from(rule.plugin_name());
+    rule_plugin == plugin
+        || (rule_plugin == LintPlugins::JEST
+            && plugin == LintPlugins::VITEST
+            && is_jest_rule_adapted_to_vitest(name))
+}
@@ -0,0 +1,456 @@
+//! Registration of lint rules that live outside of this crate.
+//!
+//! A third-party crate declares its rules with [`declare_oxc_lint!`] like the built-in rules do,
+//! implements [`LintPlugin`] to expose them, and registers the plugin in a [`RuleRegistry`]:
+//!
+//! ```ignore
+//! let mut registry = RuleRegistry::default();
+//! registry.register(&MyPlugin)?;
+//! let options = OxlintOptions::default().with_rule_registry(registry);
+//! ```
+//!
+//! [`declare_oxc_lint!`]: oxc_macros::declare_oxc_lint
+
+use std::{fmt, sync::Arc};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::SymbolId;
+use rustc_hash::FxHashSet;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    options::LintPlugins,
+    rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta},
+    rules::{RuleEnum, RULES},
+    AllowWarnDeny, AstNode,
+};
+
+fn duplicate_rule_diagnostic(plugin_name: &str, rule_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Failed to register plugin '{plugin_name}': it has more than one rule named '{rule_name}'."
+    ))
+    .with_help("Rule names must be unique within a plugin.")
+}
+
+fn duplicate_plugin_diagnostic(plugin_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Failed to register plugin '{plugin_name}': a plugin with this name already exists."
+    ))
+}
+
+fn built_in_plugin_diagnostic(plugin_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Failed to register plugin '{plugin_name}': this name is used by a built-in plugin."
+    ))
+}
+
+/// An object-safe version of [`Rule`] and [`RuleMeta`].
+///
+/// This is implemented for every type that implements both traits, so plugins never need to
+/// implement it by hand.
+pub trait DynRule: fmt::Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory;
+
+    fn fix(&self) -> RuleFixMeta;
+
+    fn documentation(&self) -> Option<&'static str>;
+
+    /// Create a new instance of this rule from its eslint json configuration.
+    fn read_json(&self, value: Value) -> Box<dyn DynRule>;
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>);
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>);
+
+    fn run_once(&self, ctx: &LintContext);
+
+    fn should_run(&self, ctx: &LintContext) -> bool;
+}
+
+impl<T> DynRule for T
+where
+    T: Rule + RuleMeta + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        T::NAME
+    }
+
+    fn category(&self) -> RuleCategory {
+        T::CATEGORY
+    }
+
+    fn fix(&self) -> RuleFixMeta {
+        T::FIX
+    }
+
+    fn documentation(&self) -> Option<&'static str> {
+        T::documentation()
+    }
+
+    fn read_json(&self, value: Value) -> Box<dyn DynRule> {
+        Box::new(T::from_configuration(value))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        Rule::run(self, node, ctx);
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        Rule::run_on_symbol(self, symbol_id, ctx);
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        Rule::run_once(self, ctx);
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        Rule::should_run(self, ctx)
+    }
+}
+
+/// A rule provided by a [`LintPlugin`], along with its default severity.
+#[derive(Debug)]
+pub struct PluginRuleDefinition {
+    rule: Box<dyn DynRule>,
+    default_severity: AllowWarnDeny,
+}
+
+impl PluginRuleDefinition {
+    /// Wrap `rule`. The rule is turned off unless it is enabled by a filter or a config file.
+    pub fn new<R: DynRule + 'static>(rule: R) -> Self {
+        Self { rule: Box::new(rule), default_severity: AllowWarnDeny::Allow }
+    }
+
+    /// Enable the rule with `severity` when no filter or config file says otherwise.
+    #[must_use]
+    pub fn with_default_severity(mut self, severity: AllowWarnDeny) -> Self {
+        self.default_severity = severity;
+        self
+    }
+}
+
+/// A set of rules compiled outside of this crate.
+pub trait LintPlugin {
+    /// The plugin name, used as the rule prefix in config files, e.g. `my-plugin/my-rule`.
+    fn name(&self) -> &'static str;
+
+    fn rules(&self) -> Vec<PluginRuleDefinition>;
+}
+
+/// A registered plugin rule.
+///
+/// Cheap to clone, and small enough to keep [`RuleEnum`] at 16 bytes.
+#[derive(Debug, Clone)]
+pub struct PluginRule(Arc<PluginRuleInner>);
+
+#[derive(Debug)]
+struct PluginRuleInner {
+    id: usize,
+    plugin_name: &'static str,
+    default_severity: AllowWarnDeny,
+    rule: Box<dyn DynRule>,
+}
+
+impl PluginRule {
+    pub fn id(&self) -> usize {
+        self.0.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0.rule.name()
+    }
+
+    pub fn category(&self) -> RuleCategory {
+        self.0.rule.category()
+    }
+
+    pub fn fix(&self) -> RuleFixMeta {
+        self.0.rule.fix()
+    }
+
+    pub fn documentation(&self) -> Option<&'static str> {
+        self.0.rule.documentation()
+    }
+
+    pub fn plugin_name(&self) -> &'static str {
+        self.0.plugin_name
+    }
+
+    pub fn default_severity(&self) -> AllowWarnDeny {
+        self.0.default_severity
+    }
+
+    #[must_use]
+    pub fn read_json(&self, value: Value) -> Self {
+        Self(Arc::new(PluginRuleInner {
+            id: self.0.id,
+            plugin_name: self.0.plugin_name,
+            default_severity: self.0.default_severity,
+            rule: self.0.rule.read_json(value),
+        }))
+    }
+
+    pub(crate) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        self.0.rule.run(node, ctx);
+    }
+
+    pub(crate) fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        self.0.rule.run_on_symbol(symbol_id, ctx);
+    }
+
+    pub(crate) fn run_once(&self, ctx: &LintContext) {
+        self.0.rule.run_once(ctx);
+    }
+
+    pub(crate) fn should_run(&self, ctx: &LintContext) -> bool {
+        self.0.rule.should_run(ctx)
+    }
+}
+
+/// All rules known to the linter: the built-in rules followed by the rules of every registered
+/// [`LintPlugin`].
+#[derive(Debug, Default, Clone)]
+pub struct RuleRegistry {
+    plugin_names: Vec<&'static str>,
+    plugin_rules: Vec<RuleEnum>,
+}
+
+impl RuleRegistry {
+    /// Add the rules of `plugin` to the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` without registering anything if the plugin name is already taken, by a
+    /// built-in plugin like `eslint` or `react` or by a registered plugin, or if two of its rules
+    /// have the same name. Rules of other plugins may share a name with them.
+    pub fn register(&mut self, plugin: &dyn LintPlugin) -> Result<(), OxcDiagnostic> {
+        let plugin_name = plugin.name();
+        if is_built_in_plugin(plugin_name) {
+            return Err(built_in_plugin_diagnostic(plugin_name));
+        }
+        if self.plugin_names.contains(&plugin_name) {
+            return Err(duplicate_plugin_diagnostic(plugin_name));
+        }
+
+        let mut names = FxHashSet::default();
+        let mut new_rules = vec![];
+        for PluginRuleDefinition { rule, default_severity } in plugin.rules() {
+            if !names.insert(rule.name()) {
+                return Err(duplicate_rule_diagnostic(plugin_name, rule.name()));
+            }
+            let id = RULES.len() + self.plugin_rules.len() + new_rules.len();
+            new_rules.push(RuleEnum::Plugin(PluginRule(Arc::new(PluginRuleInner {
+                id,
+                plugin_name,
+                default_severity,
+                rule,
+            }))));
+        }
+
+        self.plugin_names.push(plugin_name);
+        self.plugin_rules.extend(new_rules);
+        Ok(())
+    }
+
+    /// Built-in rules followed by plugin rules, in registration order.
+    pub fn rules(&self) -> impl Iterator<Item = &RuleEnum> {
+        RULES.iter().chain(&self.plugin_rules)
+    }
+
+    /// Rules added by registered plugins.
+    pub fn plugin_rules(&self) -> &[RuleEnum] {
+        &self.plugin_rules
+    }
+}
+
+/// Whether `plugin_name` names a built-in plugin, including aliases like `@typescript-eslint`
+/// that are accepted in configs.
+fn is_built_in_plugin(plugin_name: &str) -> bool {
+    plugin_name == "eslint"
+        || !LintPlugins::from(plugin_name).is_empty()
+        || RULES.iter().any(|rule| rule.plugin_name() == plugin_name)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::AstKind;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_macros::{declare_oxc_lint, declare_oxc_lint_test};
+    use oxc_span::Span;
+    use serde_json::Value;
+
+    use super::{LintPlugin, PluginRuleDefinition, RuleRegistry};
+    // Import through the public API, as a plugin crate would.
+    use oxc_linter::{AllowWarnDeny, LintContext, Rule};
+
+    use crate::{tester::Tester, AstNode, RuleEnum};
+
+    fn no_identifier_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!("Unexpected identifier '{name}'.")).with_label(span)
+    }
+
+    #[derive(Debug, Clone)]
+    struct NoIdentifier {
+        name: String,
+    }
+
+    impl Default for NoIdentifier {
+        fn default() -> Self {
+            Self { name: "foo".to_string() }
+        }
+    }
+
+    declare_oxc_lint!(
+        /// ### What it does
+        ///
+        /// Disallow references to an identifier, `foo` by default.
+        NoIdentifier,
+        restriction
+    );
+
+    impl Rule for NoIdentifier {
+        fn from_configuration(value: Value) -> Self {
+            value
+                .get(0)
+                .and_then(|config| config.get("name"))
+                .and_then(Value::as_str)
+                .map_or_else(Self::default, |name| Self { name: name.to_string() })
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            if let AstKind::IdentifierReference(ident) = node.kind() {
+                if ident.name == self.name.as_str() {
+                    ctx.diagnostic(no_identifier_diagnostic(&ident.name, ident.span));
+                }
+            }
+        }
+    }
+
+    struct ExamplePlugin;
+
+    impl LintPlugin for ExamplePlugin {
+        fn name(&self) -> &'static str {
+            "example"
+        }
+
+        fn rules(&self) -> Vec<PluginRuleDefinition> {
+            vec![PluginRuleDefinition::new(NoIdentifier::default())
+                .with_default_severity(AllowWarnDeny::Warn)]
+        }
+    }
+
+    /// Reuses the name of the built-in `no-debugger` rule.
+    #[derive(Debug, Default, Clone)]
+    struct NoDebugger;
+
+    declare_oxc_lint_test!(
+        /// ### What it does
+        ///
+        /// Shares its name with a built-in rule.
+        NoDebugger,
+        correctness
+    );
+
+    impl Rule for NoDebugger {}
+
+    struct DebuggerPlugin;
+
+    impl LintPlugin for DebuggerPlugin {
+        fn name(&self) -> &'static str {
+            "debugger"
+        }
+
+        fn rules(&self) -> Vec<PluginRuleDefinition> {
+            vec![PluginRuleDefinition::new(NoDebugger)]
+        }
+    }
+
+    struct ClashingPlugin;
+
+    impl LintPlugin for ClashingPlugin {
+        fn name(&self) -> &'static str {
+            "clashing"
+        }
+
+        fn rules(&self) -> Vec<PluginRuleDefinition> {
+            vec![PluginRuleDefinition::new(NoDebugger), PluginRuleDefinition::new(NoDebugger)]
+        }
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = RuleRegistry::default();
+        registry.register(&ExamplePlugin).unwrap();
+
+        let [RuleEnum::Plugin(rule)] = registry.plugin_rules() else {
+            panic!("expected a single plugin rule");
+        };
+        assert_eq!(rule.name(), "no-identifier");
+        assert_eq!(rule.plugin_name(), "example");
+        assert_eq!(rule.default_severity(), AllowWarnDeny::Warn);
+        assert!(registry.rules().filter(|r| r.name() == "no-identifier").count() == 1);
+
+        let err = registry.register(&ExamplePlugin).unwrap_err();
+        assert!(err.to_string().contains("a plugin with this name already exists"));
+    }
+
+    #[test]
+    fn register_name_collision() {
+        let mut registry = RuleRegistry::default();
+        let err = registry.register(&ClashingPlugin).unwrap_err();
+        assert!(err.to_string().contains("it has more than one rule named 'no-debugger'"));
+        assert!(registry.plugin_rules().is_empty());
+
+        // Rule names only need to be unique within a plugin.
+        registry.register(&DebuggerPlugin).unwrap();
+        let plugins = registry
+            .rules()
+            .filter(|r| r.name() == "no-debugger")
+            .map(RuleEnum::plugin_name)
+            .collect::<Vec<_>>();
+        assert_eq!(plugins, vec!["eslint", "debugger"]);
+    }
+
+    #[test]
+    fn register_built_in_plugin_name() {
+        struct NamedPlugin(&'static str);
+
+        impl LintPlugin for NamedPlugin {
+            fn name(&self) -> &'static str {
+                self.0
+            }
+
+            fn rules(&self) -> Vec<PluginRuleDefinition> {
+                vec![PluginRuleDefinition::new(NoIdentifier::default())]
+            }
+        }
+
+        let mut registry = RuleRegistry::default();
+        for name in
+            ["eslint", "react", "typescript", "@typescript-eslint", "jsx_a11y", "tree_shaking"]
+        {
+            let err = registry.register(&NamedPlugin(name)).unwrap_err();
+            assert!(err.to_string().contains("this name is used by a built-in plugin"), "{name}");
+        }
+        assert!(registry.plugin_rules().is_empty());
+    }
+
+    #[test]
+    fn test() {
+        use serde_json::json;
+
+        let pass = vec![
+            ("bar", None),
+            ("let foo; bar", Some(json!([{ "name": "baz" }]))),
+            ("obj.foo", None),
+        ];
+
+        let fail =
+            vec![("foo", None), ("bar(foo)", None), ("baz", Some(json!([{ "name": "baz" }])))];
+
+        Tester::new(NoIdentifier::NAME, pass, fail).with_plugin(&ExamplePlugin).test();
+    }
+}
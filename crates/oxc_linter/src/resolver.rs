@@ -0,0 +1,125 @@
+//! Module resolution for the import plugin.
+//!
+//! When the import plugin is enabled, [`LintService`] resolves every import specifier of a
+//! linted file with a [`ModuleResolver`], and loads the module records of the resolved files.
+//! Rules such as `import/no-cycle` then follow these records across files.
+//!
+//! By default [`NodeModuleResolver`] is used. Editors and other hosts that already know how
+//! to resolve modules can supply their own with [`LintServiceOptions::with_resolver`].
+//!
+//! [`LintService`]: crate::LintService
+//! [`LintServiceOptions::with_resolver`]: crate::LintServiceOptions::with_resolver
+use std::path::{Path, PathBuf};
+
+use oxc_resolver::{Resolution, ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use oxc_span::VALID_EXTENSIONS;
+
+/// Maps import specifiers to files.
+pub trait ModuleResolver: Send + Sync {
+    /// Resolves `specifier`, imported by a file in `directory`, to the absolute path of a file.
+    ///
+    /// Returns `None` if the specifier cannot be resolved, e.g. for a missing file or a Node.js
+    /// builtin module.
+    fn resolve(&self, directory: &Path, specifier: &str) -> Option<PathBuf>;
+}
+
+/// Resolves specifiers the way Node.js and bundlers do.
+///
+/// Relative and absolute specifiers are tried with each supported extension and as a
+/// directory with an `index` file. Bare specifiers are looked up in `node_modules`, using the
+/// `exports` and `main` fields of `package.json`. Path aliases of a `tsconfig.json` are
+/// applied first.
+pub struct NodeModuleResolver {
+    resolver: Resolver,
+}
+
+impl NodeModuleResolver {
+    /// Create a resolver that reads path aliases and project references from `tsconfig`, if
+    /// it is a file.
+    pub fn new(tsconfig: Option<PathBuf>) -> Self {
+        let tsconfig = tsconfig.filter(|path| path.is_file()).map(|path| TsconfigOptions {
+            config_file: path,
+            references: TsconfigReferences::Auto,
+        });
+
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            condition_names: vec!["module".into(), "require".into()],
+            tsconfig,
+            ..ResolveOptions::default()
+        });
+        Self { resolver }
+    }
+}
+
+impl ModuleResolver for NodeModuleResolver {
+    fn resolve(&self, directory: &Path, specifier: &str) -> Option<PathBuf> {
+        self.resolver.resolve(directory, specifier).ok().map(Resolution::into_path_buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex},
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::DiagnosticService;
+
+    use super::{ModuleResolver, NodeModuleResolver};
+    use crate::{LintService, LintServiceOptions, Linter, OxlintOptions};
+
+    #[test]
+    fn node_module_resolver() {
+        let directory = env::current_dir().unwrap().join("fixtures/import");
+        let resolver = NodeModuleResolver::new(None);
+        let resolve = |specifier: &str| resolver.resolve(&directory, specifier);
+
+        assert_eq!(resolve("./bar.js"), Some(directory.join("bar.js")));
+        // Extensions and directory indexes are tried.
+        assert_eq!(resolve("./bar"), Some(directory.join("bar.js")));
+        assert_eq!(resolve("."), Some(directory.join("index.js")));
+        // Bare specifiers are looked up in `node_modules`.
+        assert!(resolve("esm-package").is_some_and(|path| path.starts_with(&directory)));
+
+        assert_eq!(resolve("./does-not-exist"), None);
+        assert_eq!(resolve("fs"), None);
+    }
+
+    /// Resolves every specifier to `bar.js` and records the specifiers it was asked for.
+    #[derive(Default)]
+    struct RecordingResolver {
+        specifiers: Mutex<Vec<String>>,
+    }
+
+    impl ModuleResolver for RecordingResolver {
+        fn resolve(&self, directory: &Path, specifier: &str) -> Option<PathBuf> {
+            self.specifiers.lock().unwrap().push(specifier.to_string());
+            Some(directory.join("bar.js"))
+        }
+    }
+
+    #[test]
+    fn custom_resolver() {
+        let cwd = env::current_dir().unwrap().join("fixtures/import");
+        let paths = vec![cwd.join("resolver-test.js").into_boxed_path()];
+        let resolver = Arc::new(RecordingResolver::default());
+        let options = LintServiceOptions::new(cwd, paths)
+            .with_resolver(Arc::clone(&resolver) as Arc<dyn ModuleResolver>);
+        let linter =
+            Linter::from_options(OxlintOptions::default().with_import_plugin(true)).unwrap();
+        let service = LintService::from_linter(linter, options);
+
+        let allocator = Allocator::default();
+        let diagnostic_service = DiagnosticService::default();
+        let source_text = "import a from 'virtual:a'; import b from '@scope/b';";
+        service.run_source(&allocator, source_text, false, diagnostic_service.sender());
+
+        let mut specifiers = resolver.specifiers.lock().unwrap().clone();
+        specifiers.sort_unstable();
+        assert_eq!(specifiers, ["@scope/b", "virtual:a"]);
+    }
+}
@@ -3,9 +3,11 @@ use std::{
     fmt,
     hash::{Hash, Hasher},
     ops::Deref,
+    sync::Arc,
 };
 
 use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema};
 
 use crate::{context::LintContext, AllowWarnDeny, AstNode, FixKind, RuleEnum};
 
@@ -15,6 +17,15 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// JSON Schema of the options object accepted by [`Rule::from_configuration`], used by
+    /// editors to validate and complete configuration files.
+    ///
+    /// Returns `None` if the rule has no options.
+    #[expect(unused_variables)]
+    fn schema(gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
+
     /// Visit each AST Node
     #[expect(unused_variables)]
     #[inline]
@@ -234,6 +245,8 @@ impl From<RuleFixMeta> for FixKind {
 pub struct RuleWithSeverity {
     pub rule: RuleEnum,
     pub severity: AllowWarnDeny,
+    /// The options the rule was configured with in the config file, if any.
+    options: Option<Arc<serde_json::Value>>,
 }
 
 impl Hash for RuleWithSeverity {
@@ -260,7 +273,20 @@ impl Deref for RuleWithSeverity {
 
 impl RuleWithSeverity {
     pub fn new(rule: RuleEnum, severity: AllowWarnDeny) -> Self {
-        Self { rule, severity }
+        Self { rule, severity, options: None }
+    }
+
+    /// Record the options `rule` was configured with, so that configuration changes can be
+    /// detected without comparing rules.
+    #[must_use]
+    pub fn with_options(mut self, options: serde_json::Value) -> Self {
+        self.options = Some(Arc::new(options));
+        self
+    }
+
+    /// The options the rule was configured with, see [`RuleWithSeverity::with_options`].
+    pub fn options(&self) -> Option<&serde_json::Value> {
+        self.options.as_deref()
     }
 }
 
@@ -23,10 +23,12 @@ mod import {
     pub mod no_named_as_default_member;
     pub mod no_self_import;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
 }
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod consistent_return;
     pub mod constructor_super;
     pub mod default_case;
     pub mod default_case_last;
@@ -34,9 +36,14 @@ mod eslint {
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod func_names;
+    pub mod func_style;
     pub mod getter_return;
     pub mod guard_for_in;
+    pub mod linebreak_style;
+    pub mod lines_between_class_members;
+    pub mod logical_assignment_operators;
     pub mod max_classes_per_file;
+    pub mod max_depth;
     pub mod max_lines;
     pub mod max_params;
     pub mod no_alert;
@@ -63,6 +70,8 @@ mod eslint {
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
     pub mod no_duplicate_case;
+    pub mod no_duplicate_imports;
+    pub mod no_else_return;
     pub mod no_empty;
     pub mod no_empty_character_class;
     pub mod no_empty_function;
@@ -72,24 +81,31 @@ mod eslint {
     pub mod no_eval;
     pub mod no_ex_assign;
     pub mod no_extra_boolean_cast;
+    pub mod no_extra_parens;
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
+    pub mod no_implicit_coercion;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
     pub mod no_invalid_regexp;
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
     pub mod no_label_var;
+    pub mod no_lonely_if;
     pub mod no_loss_of_precision;
     pub mod no_magic_numbers;
+    pub mod no_multi_assign;
     pub mod no_multi_str;
+    pub mod no_multiple_empty_lines;
+    pub mod no_nested_ternary;
     pub mod no_new;
     pub mod no_new_func;
     pub mod no_new_native_nonconstructor;
     pub mod no_new_wrappers;
     pub mod no_nonoctal_decimal_escape;
     pub mod no_obj_calls;
+    pub mod no_param_reassign;
     pub mod no_proto;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
@@ -98,14 +114,19 @@ mod eslint {
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
+    pub mod no_sequences;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
     pub mod no_ternary;
     pub mod no_this_before_super;
+    pub mod no_trailing_spaces;
     pub mod no_undef;
     pub mod no_undefined;
+    pub mod no_unmodified_loop_condition;
+    pub mod no_unneeded_ternary;
     pub mod no_unreachable;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
@@ -113,6 +134,7 @@ mod eslint {
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
+    pub mod no_useless_backreference;
     pub mod no_useless_catch;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
@@ -121,8 +143,15 @@ mod eslint {
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod object_shorthand;
+    pub mod padded_blocks;
+    pub mod prefer_const;
     pub mod prefer_exponentiation_operator;
+    pub mod prefer_named_capture_group;
     pub mod prefer_numeric_literals;
+    pub mod prefer_object_has_own;
+    pub mod prefer_rest_params;
+    pub mod prefer_template;
     pub mod radix;
     pub mod require_await;
     pub mod require_yield;
@@ -152,6 +181,7 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_misused_new;
     pub mod no_namespace;
@@ -159,6 +189,7 @@ mod typescript {
     pub mod no_non_null_asserted_optional_chain;
     pub mod no_non_null_assertion;
     pub mod no_this_alias;
+    pub mod no_unnecessary_condition;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
     pub mod no_useless_empty_export;
@@ -237,6 +268,7 @@ mod react {
     pub mod jsx_no_undef;
     pub mod jsx_no_useless_fragment;
     pub mod jsx_props_no_spread_multi;
+    pub mod no_array_index_key;
     pub mod no_children_prop;
     pub mod no_danger;
     pub mod no_direct_mutation_state;
@@ -485,6 +517,7 @@ oxc_macros::declare_all_lint_rules! {
     // import::no_deprecated,
     // import::no_unused_modules,
     eslint::array_callback_return,
+    eslint::consistent_return,
     eslint::constructor_super,
     eslint::default_case,
     eslint::default_case_last,
@@ -492,9 +525,14 @@ oxc_macros::declare_all_lint_rules! {
     eslint::eqeqeq,
     eslint::for_direction,
     eslint::func_names,
+    eslint::func_style,
     eslint::getter_return,
     eslint::guard_for_in,
+    eslint::linebreak_style,
+    eslint::lines_between_class_members,
+    eslint::logical_assignment_operators,
     eslint::max_classes_per_file,
+    eslint::max_depth,
     eslint::max_lines,
     eslint::max_params,
     eslint::no_alert,
@@ -521,6 +559,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
     eslint::no_duplicate_case,
+    eslint::no_duplicate_imports,
+    eslint::no_else_return,
     eslint::no_empty,
     eslint::no_empty_character_class,
     eslint::no_empty_function,
@@ -530,24 +570,31 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_eval,
     eslint::no_ex_assign,
     eslint::no_extra_boolean_cast,
+    eslint::no_extra_parens,
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
+    eslint::no_implicit_coercion,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
     eslint::no_invalid_regexp,
     eslint::no_irregular_whitespace,
     eslint::no_iterator,
     eslint::no_label_var,
+    eslint::no_lonely_if as EslintNoLonelyIf,
     eslint::no_loss_of_precision,
     eslint::no_magic_numbers,
+    eslint::no_multi_assign,
     eslint::no_multi_str,
+    eslint::no_multiple_empty_lines,
+    eslint::no_nested_ternary as EslintNoNestedTernary,
     eslint::no_new,
     eslint::no_new_func,
     eslint::no_new_native_nonconstructor,
     eslint::no_new_wrappers,
     eslint::no_nonoctal_decimal_escape,
     eslint::no_obj_calls,
+    eslint::no_param_reassign,
     eslint::no_proto,
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
@@ -556,14 +603,19 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_script_url,
     eslint::no_self_assign,
     eslint::no_self_compare,
+    eslint::no_sequences,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_template_curly_in_string,
     eslint::no_ternary,
     eslint::no_this_before_super,
+    eslint::no_trailing_spaces,
     eslint::no_undef,
     eslint::no_undefined,
+    eslint::no_unmodified_loop_condition,
+    eslint::no_unneeded_ternary,
     eslint::no_unreachable,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
@@ -571,6 +623,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
     eslint::no_unused_vars,
+    eslint::no_useless_backreference,
     eslint::no_useless_catch,
     eslint::no_useless_concat,
     eslint::no_useless_constructor,
@@ -579,8 +632,15 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_var,
     eslint::no_void,
     eslint::no_with,
+    eslint::object_shorthand,
+    eslint::padded_blocks,
+    eslint::prefer_const,
     eslint::prefer_exponentiation_operator,
+    eslint::prefer_named_capture_group,
     eslint::prefer_numeric_literals,
+    eslint::prefer_object_has_own,
+    eslint::prefer_rest_params,
+    eslint::prefer_template,
     eslint::radix,
     eslint::require_await,
     eslint::require_yield,
@@ -605,6 +665,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default_member,
     import::no_self_import,
     import::no_webpack_loader_syntax,
+    import::order,
     jest::consistent_test_it,
     jest::expect_expect,
     jest::max_expects,
@@ -761,6 +822,7 @@ oxc_macros::declare_all_lint_rules! {
     react::jsx_no_undef,
     react::jsx_no_useless_fragment,
     react::jsx_props_no_spread_multi,
+    react::no_array_index_key,
     react::no_children_prop,
     react::no_danger,
     react::no_direct_mutation_state,
@@ -798,6 +860,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
     typescript::no_namespace,
@@ -805,6 +868,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_non_null_asserted_optional_chain,
     typescript::no_non_null_assertion,
     typescript::no_this_alias,
+    typescript::no_unnecessary_condition,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
     typescript::no_useless_empty_export,
@@ -0,0 +1,186 @@
+use oxc_ast::{
+    ast::{Expression, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::{collect_returns, ReturnInfo},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn missing_return_value_diagnostic(span: Span, first: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a return value.")
+        .with_help("Return a value here, or stop returning values from this function.")
+        .with_labels([
+            span.primary_label("This returns no value"),
+            first.label("but this earlier return statement returns a value"),
+        ])
+}
+
+fn unexpected_return_value_diagnostic(span: Span, first: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected no return value.")
+        .with_help("Do not return a value here, or return values from the whole function.")
+        .with_labels([
+            span.primary_label("This returns a value"),
+            first.label("but this earlier return statement returns no value"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn {
+    treat_undefined_as_unspecified: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function whose `return` statements sometimes return a value and sometimes do not is
+    /// hard to use correctly. Callers cannot tell whether `undefined` is a meaningful result or
+    /// a forgotten value.
+    ///
+    /// The first reachable `return` statement of a function decides whether the function
+    /// returns values, and every later one that does not agree is reported. Unreachable
+    /// `return` statements are ignored. Arrow functions with an expression body always return a
+    /// value and are never reported.
+    ///
+    /// ### Options
+    ///
+    /// - `treatUndefinedAsUnspecified` (default `false`): treat `return undefined;` and
+    ///   `return void 0;` like `return;`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return false;
+    ///     }
+    /// }
+    /// ```
+    ConsistentReturn,
+    suspicious
+);
+
+impl Rule for ConsistentReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let treat_undefined_as_unspecified = value
+            .get(0)
+            .and_then(|config| config.get("treatUndefinedAsUnspecified"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { treat_undefined_as_unspecified }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::Function(func) if func.body.is_some() => {}
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => {}
+            _ => return,
+        }
+
+        let mut returns = collect_returns(node, ctx).into_iter().filter(|info| info.is_reachable);
+        let Some(first) = returns.next() else { return };
+        let first_has_value = self.returns_value(&first, ctx);
+
+        for info in returns {
+            match (first_has_value, self.returns_value(&info, ctx)) {
+                (true, false) => {
+                    ctx.diagnostic(missing_return_value_diagnostic(info.span, first.span));
+                }
+                (false, true) => {
+                    ctx.diagnostic(unexpected_return_value_diagnostic(info.span, first.span));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl ConsistentReturn {
+    fn returns_value(&self, info: &ReturnInfo, ctx: &LintContext) -> bool {
+        if !info.has_argument || !self.treat_undefined_as_unspecified {
+            return info.has_argument;
+        }
+        let AstKind::ReturnStatement(stmt) = ctx.nodes().kind(info.node_id) else {
+            return true;
+        };
+        match stmt.argument.as_ref().map(Expression::without_parentheses) {
+            Some(Expression::Identifier(ident)) => ident.name != "undefined",
+            Some(Expression::UnaryExpression(expr)) => expr.operator != UnaryOperator::Void,
+            _ => true,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let treat_undefined = || Some(json!([{ "treatUndefinedAsUnspecified": true }]));
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function foo() { if (true) return; }", None),
+        ("function foo() { if (true) return; else return; }", None),
+        ("function foo() { if (true) return true; else return false; }", None),
+        ("function foo() { return 1; }", None),
+        ("function foo() { if (a) { return 1; } return 2; }", None),
+        ("function foo() { while (a) { if (b) return; } return; }", None),
+        ("function foo() { function bar() { return true; } return; }", None),
+        ("function foo() { const bar = () => { return true; }; return; }", None),
+        ("function foo() { return; function bar() { return true; } }", None),
+        ("const foo = () => a ? 1 : undefined;", None),
+        ("const foo = () => { if (a) return b; return c; };", None),
+        ("class Foo { bar() { if (a) return; return; } }", None),
+        ("const foo = { bar() { if (a) return 1; return 2; } };", None),
+        ("function foo() { if (a) return undefined; return; }", treat_undefined()),
+        ("function foo() { if (a) return void 0; return; }", treat_undefined()),
+        ("function foo() { if (a) return; return undefined; }", treat_undefined()),
+        ("function foo() { if (a) return (undefined); return; }", treat_undefined()),
+        // Unreachable returns are ignored.
+        ("function foo() { return 1; return; }", None),
+        ("function foo() { throw new Error(); return; return 1; }", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (a) return true; else return; }", None),
+        ("function foo() { if (a) return; else return true; }", None),
+        ("const foo = () => { if (a) return true; return; };", None),
+        ("const foo = function () { if (a) return; return 1; };", None),
+        ("class Foo { bar() { if (a) return; return 1; } }", None),
+        ("function foo() { switch (a) { case 1: return; default: return 1; } }", None),
+        ("function foo() { if (a) return 1; if (b) return; if (c) return; }", None),
+        ("function foo() { try { return 1; } catch { return; } }", None),
+        ("function foo() { if (a) return undefined; return; }", None),
+        ("function foo() { if (a) return void 0; return 1; }", treat_undefined()),
+        ("function foo() { if (a) return undefined; return null; }", treat_undefined()),
+        ("function foo() { if (a) return; function bar() { return; } return 1; }", None),
+    ];
+
+    Tester::new(ConsistentReturn::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,207 @@
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, Expression, Function, Super, ThisExpression},
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a function expression.")
+        .with_help("Assign a function expression to a variable instead of declaring a function.")
+        .with_label(span)
+}
+
+fn declaration_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a function declaration.")
+        .with_help("Declare a function instead of assigning a function expression to a variable.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FuncStyle {
+    style: Style,
+    allow_arrow_functions: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    #[default]
+    Expression,
+    Declaration,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the consistent use of either `function` declarations or expressions assigned to
+    /// variables.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// There are two ways of defining functions in JavaScript: function declarations and
+    /// function expressions assigned to variables. Function declarations are hoisted, so they
+    /// can be used before they are defined, while function expressions are not. Mixing both
+    /// styles in a codebase makes it harder to follow.
+    ///
+    /// ### Options
+    ///
+    /// - The first option is either `"expression"` (default) or `"declaration"`.
+    /// - `allowArrowFunctions` (default `false`): allow arrow functions assigned to variables
+    ///   when using `"declaration"`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"expression"` option:
+    /// ```javascript
+    /// function foo() {
+    ///   // ...
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"declaration"` option:
+    /// ```javascript
+    /// var foo = function() {
+    ///   // ...
+    /// };
+    ///
+    /// var foo = () => {};
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"expression"` option:
+    /// ```javascript
+    /// var foo = function() {
+    ///   // ...
+    /// };
+    /// ```
+    FuncStyle,
+    style
+);
+
+impl Rule for FuncStyle {
+    fn from_configuration(value: Value) -> Self {
+        let style = match value.get(0).and_then(Value::as_str) {
+            Some("declaration") => Style::Declaration,
+            _ => Style::Expression,
+        };
+        let allow_arrow_functions = value
+            .get(1)
+            .and_then(|config| config.get("allowArrowFunctions"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        Self { style, allow_arrow_functions }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match (self.style, node.kind()) {
+            (Style::Expression, AstKind::Function(func)) => {
+                // `declare function foo(): void;` and overloads have no body.
+                if !func.is_declaration() || func.body.is_none() {
+                    return;
+                }
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::ExportDefaultDeclaration(_))
+                ) {
+                    return;
+                }
+                ctx.diagnostic(expression_diagnostic(func.span));
+            }
+            (Style::Declaration, AstKind::VariableDeclarator(declarator)) => {
+                let Some(init) = &declarator.init else {
+                    return;
+                };
+                let is_violation = match init.without_parentheses() {
+                    Expression::FunctionExpression(_) => true,
+                    // Arrow functions capture `this` and `super` from the enclosing scope, which a
+                    // function declaration cannot do.
+                    Expression::ArrowFunctionExpression(arrow) => {
+                        !self.allow_arrow_functions && !uses_lexical_this(arrow)
+                    }
+                    _ => false,
+                };
+                if is_violation {
+                    ctx.diagnostic(declaration_diagnostic(declarator.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn uses_lexical_this(arrow: &ArrowFunctionExpression) -> bool {
+    let mut finder = ThisFinder { found: false };
+    finder.visit_function_body(&arrow.body);
+    finder.found
+}
+
+struct ThisFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_this_expression(&mut self, _expr: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _expr: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(){}\n function bar(){}", Some(json!(["declaration"]))),
+        ("foo.bar = function(){};", Some(json!(["declaration"]))),
+        ("(function() { /* code */ }());", Some(json!(["declaration"]))),
+        ("var module = (function() { return {}; }());", Some(json!(["declaration"]))),
+        ("var object = { foo: function(){} };", Some(json!(["declaration"]))),
+        ("Array.prototype.foo = function(){};", Some(json!(["declaration"]))),
+        ("foo.bar = function(){};", Some(json!(["expression"]))),
+        ("var foo = function(){};\n var bar = function(){};", Some(json!(["expression"]))),
+        ("var foo = () => {};\n var bar = () => {}", Some(json!(["expression"]))),
+        ("var foo = function() { this; }.bind(this);", Some(json!(["declaration"]))),
+        ("var foo = () => { this; };", Some(json!(["declaration"]))),
+        (
+            "class C extends D { foo() { var bar = () => { super.baz(); }; } }",
+            Some(json!(["declaration"])),
+        ),
+        ("var obj = { foo() { var bar = () => super.baz; } }", Some(json!(["declaration"]))),
+        ("export default function () {};", None),
+        ("var foo = () => {};", Some(json!(["declaration", { "allowArrowFunctions": true }]))),
+        (
+            "var foo = () => { function foo() { this; } };",
+            Some(json!(["declaration", { "allowArrowFunctions": true }])),
+        ),
+        ("declare function foo(a: string): void;", None),
+        ("function foo(a: string): void;\nfunction foo(a: number): void;", None),
+    ];
+
+    let fail = vec![
+        ("var foo = function(){};", Some(json!(["declaration"]))),
+        ("var foo = () => {};", Some(json!(["declaration"]))),
+        ("var foo = () => { function foo() { this; } };", Some(json!(["declaration"]))),
+        ("var foo = (function(){});", Some(json!(["declaration"]))),
+        ("const foo = async function() {};", Some(json!(["declaration"]))),
+        ("function foo(){}", Some(json!(["expression"]))),
+        ("function foo(){}", None),
+        ("export function foo(){}", None),
+        ("if (a) { function foo(){} }", None),
+        ("var foo = function() { function bar(){} };", None),
+        ("var foo = function(){};", Some(json!(["declaration", { "allowArrowFunctions": true }]))),
+    ];
+
+    Tester::new(FuncStyle::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,155 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::source_lines};
+
+fn linebreak_style_diagnostic(expected: LinebreakKind, found: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected linebreaks to be '{expected}' but found '{found}'."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LinebreakStyle {
+    linebreak_kind: LinebreakKind,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce consistent linebreak style.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When developing with a lot of people all having different editors, VCS applications and
+    /// operating systems it may occur that different line endings are written by either of the
+    /// mentioned (might especially happen when using the windows and mac versions of SourceTree
+    /// together). Mixed line endings make diffs noisy and can break tools that expect one style.
+    ///
+    /// ### Options
+    ///
+    /// Either `"unix"` (default, `\n`) or `"windows"` (`\r\n`).
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"unix"` option:
+    /// ```javascript
+    /// var a = 'a'; // \r\n
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"unix"` option:
+    /// ```javascript
+    /// var a = 'a'; // \n
+    /// ```
+    LinebreakStyle,
+    style,
+    fix
+);
+
+impl Rule for LinebreakStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            linebreak_kind: value
+                .get(0)
+                .and_then(serde_json::Value::as_str)
+                .map(LinebreakKind::from)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let expected = self.linebreak_kind.as_str();
+        for line in source_lines(ctx.source_text()) {
+            let terminator = line.terminator_text(ctx.source_text());
+            // Only `\n` and `\r\n` are considered, other line terminators are left alone.
+            if !matches!(terminator, "\n" | "\r\n") || terminator == expected {
+                continue;
+            }
+            let found = if terminator == "\n" { "LF" } else { "CRLF" };
+            ctx.diagnostic_with_fix(
+                linebreak_style_diagnostic(self.linebreak_kind, found, line.terminator),
+                |fixer| fixer.replace(line.terminator, expected),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum LinebreakKind {
+    #[default]
+    Unix,
+    Windows,
+}
+
+impl LinebreakKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Unix => "\n",
+            Self::Windows => "\r\n",
+        }
+    }
+}
+
+impl std::fmt::Display for LinebreakKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unix => write!(f, "LF"),
+            Self::Windows => write!(f, "CRLF"),
+        }
+    }
+}
+
+impl From<&str> for LinebreakKind {
+    fn from(raw: &str) -> Self {
+        if raw == "windows" {
+            Self::Windows
+        } else {
+            Self::Unix
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 'a',\n b = 'b';\n\n function foo(params) {\n /* do stuff */ \n }\n", None),
+        ("var a = 'a',\n b = 'b';\n\n function foo(params) {\n /* do stuff */ \n }\n", Some(json!(["unix"]))),
+        ("var a = 'a',\r\n b = 'b';\r\n\r\n function foo(params) {\r\n /* do stuff */ \r\n }\r\n", Some(json!(["windows"]))),
+        ("var b = 'b';", Some(json!(["unix"]))),
+        ("var b = 'b';", Some(json!(["windows"]))),
+        ("", None),
+    ];
+
+    let fail = vec![
+        ("var a = 'a';\r\n", None),
+        ("var a = 'a';\r\n", Some(json!(["unix"]))),
+        ("var a = 'a';\n", Some(json!(["windows"]))),
+        ("var a = 'a',\n b = 'b';\n\n function foo(params) {\r\n /* do stuff */ \n }\r\n", None),
+        (
+            "var a = 'a',\r\n b = 'b';\r\n\r\n function foo(params) {\n /* do stuff */ \n }\r\n",
+            Some(json!(["windows"])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var a = 'a';\r\n", "var a = 'a';\n", None),
+        ("var a = 'a';\r\n", "var a = 'a';\n", Some(json!(["unix"]))),
+        ("var a = 'a';\n", "var a = 'a';\r\n", Some(json!(["windows"]))),
+        (
+            "var a = 'a',\n b = 'b';\r\n\r\n function foo() {\r\n }\n",
+            "var a = 'a',\n b = 'b';\n\n function foo() {\n }\n",
+            Some(json!(["unix"])),
+        ),
+        (
+            "var a = 'a',\n b = 'b';\r\n\n function foo() {\r\n }\n",
+            "var a = 'a',\r\n b = 'b';\r\n\r\n function foo() {\r\n }\r\n",
+            Some(json!(["windows"])),
+        ),
+    ];
+
+    Tester::new(LinebreakStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,311 @@
+use oxc_ast::{
+    ast::{ClassElement, MethodDefinitionType},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn always_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected blank line between class members.").with_label(span)
+}
+
+fn never_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected blank line between class members.").with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct LinesBetweenClassMembers {
+    always: bool,
+    except_after_single_line: bool,
+    except_after_overload: bool,
+}
+
+impl Default for LinesBetweenClassMembers {
+    fn default() -> Self {
+        Self { always: true, except_after_single_line: false, except_after_overload: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow an empty line between class members.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// This rule improves readability by enforcing lines between class members. It will not
+    /// check empty lines before the first member and after the last member, since that is
+    /// already taken care of by `padded-blocks`.
+    ///
+    /// ### Options
+    ///
+    /// The first option is either `"always"` (default) or `"never"`.
+    ///
+    /// The second option is an object with:
+    /// - `exceptAfterSingleLine` (default `false`): skip checking empty lines after single-line
+    ///   class members.
+    /// - `exceptAfterOverload` (default `true`): skip checking empty lines after TypeScript
+    ///   overload signatures.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// class MyClass {
+    ///   x;
+    ///   foo() {
+    ///     //...
+    ///   }
+    ///   bar() {
+    ///     //...
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// class MyClass {
+    ///   x;
+    ///
+    ///   foo() {
+    ///     //...
+    ///   }
+    ///
+    ///   bar() {
+    ///     //...
+    ///   }
+    /// }
+    /// ```
+    LinesBetweenClassMembers,
+    style,
+    conditional_fix
+);
+
+impl Rule for LinesBetweenClassMembers {
+    fn from_configuration(value: Value) -> Self {
+        let default = Self::default();
+        let options = value.get(1);
+        Self {
+            always: value.get(0).and_then(Value::as_str) != Some("never"),
+            except_after_single_line: options
+                .and_then(|options| options.get("exceptAfterSingleLine"))
+                .and_then(Value::as_bool)
+                .unwrap_or(default.except_after_single_line),
+            except_after_overload: options
+                .and_then(|options| options.get("exceptAfterOverload"))
+                .and_then(Value::as_bool)
+                .unwrap_or(default.except_after_overload),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ClassBody(class_body) = node.kind() else {
+            return;
+        };
+        let source_text = ctx.source_text();
+
+        for pair in class_body.body.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            let prev_span = prev.span();
+            let next_span = next.span();
+
+            if self.except_after_single_line && !has_line_break(prev_span.source_text(source_text))
+            {
+                continue;
+            }
+            if self.except_after_overload && is_overload(prev) {
+                continue;
+            }
+
+            let gap = Span::new(prev_span.end, next_span.start);
+            let has_blank_line = has_blank_line(gap, ctx);
+
+            if self.always && !has_blank_line {
+                ctx.diagnostic_with_fix(always_diagnostic(next_span), |fixer| {
+                    // Insert the blank line after the end of the previous member's last line.
+                    match find_line_break(gap, ctx) {
+                        Some(line_break) => fixer.insert_text_before_range(line_break, "\n"),
+                        None => fixer.insert_text_after_range(prev_span, "\n\n"),
+                    }
+                });
+            } else if !self.always && has_blank_line {
+                let diagnostic = never_diagnostic(next_span);
+                if ctx.semantic().trivias().has_comments_between(gap) {
+                    ctx.diagnostic(diagnostic);
+                    continue;
+                }
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(gap, remove_blank_lines(gap.source_text(source_text)))
+                });
+            }
+        }
+    }
+}
+
+/// A TypeScript overload signature, e.g. `foo(a: string): void;`
+fn is_overload(element: &ClassElement) -> bool {
+    matches!(
+        element,
+        ClassElement::MethodDefinition(method)
+            if method.r#type == MethodDefinitionType::MethodDefinition && method.value.body.is_none()
+    )
+}
+
+fn has_line_break(text: &str) -> bool {
+    text.contains(['\n', '\r', '\u{2028}', '\u{2029}'])
+}
+
+/// Whether there is a line containing only whitespace in `gap`, ignoring the (partial) first
+/// and last lines and lines inside comments.
+fn has_blank_line(gap: Span, ctx: &LintContext) -> bool {
+    let comments = ctx
+        .semantic()
+        .trivias()
+        .comments_range(gap.start..gap.end)
+        .map(|comment| Span::new(comment.real_span_start(), comment.real_span_end()))
+        .collect::<Vec<_>>();
+    let text = gap.source_text(ctx.source_text());
+    let mut line_start = 0;
+    let mut lines = vec![];
+    for (i, c) in text.char_indices() {
+        if c == '\n' {
+            lines.push((line_start, i));
+            line_start = i + 1;
+        }
+    }
+    lines.push((line_start, text.len()));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let is_blank = |&(start, end): &(usize, usize)| {
+        let span = Span::new(gap.start + start as u32, gap.start + end as u32);
+        text[start..end].trim().is_empty()
+            && !comments.iter().any(|comment| comment.start < span.start && span.end < comment.end)
+    };
+    lines.len() > 2 && lines[1..lines.len() - 1].iter().any(is_blank)
+}
+
+/// The first line break in `gap` that is not inside a comment.
+fn find_line_break(gap: Span, ctx: &LintContext) -> Option<Span> {
+    gap.source_text(ctx.source_text()).char_indices().find_map(|(i, c)| {
+        #[allow(clippy::cast_possible_truncation)]
+        let pos = gap.start + i as u32;
+        let in_comment = ctx
+            .semantic()
+            .trivias()
+            .comments_range(gap.start..gap.end)
+            .any(|comment| comment.real_span_start() <= pos && pos < comment.real_span_end());
+        (matches!(c, '\n' | '\r') && !in_comment).then(|| Span::empty(pos))
+    })
+}
+
+/// Keep the first and last (partial) lines of `text` and drop all blank lines in between.
+fn remove_blank_lines(text: &str) -> String {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(i, line)| *i == 0 || *i == last || !line.trim().is_empty())
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("class foo{}", None),
+        ("class foo{;;}", None),
+        ("class foo{\n\n}", None),
+        ("class foo{constructor(){}\n}", None),
+        ("class foo{\nconstructor(){}}", None),
+        ("class foo{ bar(){}\n\nbaz(){}}", None),
+        ("class foo{ bar(){}\n\n/*comments*/baz(){}}", None),
+        ("class foo{ bar(){}\n\n//comments\nbaz(){}}", None),
+        ("class foo{ bar(){}\n//comments\n\nbaz(){}}", None),
+        ("class A{ foo() {} // a comment\n\nbar() {}}", None),
+        ("class A{ foo() {}\n/* a */ /* b */\n\nbar() {}}", None),
+        ("class A{ foo() {}/* a */ \n\n /* b */bar() {}}", None),
+        ("class A {\nfoo() {}\n/* comment */;\n;\n\nbar() {}\n}", None),
+        ("class foo{ bar(){}\nbaz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n/*comments*/baz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n//comments\nbaz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n/* comments\n\n*/baz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n\nbaz(){}}", Some(json!(["always"]))),
+        ("class foo{ bar(){}\n\n/*comments*/baz(){}}", Some(json!(["always"]))),
+        ("class foo{ bar(){}\n\n//comments\nbaz(){}}", Some(json!(["always"]))),
+        (
+            "class foo{ bar(){}\nbaz(){}}",
+            Some(json!(["always", { "exceptAfterSingleLine": true }])),
+        ),
+        (
+            "class foo{ bar(){\n}\n\nbaz(){}}",
+            Some(json!(["always", { "exceptAfterSingleLine": true }])),
+        ),
+        (
+            "class foo{\naaa;\n#bbb;\nccc(){\n}\n\n#ddd(){\n}\n}",
+            Some(json!(["always", { "exceptAfterSingleLine": true }])),
+        ),
+        (
+            "class foo {\n  bar(a: string): void;\n  bar(a: number): void;\n  bar(a: any) {}\n}",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("class foo{ bar(){}\nbaz(){}}", None),
+        ("class foo{ bar(){}baz(){}}", None),
+        ("class foo{ bar(){}\n/*comments*/baz(){}}", None),
+        ("class foo{ bar(){}\n//comments\nbaz(){}}", None),
+        ("class foo{ bar(){}/* comments\n\n*/baz(){}}", None),
+        ("class foo{ bar(){}\n\nbaz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n\n/*comments*/baz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\n\n//comments\nbaz(){}}", Some(json!(["never"]))),
+        ("class foo{ bar(){}\nbaz(){}}", Some(json!(["always"]))),
+        (
+            "class foo{ bar(){\n}\nbaz(){}}",
+            Some(json!(["always", { "exceptAfterSingleLine": true }])),
+        ),
+        ("class foo{\naaa;\n#bbb;\nccc(){\n}\n#ddd(){\n}\n}", None),
+        (
+            "class foo {\n  bar(a: string): void;\n  bar(a: number): void;\n  bar(a: any) {}\n}",
+            Some(json!(["always", { "exceptAfterOverload": false }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("class foo{ bar(){}\nbaz(){}}", "class foo{ bar(){}\n\nbaz(){}}", None),
+        ("class foo{ bar(){}baz(){}}", "class foo{ bar(){}\n\nbaz(){}}", None),
+        (
+            "class foo{ bar(){} // comment\nbaz(){}}",
+            "class foo{ bar(){} // comment\n\nbaz(){}}",
+            None,
+        ),
+        (
+            "class foo{ bar(){}\n/*comments*/baz(){}}",
+            "class foo{ bar(){}\n\n/*comments*/baz(){}}",
+            None,
+        ),
+        ("class foo{ bar(){}\n\nbaz(){}}", "class foo{ bar(){}\nbaz(){}}", Some(json!(["never"]))),
+        (
+            "class foo{\n  bar(){}\n\n\n  baz(){}\n}",
+            "class foo{\n  bar(){}\n  baz(){}\n}",
+            Some(json!(["never"])),
+        ),
+        (
+            "class foo{ bar(){}\r\n\r\nbaz(){}}",
+            "class foo{ bar(){}\r\nbaz(){}}",
+            Some(json!(["never"])),
+        ),
+    ];
+
+    Tester::new(LinesBetweenClassMembers::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
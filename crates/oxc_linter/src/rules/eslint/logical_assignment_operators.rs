@@ -0,0 +1,561 @@
+use oxc_ast::{
+    ast::{
+        match_member_expression, AssignmentExpression, AssignmentTarget, BinaryExpression,
+        Expression, IfStatement, LogicalExpression, SimpleAssignmentTarget, Statement,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_same_member_expression, is_same_reference},
+    AstNode,
+};
+
+fn assignment_diagnostic(operator: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Assignment (=) can be replaced with operator assignment ({operator})."
+    ))
+    .with_label(span)
+}
+
+fn logical_diagnostic(operator: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Logical expression can be replaced with an assignment ({operator})."
+    ))
+    .with_label(span)
+}
+
+fn if_diagnostic(operator: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'if' statement can be replaced with a logical operator assignment with operator {operator}."
+    ))
+    .with_label(span)
+}
+
+fn unexpected_diagnostic(operator: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected logical operator assignment ({operator}) shorthand."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LogicalAssignmentOperators {
+    mode: Mode,
+    enforce_for_if_statements: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Always,
+    Never,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow logical assignment operator shorthand.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// ES2021 introduces the assignment operator shorthand for the logical operators `||`, `&&`
+    /// and `??`. `a = a || b` can be written as `a ||= b`, which is shorter and only evaluates
+    /// `a` once.
+    ///
+    /// ### Options
+    ///
+    /// The first option is either `"always"` (default) or `"never"`.
+    ///
+    /// With `"always"`, an object with `enforceForIfStatements` (default `false`) can be passed
+    /// to also check `if` statements that are equivalent to a logical assignment, such as
+    /// `if (!a) a = b`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// a = a || b
+    /// a = a && b
+    /// a = a ?? b
+    /// a || (a = b)
+    /// a.b = a.b ?? c
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// a = b
+    /// a ||= b
+    /// a.b = a.c || d
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with the `"never"` option:
+    /// ```javascript
+    /// a ||= b
+    /// a &&= b
+    /// a ??= b
+    /// ```
+    LogicalAssignmentOperators,
+    style,
+    conditional_fix_suggestion
+);
+
+impl Rule for LogicalAssignmentOperators {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("never") => Mode::Never,
+            _ => Mode::Always,
+        };
+        let enforce_for_if_statements = mode == Mode::Always
+            && value
+                .get(1)
+                .and_then(|v| v.get("enforceForIfStatements"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false);
+        Self { mode, enforce_for_if_statements }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match (self.mode, node.kind()) {
+            (Mode::Always, AstKind::AssignmentExpression(assign_expr)) => {
+                check_assignment(assign_expr, ctx);
+            }
+            (Mode::Always, AstKind::LogicalExpression(logical_expr)) => {
+                check_logical(node, logical_expr, ctx);
+            }
+            (Mode::Always, AstKind::IfStatement(if_stmt)) if self.enforce_for_if_statements => {
+                check_if_statement(if_stmt, ctx);
+            }
+            (Mode::Never, AstKind::AssignmentExpression(assign_expr)) => {
+                check_shorthand(assign_expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `a = a || b`
+fn check_assignment<'a>(assign_expr: &AssignmentExpression<'a>, ctx: &LintContext<'a>) {
+    if assign_expr.operator != AssignmentOperator::Assign {
+        return;
+    }
+    let Expression::LogicalExpression(logical_expr) = assign_expr.right.without_parentheses()
+    else {
+        return;
+    };
+    // `a = a || b || c` is `a = (a || b) || c`, which is the same as `a ||= b || c`.
+    let mut leftmost = &**logical_expr;
+    while let Expression::LogicalExpression(inner) = &leftmost.left {
+        if inner.operator != logical_expr.operator {
+            break;
+        }
+        leftmost = &**inner;
+    }
+    if !assignment_target_eq_expr(&assign_expr.left, &leftmost.left, ctx) {
+        return;
+    }
+
+    let operator = to_assignment_operator(logical_expr.operator).as_str();
+    let diagnostic = assignment_diagnostic(operator, assign_expr.span);
+    if ctx.semantic().trivias().has_comments_between(assign_expr.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let target = ctx.source_range(assign_expr.left.span());
+        let value = ctx.source_range(Span::new(leftmost.right.span().start, logical_expr.span.end));
+        fixer.replace(assign_expr.span, format!("{target} {operator} {value}"))
+    });
+}
+
+/// `a || (a = b)`
+fn check_logical<'a>(
+    node: &AstNode<'a>,
+    logical_expr: &LogicalExpression<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let Expression::AssignmentExpression(assign_expr) = logical_expr.right.without_parentheses()
+    else {
+        return;
+    };
+    if assign_expr.operator != AssignmentOperator::Assign
+        || !assignment_target_eq_expr(&assign_expr.left, &logical_expr.left, ctx)
+    {
+        return;
+    }
+
+    let operator = to_assignment_operator(logical_expr.operator).as_str();
+    let diagnostic = logical_diagnostic(operator, logical_expr.span);
+    if ctx.semantic().trivias().has_comments_between(logical_expr.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    let needs_parens = !matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(AstKind::ExpressionStatement(_) | AstKind::ParenthesizedExpression(_))
+    );
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let target = ctx.source_range(assign_expr.left.span());
+        let value = ctx.source_range(assign_expr.right.span());
+        let replacement = if needs_parens {
+            format!("({target} {operator} {value})")
+        } else {
+            format!("{target} {operator} {value}")
+        };
+        fixer.replace(logical_expr.span, replacement)
+    });
+}
+
+/// `if (a) a = b`, `if (!a) a = b`, `if (a == null) a = b`
+fn check_if_statement<'a>(if_stmt: &IfStatement<'a>, ctx: &LintContext<'a>) {
+    if if_stmt.alternate.is_some() {
+        return;
+    }
+    let Some(assign_expr) = single_assignment(&if_stmt.consequent) else {
+        return;
+    };
+    if assign_expr.operator != AssignmentOperator::Assign {
+        return;
+    }
+    let Some(operator) = if_test_operator(&if_stmt.test, &assign_expr.left, ctx) else {
+        return;
+    };
+
+    let operator = operator.as_str();
+    let diagnostic = if_diagnostic(operator, if_stmt.span);
+    if ctx.semantic().trivias().has_comments_between(if_stmt.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let target = ctx.source_range(assign_expr.left.span());
+        let value = ctx.source_range(assign_expr.right.span());
+        fixer.replace(if_stmt.span, format!("{target} {operator} {value};"))
+    });
+}
+
+/// `a ||= b` with the `"never"` option
+fn check_shorthand<'a>(assign_expr: &AssignmentExpression<'a>, ctx: &LintContext<'a>) {
+    let operator = match assign_expr.operator {
+        AssignmentOperator::LogicalOr => LogicalOperator::Or,
+        AssignmentOperator::LogicalAnd => LogicalOperator::And,
+        AssignmentOperator::LogicalNullish => LogicalOperator::Coalesce,
+        _ => return,
+    };
+
+    let diagnostic = unexpected_diagnostic(assign_expr.operator.as_str(), assign_expr.span);
+    if ctx.semantic().trivias().has_comments_between(assign_expr.span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    let target = ctx.source_range(assign_expr.left.span());
+    let value = ctx.source_range(assign_expr.right.span());
+    let value = if needs_parens_in_logical(&assign_expr.right) {
+        format!("({value})")
+    } else {
+        value.to_string()
+    };
+    let replacement = format!("{target} = {target} {} {value}", operator.as_str());
+
+    // Expanding `a.b ||= c` evaluates `a.b` (and any getter on it) twice, and
+    // also assigns even when the value is unchanged.
+    if matches!(assign_expr.left, AssignmentTarget::AssignmentTargetIdentifier(_)) {
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(assign_expr.span, replacement));
+    } else {
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            fixer.replace(assign_expr.span, replacement)
+        });
+    }
+}
+
+fn to_assignment_operator(operator: LogicalOperator) -> AssignmentOperator {
+    match operator {
+        LogicalOperator::Or => AssignmentOperator::LogicalOr,
+        LogicalOperator::And => AssignmentOperator::LogicalAnd,
+        LogicalOperator::Coalesce => AssignmentOperator::LogicalNullish,
+    }
+}
+
+fn needs_parens_in_logical(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::LogicalExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::SequenceExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::YieldExpression(_)
+    )
+}
+
+/// Returns the assignment if `stmt` is `a = b;` or `{ a = b; }`.
+fn single_assignment<'a, 'b>(stmt: &'b Statement<'a>) -> Option<&'b AssignmentExpression<'a>> {
+    let stmt = match stmt {
+        Statement::BlockStatement(block) if block.body.len() == 1 => &block.body[0],
+        stmt => stmt,
+    };
+    let Statement::ExpressionStatement(expr_stmt) = stmt else {
+        return None;
+    };
+    match expr_stmt.expression.without_parentheses() {
+        Expression::AssignmentExpression(assign_expr) => Some(assign_expr),
+        _ => None,
+    }
+}
+
+/// Returns the logical assignment operator equivalent to `if (test) target = value`.
+fn if_test_operator<'a>(
+    test: &Expression<'a>,
+    target: &AssignmentTarget<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<AssignmentOperator> {
+    let test = test.without_parentheses();
+    match test {
+        // `if (!!a)`
+        Expression::UnaryExpression(outer) if outer.operator == UnaryOperator::LogicalNot => {
+            match outer.argument.without_parentheses() {
+                Expression::UnaryExpression(inner)
+                    if inner.operator == UnaryOperator::LogicalNot =>
+                {
+                    assignment_target_eq_expr(target, &inner.argument, ctx)
+                        .then_some(AssignmentOperator::LogicalAnd)
+                }
+                argument => assignment_target_eq_expr(target, argument, ctx)
+                    .then_some(AssignmentOperator::LogicalOr),
+            }
+        }
+        // `if (a == null)`
+        Expression::BinaryExpression(binary_expr) => {
+            is_loose_nullish_check(binary_expr, target, ctx)
+                .then_some(AssignmentOperator::LogicalNullish)
+        }
+        // `if (a === null || a === undefined)`
+        Expression::LogicalExpression(logical_expr)
+            if logical_expr.operator == LogicalOperator::Or =>
+        {
+            let (Expression::BinaryExpression(left), Expression::BinaryExpression(right)) =
+                (logical_expr.left.without_parentheses(), logical_expr.right.without_parentheses())
+            else {
+                return None;
+            };
+            let left = strict_nullish_check(left, target, ctx)?;
+            let right = strict_nullish_check(right, target, ctx)?;
+            (left != right).then_some(AssignmentOperator::LogicalNullish)
+        }
+        test => {
+            assignment_target_eq_expr(target, test, ctx).then_some(AssignmentOperator::LogicalAnd)
+        }
+    }
+}
+
+/// `a == null`, `null == a`, `a == undefined`
+fn is_loose_nullish_check<'a>(
+    binary_expr: &BinaryExpression<'a>,
+    target: &AssignmentTarget<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    if binary_expr.operator != BinaryOperator::Equality {
+        return false;
+    }
+    let (left, right) =
+        (binary_expr.left.without_parentheses(), binary_expr.right.without_parentheses());
+    let is_nullish = |expr: &Expression| expr.is_null() || expr.is_undefined();
+    (is_nullish(right) && assignment_target_eq_expr(target, left, ctx))
+        || (is_nullish(left) && assignment_target_eq_expr(target, right, ctx))
+}
+
+/// For `a === null` returns `Some(true)`, for `a === undefined` returns `Some(false)`.
+fn strict_nullish_check<'a>(
+    binary_expr: &BinaryExpression<'a>,
+    target: &AssignmentTarget<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<bool> {
+    if binary_expr.operator != BinaryOperator::StrictEquality {
+        return None;
+    }
+    let (left, right) =
+        (binary_expr.left.without_parentheses(), binary_expr.right.without_parentheses());
+    let value = if assignment_target_eq_expr(target, left, ctx) {
+        right
+    } else if assignment_target_eq_expr(target, right, ctx) {
+        left
+    } else {
+        return None;
+    };
+    if value.is_null() {
+        Some(true)
+    } else if value.is_undefined() {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Whether `assignment_target` and `expr` refer to the same identifier or member path.
+///
+/// Only identifiers, `this`, `super` and literals are compared, so expressions with side
+/// effects such as `a[f()]` never match and are not evaluated once instead of twice by a fix.
+fn assignment_target_eq_expr<'a>(
+    assignment_target: &AssignmentTarget<'a>,
+    expr: &Expression<'_>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let expr = expr.without_parentheses();
+    let Some(simple_assignment_target) = assignment_target.as_simple_assignment_target() else {
+        return false;
+    };
+    match simple_assignment_target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+            matches!(expr, Expression::Identifier(right_ident) if ident.name == right_ident.name)
+        }
+        match_member_expression!(SimpleAssignmentTarget) => {
+            let member_expr = simple_assignment_target.to_member_expression();
+            expr.as_member_expression().is_some_and(|right_member_expr| {
+                is_same_member_expression(member_expr, right_member_expr, ctx)
+            })
+        }
+        SimpleAssignmentTarget::TSAsExpression(_)
+        | SimpleAssignmentTarget::TSSatisfiesExpression(_)
+        | SimpleAssignmentTarget::TSNonNullExpression(_)
+        | SimpleAssignmentTarget::TSTypeAssertion(_)
+        | SimpleAssignmentTarget::TSInstantiationExpression(_) => simple_assignment_target
+            .get_expression()
+            .is_some_and(|target| is_same_reference(target, expr, ctx)),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a || b", None),
+        ("a && b", None),
+        ("a ?? b", None),
+        ("a || a || b", None),
+        ("var a = a || b", None),
+        ("a === undefined ? a : b", None),
+        ("a = b", None),
+        ("a = a + b", None),
+        ("a = b || a", None),
+        ("a = b || c", None),
+        ("a.b = a.c || d", None),
+        ("a.b = c.b || d", None),
+        ("a[b] = a[c] || d", None),
+        ("a = a() || b", None),
+        ("a[f()] = a[f()] || b", None),
+        ("a[i++] = a[i++] ?? b", None),
+        ("a.b().c = a.b().c && d", None),
+        ("a = a && b || c", None),
+        ("a || (b = c)", None),
+        ("a || (a += b)", None),
+        ("a.b || (a.c = d)", None),
+        ("a ||= b", None),
+        ("a &&= b", None),
+        ("a ??= b", None),
+        ("a.b ??= c", None),
+        // `if` statements are only checked with `enforceForIfStatements`
+        ("if (a) a = b", None),
+        ("if (!a) a = b", None),
+        ("if (a) a = b", Some(json!(["always", { "enforceForIfStatements": false }]))),
+        ("if (a) a = b; else a = c", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a) b = c", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a) { a = b; c = d; }", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a) a += b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a === null) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        (
+            "if (a === null || a === null) a = b",
+            Some(json!(["always", { "enforceForIfStatements": true }])),
+        ),
+        ("if (a == b) a = c", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        // `"never"`
+        ("a = a || b", Some(json!(["never"]))),
+        ("a = a && b", Some(json!(["never"]))),
+        ("a = a ?? b", Some(json!(["never"]))),
+        ("a || (a = b)", Some(json!(["never"]))),
+        ("a += b", Some(json!(["never"]))),
+        ("if (a) a = b", Some(json!(["never", { "enforceForIfStatements": true }]))),
+    ];
+
+    let fail = vec![
+        ("a = a || b", None),
+        ("a = a && b", None),
+        ("a = a ?? b", None),
+        ("a = (a || b)", None),
+        ("a.b = a.b || c", None),
+        ("a.b.c = a.b.c ?? d", None),
+        ("a[b] = a[b] && c", None),
+        ("a['b'] = a.b || c", None),
+        ("this.a = this.a || b", None),
+        ("a = a || b || c", None),
+        ("a = a && b && c", None),
+        ("a = a ?? b ?? c", None),
+        ("a = a || b && c", None),
+        ("a = a /* comment */ || b", None),
+        ("a || (a = b)", None),
+        ("a && (a = b)", None),
+        ("a ?? (a = b)", None),
+        ("a.b || (a.b = c)", None),
+        ("foo(a || (a = b))", None),
+        ("if (a) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (!a) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (!!a) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a) { a = b }", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a == null) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (null == a) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        ("if (a == undefined) a = b", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        (
+            "if (a === null || a === undefined) a = b",
+            Some(json!(["always", { "enforceForIfStatements": true }])),
+        ),
+        (
+            "if (undefined === a.b || a.b === null) a.b = c",
+            Some(json!(["always", { "enforceForIfStatements": true }])),
+        ),
+        ("a ||= b", Some(json!(["never"]))),
+        ("a &&= b", Some(json!(["never"]))),
+        ("a ??= b", Some(json!(["never"]))),
+        ("a.b ||= c", Some(json!(["never"]))),
+        ("a &&= b || c", Some(json!(["never"]))),
+    ];
+
+    let fix = vec![
+        ("a = a || b", "a ||= b", None),
+        ("a = a && b", "a &&= b", None),
+        ("a = a ?? b", "a ??= b", None),
+        ("a = (a || b)", "a ||= b", None),
+        ("a.b = a.b || c", "a.b ||= c", None),
+        ("a[b] = a[b] && c", "a[b] &&= c", None),
+        ("a = a || b || c", "a ||= b || c", None),
+        ("a = a && b && c", "a &&= b && c", None),
+        ("a = a ?? b ?? c", "a ??= b ?? c", None),
+        ("a = a || b && c", "a ||= b && c", None),
+        ("a = a /* comment */ || b", "a = a /* comment */ || b", None),
+        ("a || (a = b)", "a ||= b", None),
+        ("a ?? (a = b);", "a ??= b;", None),
+        ("foo(a || (a = b))", "foo((a ||= b))", None),
+        ("if (a) a = b", "a &&= b;", Some(json!(["always", { "enforceForIfStatements": true }]))),
+        (
+            "if (!a) { a = b; }",
+            "a ||= b;",
+            Some(json!(["always", { "enforceForIfStatements": true }])),
+        ),
+        (
+            "if (a == null) a = b",
+            "a ??= b;",
+            Some(json!(["always", { "enforceForIfStatements": true }])),
+        ),
+        ("a ||= b", "a = a || b", Some(json!(["never"]))),
+        ("a ??= b", "a = a ?? b", Some(json!(["never"]))),
+        ("a &&= b || c", "a = a && (b || c)", Some(json!(["never"]))),
+    ];
+
+    Tester::new(LogicalAssignmentOperators::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
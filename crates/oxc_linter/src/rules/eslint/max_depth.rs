@@ -0,0 +1,152 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn max_depth_diagnostic(depth: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Blocks are nested too deeply ({depth}). Maximum allowed is {max}."
+    ))
+    .with_help("Consider extracting the nested blocks into a separate function.")
+    .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct MaxDepth {
+    max: usize,
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum depth that blocks can be nested.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested blocks are hard to read and to follow, and usually mean the code should be
+    /// split into smaller functions.
+    ///
+    /// `if`, `switch`, `try`, `with` statements and loops count as one level of nesting. An
+    /// `else if` is on the same level as its `if`. Functions start counting from zero again.
+    ///
+    /// ### Options
+    ///
+    /// The maximum depth, either as a number or as `{ "max": n }`. Defaults to 4.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `{ "max": 4 }`:
+    /// ```javascript
+    /// function foo() {
+    ///     for (;;) {
+    ///         while (true) {
+    ///             if (true) {
+    ///                 if (true) {
+    ///                     if (true) {}
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    MaxDepth,
+    pedantic
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let max = config
+            .and_then(Value::as_u64)
+            .or_else(|| config.and_then(|config| config.get("max")).and_then(Value::as_u64))
+            .and_then(|max| usize::try_from(max).ok())
+            .unwrap_or(4);
+        Self { max }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_nesting_block(node, ctx) {
+            return;
+        }
+        let depth = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .take_while(|node| !is_function_boundary(node.kind()))
+            .filter(|node| is_nesting_block(node, ctx))
+            .count();
+        if depth > self.max {
+            ctx.diagnostic(max_depth_diagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+/// Whether `node` adds a level of nesting.
+fn is_nesting_block(node: &AstNode, ctx: &LintContext) -> bool {
+    match node.kind() {
+        // `else if` stays on the level of the `if`.
+        AstKind::IfStatement(stmt) => !matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(AstKind::IfStatement(parent))
+                if parent.alternate.as_ref().is_some_and(|alternate| alternate.span() == stmt.span)
+        ),
+        AstKind::SwitchStatement(_)
+        | AstKind::TryStatement(_)
+        | AstKind::WithStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_) => true,
+        _ => false,
+    }
+}
+
+fn is_function_boundary(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) | AstKind::StaticBlock(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([3]))),
+        ("function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }", Some(serde_json::json!([3]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([3]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([{ "max": 3 }]))),
+        ("function foo() { if (a) { function bar() { if (b) { if (c) {} } } } }", Some(serde_json::json!([2]))),
+        ("class C { static { if (1) { if (2) {} } } }", Some(serde_json::json!([2]))),
+        ("if (a) { if (b) { class C { static { if (c) {} } } } }", Some(serde_json::json!([2]))),
+        ("switch (a) { case 0: if (b) {} }", Some(serde_json::json!([2]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([2]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([2]))),
+        ("function foo() { if (true) {} else { for(;;) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { while (true) { if (true) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { for (let x of foo) { if (true) {} } }", Some(serde_json::json!([1]))),
+        ("function foo() { while (true) { if (true) { if (false) { } } } }", Some(serde_json::json!([1]))),
+        ("function foo() { if (true) { if (false) { if (true) { if (false) { if (true) { } } } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(serde_json::json!([{ "max": 2 }]))),
+        ("if (a) { if (b) { if (c) {} } }", Some(serde_json::json!([2]))),
+        ("class C { static { if (1) { if (2) { if (3) {} } } } }", Some(serde_json::json!([2]))),
+        ("if (a) { try { do {} while (b); } catch {} }", Some(serde_json::json!([2]))),
+        ("switch (a) { case 0: if (b) { for (;;) {} } }", Some(serde_json::json!([2]))),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
@@ -43,6 +43,12 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Functions that take numerous parameters can be difficult to read and write because it requires the memorization of what each parameter is, its type, and the order they should appear in. As a result, many coders adhere to a convention that caps the number of parameters a function can take.
     ///
+    /// Named functions are reported at their name, other functions at their parameter list.
+    ///
+    /// ### Options
+    ///
+    /// The maximum number of parameters, either as a number or as `{ "max": n }`. Defaults to 3.
+    ///
     /// ### Example
     /// ```javascript
     /// function foo (bar, baz, qux, qxx) {
@@ -89,11 +95,7 @@ impl Rule for MaxParams {
                             function.params.items.len(),
                             self.max
                         );
-                        let span = function.params.span;
-                        ctx.diagnostic(max_params_diagnostic(
-                            &error_msg,
-                            Span::new(span.start, span.end),
-                        ));
+                        ctx.diagnostic(max_params_diagnostic(&error_msg, id.span));
                     } else {
                         let error_msg = format!(
                             "Function has too many parameters ({}). Maximum allowed is {}.",
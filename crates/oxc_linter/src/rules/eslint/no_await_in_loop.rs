@@ -1,10 +1,7 @@
-use oxc_ast::{
-    ast::{Expression, Statement},
-    AstKind,
-};
+use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -59,8 +56,7 @@ impl Rule for NoAwaitInLoop {
                 // only highlight the 'await' keyword
                 Span::new(for_of_stmt.span.start + 4, for_of_stmt.span.start + 9)
             }
-            // only highlight the 'await' keyword
-            AstKind::AwaitExpression(expr) => Span::new(expr.span.start, expr.span.start + 5),
+            AstKind::AwaitExpression(expr) => expr.span,
             // other node type, return
             _ => return,
         };
@@ -91,75 +87,27 @@ impl Rule for NoAwaitInLoop {
 }
 
 impl NoAwaitInLoop {
-    fn node_matches_stmt_span(span: Span, stmt: &Statement) -> bool {
-        match stmt {
-            Statement::BlockStatement(block) => Self::include_span(block.span, span),
-            Statement::ExpressionStatement(expr_statement) => {
-                Self::include_span(expr_statement.span, span)
-            }
-            _ => false,
-        }
-    }
-
-    fn node_matches_expr_span(span: Span, expr: &Expression) -> bool {
-        match expr {
-            Expression::TemplateLiteral(expr) => Self::include_span(expr.span, span),
-            Expression::ArrayExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ArrowFunctionExpression(expr) => Self::include_span(expr.span, span),
-            Expression::AssignmentExpression(expr) => Self::include_span(expr.span, span),
-            Expression::AwaitExpression(expr) => Self::include_span(expr.span, span),
-            Expression::BinaryExpression(expr) => Self::include_span(expr.span, span),
-            Expression::CallExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ChainExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ClassExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ConditionalExpression(expr) => Self::include_span(expr.span, span),
-            Expression::FunctionExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ImportExpression(expr) => Self::include_span(expr.span, span),
-            Expression::LogicalExpression(expr) => Self::include_span(expr.span, span),
-            Expression::NewExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ObjectExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ParenthesizedExpression(expr) => Self::include_span(expr.span, span),
-            Expression::SequenceExpression(expr) => Self::include_span(expr.span, span),
-            Expression::TaggedTemplateExpression(expr) => Self::include_span(expr.span, span),
-            Expression::ThisExpression(expr) => Self::include_span(expr.span, span),
-            Expression::UnaryExpression(expr) => Self::include_span(expr.span, span),
-            Expression::UpdateExpression(expr) => Self::include_span(expr.span, span),
-            Expression::YieldExpression(expr) => Self::include_span(expr.span, span),
-            Expression::PrivateInExpression(expr) => Self::include_span(expr.span, span),
-            _ => false,
-        }
-    }
-
+    /// Whether `span` is in a part of the loop `parent` that is evaluated on every iteration:
+    /// the body, and the test and update of `for` and `while` loops.
     fn is_looped(span: Span, parent: &AstNode) -> bool {
         match parent.kind() {
             AstKind::ForStatement(stmt) => {
-                let mut result = Self::node_matches_stmt_span(span, &stmt.body);
-                if result {
-                    return result;
-                }
-
-                if let Some(test) = &stmt.test {
-                    result = Self::node_matches_expr_span(span, test);
-                    if result {
-                        return result;
-                    }
-                }
-
-                if let Some(update) = &stmt.update {
-                    result = Self::node_matches_expr_span(span, update);
-                }
-
-                result
+                Self::include_span(stmt.body.span(), span)
+                    || stmt.test.as_ref().is_some_and(|test| Self::include_span(test.span(), span))
+                    || stmt
+                        .update
+                        .as_ref()
+                        .is_some_and(|update| Self::include_span(update.span(), span))
             }
-            AstKind::ForInStatement(stmt) => Self::node_matches_stmt_span(span, &stmt.body),
-            AstKind::ForOfStatement(stmt) => Self::node_matches_stmt_span(span, &stmt.body),
+            AstKind::ForInStatement(stmt) => Self::include_span(stmt.body.span(), span),
+            AstKind::ForOfStatement(stmt) => Self::include_span(stmt.body.span(), span),
             AstKind::WhileStatement(stmt) => {
-                Self::node_matches_stmt_span(span, &stmt.body)
-                    || Self::node_matches_expr_span(span, &stmt.test)
+                Self::include_span(stmt.body.span(), span)
+                    || Self::include_span(stmt.test.span(), span)
             }
             AstKind::DoWhileStatement(stmt) => {
-                Self::node_matches_stmt_span(span, &stmt.body)
-                    || Self::node_matches_expr_span(span, &stmt.test)
+                Self::include_span(stmt.body.span(), span)
+                    || Self::include_span(stmt.test.span(), span)
             }
             _ => false,
         }
@@ -205,6 +153,8 @@ fn test() {
         "async function foo() { while (true) { class Foo { async foo() { await bar; } } } }",
         // Asynchronous iteration intentionally
         "async function foo() { for await (var x of xs) { await f(x) } }",
+        // Blocked by a callback
+        "async function foo() { for (const x of xs) { xs.map(async (y) => await y); } }",
     ];
 
     let fail = vec![
@@ -230,6 +180,11 @@ fn test() {
         "async function foo() { while (xyz || 5 > await x) {  } }",
         // In a nested loop of for-await-of
         "async function foo() { for await (var x of xs) { while (1) await f(x) } }",
+        // Loop bodies that are not blocks or expression statements
+        "async function foo() { while (baz) if (qux) await bar; }",
+        "async function foo() { for (;;) try { await bar; } catch {} }",
+        // Deep in a member expression of a loop condition
+        "async function foo() { while (bar[await baz]) {} }",
     ];
 
     Tester::new(NoAwaitInLoop::NAME, pass, fail).test_and_snapshot();
@@ -86,6 +86,7 @@ impl Rule for NoCondAssign {
                         | AstKind::ForStatement(_)
                         | AstKind::ConditionalExpression(_) => {
                             Self::emit_diagnostic(ctx, expr);
+                            break;
                         }
                         AstKind::Function(_)
                         | AstKind::ArrowFunctionExpression(_)
@@ -114,9 +115,9 @@ impl NoCondAssign {
     }
 
     fn check_expression(&self, ctx: &LintContext<'_>, expr: &Expression<'_>) {
-        let mut expr = expr;
+        // With `always`, assignments are reported when visiting the assignment itself.
         if self.config == NoCondAssignConfig::Always {
-            expr = expr.get_inner_expression();
+            return;
         }
         if let Expression::AssignmentExpression(expr) = expr {
             Self::emit_diagnostic(ctx, expr);
@@ -1,9 +1,23 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Expression, Statement},
+    AstKind,
+};
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    BasicBlockId, EdgeType,
+};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::Idx;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
 
-use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::{is_jump_target_inside, IsConstant},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
 
 fn no_constant_condition_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected constant condition")
@@ -11,9 +25,27 @@ fn no_constant_condition_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn no_infinite_loop_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected infinite loop")
+        .with_help(
+            "This loop condition is always true and the loop never breaks, returns or throws",
+        )
+        .with_label(span)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: CheckLoops,
+}
+
+/// Which loops to check for constant conditions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CheckLoops {
+    All,
+    /// Allow `while (true)`, which is a common idiom for loops that exit with a `break`.
+    AllExceptWhileTrue,
+    #[default]
+    None,
 }
 
 declare_oxc_lint!(
@@ -31,6 +63,14 @@ declare_oxc_lint!(
     /// - `if`, `for`, `while`, or `do...while` statement
     /// - `?`: ternary expression
     ///
+    /// `while (true)` loops that can never `break`, `return`, `throw` or `yield` are always
+    /// reported as infinite loops, regardless of `checkLoops`.
+    ///
+    /// ### Options
+    ///
+    /// - `checkLoops` (default `"none"`): `"all"` (or `true`) checks constant
+    ///   conditions in loops, `"allExceptWhileTrue"` checks all loops except `while (true)`, and
+    ///   `"none"` (or `false`) does not check loops.
     ///
     /// ### Example
     ///
@@ -70,12 +110,18 @@ impl Rule for NoConstantCondition {
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
 
-        Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        let check_loops = match obj.and_then(|v| v.get("checkLoops")) {
+            Some(serde_json::Value::Bool(true)) => CheckLoops::All,
+            Some(serde_json::Value::Bool(false)) => CheckLoops::None,
+            Some(serde_json::Value::String(check_loops)) => match check_loops.as_str() {
+                "all" => CheckLoops::All,
+                "allExceptWhileTrue" => CheckLoops::AllExceptWhileTrue,
+                _ => CheckLoops::None,
+            },
+            _ => CheckLoops::default(),
+        };
+
+        Self { check_loops }
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -90,13 +136,184 @@ impl Rule for NoConstantCondition {
                     ctx.diagnostic(no_constant_condition_diagnostic(condition_expr.test.span()));
                 }
             }
+            AstKind::WhileStatement(stmt) => {
+                self.check_loop(node, &stmt.test, &stmt.body, ctx);
+            }
+            AstKind::DoWhileStatement(stmt) => {
+                self.check_loop(node, &stmt.test, &stmt.body, ctx);
+            }
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    self.check_loop(node, test, &stmt.body, ctx);
+                }
+            }
             _ => {}
         }
     }
 }
 
+impl NoConstantCondition {
+    fn check_loop<'a>(
+        &self,
+        node: &AstNode<'a>,
+        test: &Expression<'a>,
+        body: &Statement<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let is_while_true = matches!(node.kind(), AstKind::WhileStatement(_))
+            && matches!(test.without_parentheses(), Expression::BooleanLiteral(lit) if lit.value);
+        let exits = LoopExits::find(node, body, ctx);
+        if is_while_true && !exits.exits {
+            ctx.diagnostic(no_infinite_loop_diagnostic(test.span()));
+            return;
+        }
+
+        // Constant loop conditions are fine in generators as long as the loop yields.
+        if exits.yields || !test.is_constant(true, ctx) {
+            return;
+        }
+        let skip = match self.check_loops {
+            CheckLoops::All => false,
+            CheckLoops::AllExceptWhileTrue => is_while_true,
+            CheckLoops::None => true,
+        };
+        if !skip {
+            ctx.diagnostic(no_constant_condition_diagnostic(test.span()));
+        }
+    }
+}
+
+/// The ways control can leave a loop: a `break` (or labeled `continue`) jumping out of it, a
+/// `return`, a `throw` or a `yield`. Only statements that are reachable from the start of the
+/// loop in the control flow graph count, and nested functions are not considered.
+#[derive(Default)]
+struct LoopExits {
+    exits: bool,
+    yields: bool,
+}
+
+impl LoopExits {
+    fn find(node: &AstNode, body: &Statement, ctx: &LintContext) -> Self {
+        let span = node.kind().span();
+        // `continue outer` stays in the loop if `outer` labels the loop itself.
+        let continue_span = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .map_while(|parent| match parent.kind() {
+                AstKind::LabeledStatement(labeled) => Some(labeled.span),
+                _ => None,
+            })
+            .last()
+            .unwrap_or(span);
+        // The initializer of a `for` loop only runs once, before the loop.
+        let init_span = match node.kind() {
+            AstKind::ForStatement(stmt) => stmt.init.as_ref().map(GetSpan::span),
+            _ => None,
+        };
+        let reachable = reachable_blocks(node, ctx);
+
+        let mut exits = Self::default();
+        // Nodes are created in pre-order, so the descendants of the loop are the nodes right
+        // after it, up to the first node outside of its span.
+        let descendants = ctx
+            .nodes()
+            .iter()
+            .skip(node.id().index() + 1)
+            .take_while(|descendant| span.contains_inclusive(descendant.kind().span()));
+        let mut skipped_end = None;
+        for descendant in descendants {
+            let descendant_span = descendant.kind().span();
+            if skipped_end.is_some_and(|end| descendant_span.end <= end) {
+                continue;
+            }
+            let (exits_loop, yields) = match descendant.kind() {
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                    skipped_end = Some(descendant_span.end);
+                    continue;
+                }
+                _ if init_span.is_some_and(|init| init.contains_inclusive(descendant_span)) => {
+                    continue;
+                }
+                AstKind::ReturnStatement(_) | AstKind::ThrowStatement(_) => (true, false),
+                AstKind::YieldExpression(_) => (true, true),
+                AstKind::BreakStatement(_) => {
+                    (!is_jump_target_inside(descendant.id(), body.span(), ctx), false)
+                }
+                AstKind::ContinueStatement(_) => {
+                    (!is_jump_target_inside(descendant.id(), continue_span, ctx), false)
+                }
+                _ => continue,
+            };
+            if exits_loop
+                && reachable.contains(&descendant.cfg_id())
+                && !is_in_dead_branch(descendant, node, ctx)
+            {
+                exits.exits = true;
+                exits.yields |= yields;
+            }
+        }
+        exits
+    }
+}
+
+/// The basic blocks reachable from the start of `node`. Nested functions are not entered.
+fn reachable_blocks(node: &AstNode, ctx: &LintContext) -> FxHashSet<BasicBlockId> {
+    let graph = ctx.cfg().graph();
+    let mut reachable = FxHashSet::default();
+    let mut stack = vec![node.cfg_id()];
+    while let Some(block_id) = stack.pop() {
+        if !reachable.insert(block_id) {
+            continue;
+        }
+        stack.extend(
+            graph
+                .edges_directed(block_id, Direction::Outgoing)
+                .filter(|edge| {
+                    !matches!(edge.weight(), EdgeType::NewFunction | EdgeType::Unreachable)
+                })
+                .map(|edge| edge.target()),
+        );
+    }
+    reachable
+}
+
+/// Whether `node` is in a branch inside of `loop_node` that is never taken because its condition
+/// is constant, e.g. `break` in `if (false) break;`. The control flow graph does not evaluate
+/// conditions, so these branches are still connected to the rest of the loop.
+fn is_in_dead_branch(node: &AstNode, loop_node: &AstNode, ctx: &LintContext) -> bool {
+    let is_always =
+        |test: &Expression, value: bool| test.without_parentheses().to_boolean() == Some(value);
+    let mut span = node.kind().span();
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        if parent.id() == loop_node.id() {
+            return false;
+        }
+        let is_dead = match parent.kind() {
+            AstKind::IfStatement(stmt) if stmt.consequent.span() == span => {
+                is_always(&stmt.test, false)
+            }
+            AstKind::IfStatement(stmt) if stmt.test.span() != span => is_always(&stmt.test, true),
+            AstKind::WhileStatement(stmt) if stmt.body.span() == span => {
+                is_always(&stmt.test, false)
+            }
+            AstKind::ForStatement(stmt) if stmt.body.span() == span => {
+                stmt.test.as_ref().is_some_and(|test| is_always(test, false))
+            }
+            _ => false,
+        };
+        if is_dead {
+            return true;
+        }
+        span = parent.kind().span();
+    }
+    false
+}
+
 #[test]
 fn test() {
+    use serde_json::json;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -215,6 +432,19 @@ fn test() {
         ("if (Boolean(a)) {}", None),
         ("if (Boolean(...args)) {}", None),
         ("if (foo.Boolean(1)) {}", None),
+        ("while(true){ break; }", Some(json!([{ "checkLoops": false }]))),
+        ("while(true){ if (a) { break; } }", None),
+        ("while(true){ if (a) { return; } }", None),
+        ("while(true){ throw new Error(); }", None),
+        ("outer: while(true){ while(b) { break outer; } }", None),
+        ("outer: for(;;){ for(;;) { for(;;) { break outer; } } }", None),
+        ("for(;;){ if (a) break; }", None),
+        ("do { if (a) break; } while(true)", Some(json!([{ "checkLoops": "none" }]))),
+        ("while(1){ if (a) break; }", Some(json!([{ "checkLoops": false }]))),
+        ("while(true){ if (a) break; }", Some(json!([{ "checkLoops": "allExceptWhileTrue" }]))),
+        ("for(;;);", None),
+        ("for(;true;);", Some(json!([{ "checkLoops": false }]))),
+        ("do{}while(true)", Some(json!([{ "checkLoops": false }]))),
         // TODO
         // ("const undefined = 'lol'; if (undefined) {}", None),
         // ("function foo(Boolean) { if (Boolean(1)) {} }", None),
@@ -226,30 +456,25 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", None),
+        ("while(a = b);", None),
+        ("while(`${a}`);", None),
+        ("for(;x < 10;);", None),
+        ("for(;`${a}`;);", None),
+        ("do{ }while(x)", None),
+        ("while(x += 3) {}", None),
+        ("while(tag`a`) {}", None),
+        ("while(tag`${a}`) {}", None),
+        ("while(`\\\n${a}`) {}", None),
+        ("function* foo(){while(true){yield 'foo';}}", None),
+        ("function* foo(){for(;true;){yield 'foo';}}", None),
+        ("function* foo(){do{yield 'foo';}while(true)}", None),
+        ("function* foo(){while (true) { while(true) {yield;}}}", None),
+        ("function* foo() {for (; yield; ) {}}", None),
+        ("function* foo() {for (; ; yield) {}}", None),
+        ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
+        ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
+        ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
     ];
 
     let fail = vec![
@@ -369,40 +594,64 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", Some(json!([{ "checkLoops": true }]))),
+        ("for(;``;);", Some(json!([{ "checkLoops": true }]))),
+        ("for(;`foo`;);", Some(json!([{ "checkLoops": true }]))),
+        ("for(;`foo${bar}`;);", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(true)", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while('1')", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(0)", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(t = -2)", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(``)", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(`foo`)", Some(json!([{ "checkLoops": true }]))),
+        ("do{}while(`foo${bar}`)", Some(json!([{ "checkLoops": true }]))),
+        ("while([]);", Some(json!([{ "checkLoops": true }]))),
+        ("while(~!0);", Some(json!([{ "checkLoops": true }]))),
+        ("while(x = 1);", Some(json!([{ "checkLoops": true }]))),
+        ("while(function(){});", Some(json!([{ "checkLoops": true }]))),
+        ("while(true);", None),
+        ("while(1);", Some(json!([{ "checkLoops": true }]))),
+        ("while(() => {});", Some(json!([{ "checkLoops": true }]))),
+        ("while(`foo`);", Some(json!([{ "checkLoops": true }]))),
+        ("while(``);", Some(json!([{ "checkLoops": true }]))),
+        ("while(`${'foo'}`);", Some(json!([{ "checkLoops": true }]))),
+        ("while(`${'foo' + 'bar'}`);", Some(json!([{ "checkLoops": true }]))),
+        ("function* foo(){while(true){} yield 'foo';}", None),
+        ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
+        ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
+        ("var a = function* foo(){while(true){} yield 'foo';}", None),
+        ("while (true) { function* foo() {yield;}}", None),
+        ("function* foo(){if (true) {yield 'foo';}}", None),
+        (
+            "function* foo() {for (let foo = yield; true;) {}}",
+            Some(json!([{ "checkLoops": true }])),
+        ),
+        ("function* foo() {for (foo = yield; true;) {}}", Some(json!([{ "checkLoops": true }]))),
+        ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
+        ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
+        (
+            "function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}",
+            Some(json!([{ "checkLoops": true }])),
+        ),
+        // infinite loops are reported regardless of `checkLoops`
+        ("while(true);", Some(json!([{ "checkLoops": false }]))),
+        ("while(true){}", Some(json!([{ "checkLoops": false }]))),
+        ("while(true){ if (a) { continue; } }", Some(json!([{ "checkLoops": "none" }]))),
+        ("while(true){ for (;;) { break; } }", None),
+        ("while(true){ switch (a) { case 1: break; } }", None),
+        ("while(true){ inner: { break inner; } }", None),
+        ("outer: while(true){ continue outer; }", None),
+        // Only the inner loop is left by `continue outer`.
+        ("outer: while(true){ while(true) { continue outer; } }", None),
+        ("while(true){ (() => { return; })(); }", None),
+        ("while(true){ function foo() { throw new Error(); } }", None),
+        // Exits that can never be reached do not end the loop.
+        ("while(true){ continue; break; }", None),
+        ("while(true){ if (false) break; }", None),
+        ("while(true){ if (true) {} else { break; } }", None),
+        ("while(true){ while (false) { return; } }", None),
+        ("while(x = 1){ if (a) break; }", Some(json!([{ "checkLoops": true }]))),
+        ("do { if (a) break; } while(1)", Some(json!([{ "checkLoops": true }]))),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
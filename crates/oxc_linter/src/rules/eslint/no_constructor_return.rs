@@ -1,11 +1,11 @@
 use oxc_ast::{
-    ast::{MethodDefinition, MethodDefinitionKind},
-    AstKind,
+    ast::{ArrowFunctionExpression, Function, MethodDefinitionKind, ReturnStatement},
+    AstKind, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::AstNodeId;
-use oxc_span::Span;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -47,31 +47,36 @@ declare_oxc_lint!(
 
 impl Rule for NoConstructorReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(ret) = node.kind() else { return };
-        if ret.argument.is_none() {
+        let AstKind::MethodDefinition(method) = node.kind() else { return };
+        if method.kind != MethodDefinitionKind::Constructor {
             return;
         }
+        let Some(body) = &method.value.body else { return };
 
-        if is_definitely_in_constructor(ctx, node.id()) {
-            ctx.diagnostic(no_constructor_return_diagnostic(ret.span));
+        let mut finder = ReturnFinder::default();
+        finder.visit_function_body(body);
+        for span in finder.returns {
+            ctx.diagnostic(no_constructor_return_diagnostic(span));
         }
     }
 }
 
-fn is_constructor(node: &AstNode<'_>) -> bool {
-    matches!(
-        node.kind(),
-        AstKind::MethodDefinition(MethodDefinition { kind: MethodDefinitionKind::Constructor, .. })
-    )
+/// Collects the spans of returned values, without entering nested functions.
+#[derive(Debug, Default)]
+struct ReturnFinder {
+    returns: Vec<Span>,
 }
 
-fn is_definitely_in_constructor(ctx: &LintContext, node_id: AstNodeId) -> bool {
-    ctx.nodes()
-        .ancestors(node_id)
-        .map(|id| ctx.nodes().get_node(id))
-        .skip_while(|node| !node.kind().is_function_like())
-        .nth(1)
-        .is_some_and(is_constructor)
+impl<'a> Visit<'a> for ReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if let Some(argument) = &stmt.argument {
+            self.returns.push(argument.span());
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
 }
 
 #[test]
@@ -97,11 +102,16 @@ fn test() {
         "class C { constructor() { this.fn = () => { return true } } }",
         "class C { constructor() { return } }",
         "class C { constructor() { { return } } }",
+        "class C { constructor() { this.items = [1, 2].map((x) => { return x * 2 }) } }",
+        "class C { constructor() { const f = () => 1; f() } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
     ];
 
     let fail = vec![
         "class C { constructor() { return '' } }",
         "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { const f = () => { return 1 }; return f() } }",
+        "class C { constructor(a) { for (const x of a) { if (x) return x } return null } }",
     ];
 
     Tester::new(NoConstructorReturn::NAME, pass, fail).test_and_snapshot();
@@ -8,7 +8,10 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::{Matches, Regex};
 
-use crate::{ast_util::extract_regex_flags, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::extract_regex_flags, context::LintContext, rule::Rule, utils::regex_pattern_of,
+    AstNode,
+};
 
 fn no_control_regex_diagnostic(regex: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected control character(s)")
@@ -189,30 +192,16 @@ fn regex_pattern<'a>(node: &AstNode<'a>) -> Option<RegexPatternData<'a>> {
 
         // new RegExp()
         AstKind::NewExpression(expr) => {
-            // constructor is RegExp,
-            if expr.callee.is_specific_id("RegExp")
-            // which is provided at least 1 parameter,
-                && expr.arguments.len() > 0
-            {
-                // where the first one is a string literal
-                // note: improvements required for strings used via identifier
-                // references
-                if let Argument::StringLiteral(pattern) = &expr.arguments[0] {
-                    // get pattern from arguments. Missing or non-string arguments
-                    // will be runtime errors, but are not covered by this rule.
-                    // Note that we're intentionally reporting the entire "new
-                    // RegExp("pat") expression, not just "pat".
-                    Some(RegexPatternData {
-                        pattern: PatRef::Owned(RegExpPattern::Raw(pattern.value.as_ref())),
-                        flags: extract_regex_flags(&expr.arguments),
-                        span: kind.span(),
-                    })
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+            // Only patterns and flags known statically can be checked. Note that we're
+            // intentionally reporting the entire `new RegExp("pat")` expression, not just "pat".
+            let (pattern, flags) = regex_pattern_of(expr)?;
+            Some(RegexPatternData {
+                pattern: PatRef::Owned(RegExpPattern::Raw(pattern)),
+                flags: flags.chars().try_fold(RegExpFlags::empty(), |flags, ch| {
+                    RegExpFlags::try_from(ch).ok().map(|flag| flags | flag)
+                }),
+                span: kind.span(),
+            })
         }
 
         // RegExp()
@@ -338,6 +327,9 @@ mod tests {
                 r"var regex = /\\x1f/",
                 "var regex = new RegExp(\"x1f\");",
                 "var regex = RegExp(\"x1f\");",
+                r"new RegExp(source)",
+                r"new RegExp(`${prefix}\x1f`)",
+                r"new RegExp('\x1f', flags)",
                 "new RegExp('[')",
                 "RegExp('[')",
                 "new (function foo(){})('\\x1f')",
@@ -358,6 +350,8 @@ mod tests {
                 "var regex = new RegExp('\\x1fFOO\\x00')",
                 "var regex = new RegExp('FOO\\x1fFOO\\x1f')",
                 "var regex = RegExp('\\x1f')",
+                "var regex = new RegExp(`\\x1f`)",
+                r"new RegExp('\x1f', `g`)",
                 "var regex = /(?<a>\\x1f)/",
                 r"var regex = /(?<\u{1d49c}>.)\x1f/",
                 r"new RegExp('\\u{1111}*\\x1F', 'u')",
@@ -0,0 +1,198 @@
+use oxc_ast::{
+    ast::{ImportOrExportKind, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_duplicate_imports_diagnostic(
+    first: Span,
+    second: Span,
+    module: &str,
+    message: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{module}' {message}"))
+        .with_help("Merge the declarations into one")
+        .with_labels([first.label("It is first used here"), second.label("and again here")])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateImports {
+    include_exports: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate module imports
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Using a single `import` statement per module makes the code clearer, because all the
+    /// bindings imported from that module are listed in one place.
+    ///
+    /// Type-only imports, `import type { Foo } from "foo"`, are only compared with each other.
+    ///
+    /// ### Options
+    ///
+    /// - `includeExports` (default `false`): also check re-exports, e.g. `export { foo } from
+    ///   "foo"` after `import { bar } from "foo"`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { merge } from 'module';
+    /// import something from 'another-module';
+    /// import { find } from 'module';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { merge, find } from 'module';
+    /// import something from 'another-module';
+    /// ```
+    NoDuplicateImports,
+    style
+);
+
+impl Rule for NoDuplicateImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let include_exports = value
+            .get(0)
+            .and_then(|config| config.get("includeExports"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { include_exports }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(root) = ctx.nodes().root_node() else {
+            return;
+        };
+        let AstKind::Program(program) = root.kind() else { unreachable!() };
+
+        let mut imports: FxHashMap<(&str, bool), Span> = FxHashMap::default();
+        let mut exports: FxHashMap<(&str, bool), Span> = FxHashMap::default();
+
+        for statement in &program.body {
+            let (source, is_type, is_import) = match statement {
+                Statement::ImportDeclaration(decl) => {
+                    (&decl.source, decl.import_kind == ImportOrExportKind::Type, true)
+                }
+                Statement::ExportNamedDeclaration(decl) if self.include_exports => {
+                    let Some(source) = &decl.source else {
+                        continue;
+                    };
+                    (source, decl.export_kind == ImportOrExportKind::Type, false)
+                }
+                Statement::ExportAllDeclaration(decl) if self.include_exports => {
+                    (&decl.source, decl.export_kind == ImportOrExportKind::Type, false)
+                }
+                _ => continue,
+            };
+            let key = (source.value.as_str(), is_type);
+            let module = source.value.as_str();
+
+            let (same, other, messages) = if is_import {
+                (
+                    &mut imports,
+                    &exports,
+                    ["import is duplicated.", "import is duplicated as export."],
+                )
+            } else {
+                (
+                    &mut exports,
+                    &imports,
+                    ["export is duplicated.", "export is duplicated as import."],
+                )
+            };
+            if let Some(&first) = same.get(&key) {
+                ctx.diagnostic(no_duplicate_imports_diagnostic(
+                    first,
+                    source.span,
+                    module,
+                    messages[0],
+                ));
+            } else {
+                same.insert(key, source.span);
+            }
+            if let Some(&first) = other.get(&key) {
+                ctx.diagnostic(no_duplicate_imports_diagnostic(
+                    first,
+                    source.span,
+                    module,
+                    messages[1],
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from 'os';\nimport fs from 'fs';", None),
+        ("import { merge } from 'lodash-es';", None),
+        ("import _, { merge } from 'lodash-es';", None),
+        ("import * as Foobar from 'async';", None),
+        ("import 'foo'", None),
+        ("import os from 'os';\nexport { something } from 'os';", None),
+        ("import * as bar from 'os';\nimport { baz } from 'fs';", None),
+        ("import foo from 'os';\nexport * from 'os';", None),
+        (
+            "import os from 'os';\nexport { hello } from 'hello';",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        ("import os from 'os';\nexport * from 'hello';", Some(json!([{ "includeExports": true }]))),
+        (
+            "import os from 'os';\nexport { hello as hi } from 'hello';",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        (
+            "import os from 'os';\nexport default function(){};",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        (
+            "import { merge } from 'lodash-es';\nexport { merge as lodashMerge }",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        ("export { something } from 'os';\nexport * as os from 'os';", None),
+        ("import type { Foo } from 'foo';\nimport { bar } from 'foo';", None),
+    ];
+
+    let fail = vec![
+        ("import 'fs';\nimport 'fs'", None),
+        ("import { merge } from 'lodash-es';\nimport { find } from 'lodash-es';", None),
+        ("import { merge } from 'lodash-es';\nimport _ from 'lodash-es';", None),
+        (
+            "import os from 'os';\nimport { something } from 'os';\nimport * as foobar from 'os';",
+            None,
+        ),
+        ("import type { Foo } from 'foo';\nimport type { Bar } from 'foo';", None),
+        (
+            "export { os } from 'os';\nexport { something } from 'os';",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        (
+            "import os from 'os';\nexport { os as foobar } from 'os';\nexport { something } from 'os';",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        (
+            "import os from 'os';\nexport { something } from 'os';",
+            Some(json!([{ "includeExports": true }])),
+        ),
+        ("import os from 'os';\nexport * from 'os';", Some(json!([{ "includeExports": true }]))),
+        ("export * from 'os';\nimport os from 'os';", Some(json!([{ "includeExports": true }]))),
+    ];
+
+    Tester::new(NoDuplicateImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,403 @@
+use oxc_ast::{
+    ast::{IfStatement, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::Idx;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{block_always_completes_abruptly, is_jump_target_inside},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_else_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary 'else' after 'return'.")
+        .with_help("Remove the `else` and move its body after the `if` statement.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoElseReturn {
+    allow_else_if: bool,
+}
+
+impl Default for NoElseReturn {
+    fn default() -> Self {
+        Self { allow_else_if: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `else` blocks after `return` statements in `if` statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If an `if` block always ends in a `return`, the `else` block is unnecessary. Its
+    /// contents can be placed after the `if` statement, one level of nesting less.
+    ///
+    /// The fix is not applied when the `else` block declares a `let`, `const`, `class` or
+    /// function whose name is already used around the `if` statement, since moving the
+    /// declaration out of the block would clash with it. `var` declarations already belong to
+    /// the enclosing function and are moved as they are.
+    ///
+    /// ### Options
+    ///
+    /// - `allowElseIf` (default `true`): allow `else if` after a `return`. With `false`,
+    ///   `if (a) { return b; } else if (c) { ... }` is reported as well.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function foo(a) {
+    ///     if (a) {
+    ///         return 1;
+    ///     } else {
+    ///         return 2;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function foo(a) {
+    ///     if (a) {
+    ///         return 1;
+    ///     }
+    ///     return 2;
+    /// }
+    /// ```
+    NoElseReturn,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoElseReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_else_if = value
+            .get(0)
+            .and_then(|config| config.get("allowElseIf"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+        Self { allow_else_if }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else {
+            return;
+        };
+        // The `else` can only be removed if its body can follow the `if` statement.
+        if !matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::TSModuleBlock(_)
+            )
+        ) {
+            return;
+        }
+
+        // The `if` statement whose `else` is reported.
+        let last_if = if self.allow_else_if {
+            // `if (a) { return; } else if (b) { return; } else { ... }`: only the final `else`
+            // is reported, and only if every branch before it returns.
+            let (mut current_node, mut current) = (node, if_stmt);
+            loop {
+                if !always_returns(current_node, &current.consequent, ctx) {
+                    return;
+                }
+                match &current.alternate {
+                    Some(Statement::IfStatement(alternate)) => {
+                        let Some(alternate_node) = child_node(current_node, alternate.span, ctx)
+                        else {
+                            return;
+                        };
+                        (current_node, current) = (alternate_node, &**alternate);
+                    }
+                    Some(_) => break current,
+                    None => return,
+                }
+            }
+        } else {
+            if if_stmt.alternate.is_none() || !always_returns(node, &if_stmt.consequent, ctx) {
+                return;
+            }
+            if_stmt
+        };
+        let Some(alternate) = &last_if.alternate else {
+            return;
+        };
+
+        let diagnostic = no_else_return_diagnostic(alternate.span());
+        if !is_safe_to_unwrap(last_if, alternate, ctx) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let indentation = line_indentation(ctx.source_text(), if_stmt.span.start);
+            let body = unwrapped_body(alternate, indentation, ctx);
+            fixer.replace(
+                Span::new(last_if.consequent.span().end, alternate.span().end),
+                format!("\n{indentation}{body}"),
+            )
+        });
+    }
+}
+
+/// Whether `consequent`, the consequent of the `if` statement `if_node`, always ends in a
+/// `return`.
+///
+/// Branches that end in a `throw`, or in a `break` or `continue` that leaves `consequent`, are
+/// not enough: the `else` is only unnecessary after a `return`.
+fn always_returns(if_node: &AstNode, consequent: &Statement, ctx: &LintContext) -> bool {
+    let Some(consequent_node) = child_node(if_node, consequent.span(), ctx) else {
+        return false;
+    };
+    if !block_always_completes_abruptly(consequent_node, ctx) {
+        return false;
+    }
+
+    let span = consequent.span();
+    let mut nested_function_end = None;
+    !descendants(consequent_node, ctx).any(|node| {
+        let node_span = node.kind().span();
+        if nested_function_end.is_some_and(|end| node_span.end <= end) {
+            return false;
+        }
+        match node.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                nested_function_end = Some(node_span.end);
+                false
+            }
+            AstKind::ThrowStatement(_) => true,
+            AstKind::BreakStatement(_) | AstKind::ContinueStatement(_) => {
+                !is_jump_target_inside(node.id(), span, ctx)
+            }
+            _ => false,
+        }
+    })
+}
+
+/// The nodes inside of `node`, in source order.
+fn descendants<'a, 'c>(
+    node: &AstNode,
+    ctx: &'c LintContext<'a>,
+) -> impl Iterator<Item = &'c AstNode<'a>> + 'c {
+    let span = node.kind().span();
+    // Nodes are created in pre-order, so the descendants of `node` are the nodes right after it,
+    // up to the first node outside of its span.
+    ctx.nodes()
+        .iter()
+        .skip(node.id().index() + 1)
+        .take_while(move |descendant| span.contains_inclusive(descendant.kind().span()))
+}
+
+/// The child of `parent` that covers `span`.
+fn child_node<'a, 'c>(
+    parent: &AstNode,
+    span: Span,
+    ctx: &'c LintContext<'a>,
+) -> Option<&'c AstNode<'a>> {
+    let parent_id = parent.id();
+    descendants(parent, ctx).find(|node| {
+        node.kind().span() == span && ctx.nodes().parent_id(node.id()) == Some(parent_id)
+    })
+}
+
+/// Whether the `else` of `if_stmt` can be removed without changing what the code means.
+fn is_safe_to_unwrap(if_stmt: &IfStatement, alternate: &Statement, ctx: &LintContext) -> bool {
+    let consequent_end = if_stmt.consequent.span().end;
+    // `if (a) return b\nelse (c)` would become `return b\n(c)`.
+    let consequent = ctx.source_range(if_stmt.consequent.span());
+    if !consequent.ends_with([';', '}']) {
+        return false;
+    }
+    // Comments next to the `else` keyword would be lost.
+    if ctx
+        .semantic()
+        .trivias()
+        .has_comments_between(Span::new(consequent_end, alternate.span().start))
+    {
+        return false;
+    }
+
+    let Statement::BlockStatement(block) = alternate else {
+        return true;
+    };
+    let Some(block_scope) = block.scope_id.get() else {
+        return true;
+    };
+    // Block-scoped declarations of the `else` block end up in the scope of the `if` statement,
+    // where they could clash with or shadow variables of the same name. `var` declarations
+    // are not bindings of the block scope, they already live in the enclosing function.
+    let scopes = ctx.scopes();
+    let Some(outer_scope) = scopes.get_parent_id(block_scope) else {
+        return true;
+    };
+    scopes.get_bindings(block_scope).keys().all(|name| {
+        scopes.find_binding(outer_scope, name).is_none()
+            && !scopes.root_unresolved_references().contains_key(name)
+    })
+}
+
+/// The whitespace at the start of the line `offset` is on.
+fn line_indentation(source_text: &str, offset: u32) -> &str {
+    let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source_text[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// The statements of `alternate`, dedented by one level if it is a block.
+fn unwrapped_body(alternate: &Statement, indentation: &str, ctx: &LintContext) -> String {
+    let Statement::BlockStatement(block) = alternate else {
+        return ctx.source_range(alternate.span()).to_string();
+    };
+    let inner = ctx.source_range(Span::new(block.span.start + 1, block.span.end - 1));
+    let body = inner.trim();
+    // Indentation of the first statement if it is on its own line. Lines inside template
+    // literals must not be changed, so those bodies are kept as they are.
+    let leading = &inner[..inner.len() - inner.trim_start().len()];
+    let body_indentation = leading.rsplit_once('\n').map(|(_, indentation)| indentation);
+    match body_indentation {
+        Some(body_indentation) if !body.contains('`') => body
+            .split('\n')
+            .enumerate()
+            .map(|(i, line)| match line.strip_prefix(body_indentation) {
+                Some(rest) if i > 0 => format!("{indentation}{rest}"),
+                _ => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => body.to_string(),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { var bar = function() { if (baz) { return true; } }; }", None),
+        ("function foo() { if (bar) { return true; } return false; }", None),
+        ("function foo() { if (bar) { foo(); } else { return false; } }", None),
+        ("function foo() { if (bar) { return true; } else if (baz) { foo(); } }", None),
+        ("function foo() { if (bar) return true; else if (baz) foo(); }", None),
+        ("function foo() { if (bar) { return true; } else if (baz) { return false; } }", None),
+        (
+            "function foo() { if (bar) { return true; } else if (baz) { foo(); } else { bar(); } }",
+            None,
+        ),
+        ("function foo() { if (bar) { throw baz; } else { qux(); } }", None),
+        ("function foo() { while (a) if (bar) { return true; } else { qux(); } }", None),
+        ("function foo() { if (a) { if (b) { return 1; } } else { foo(); } }", None),
+        ("function foo() { if (a) { if (b) { return 1; } throw c; } else { foo(); } }", None),
+        ("function foo() { while (a) { if (b) { break; } else { foo(); } } }", None),
+        ("function foo() { if (a) { try { return b(); } catch { c(); } } else { foo(); } }", None),
+        (
+            "function foo() { if (bar) { return true; } else if (baz) { return false; } }",
+            Some(json!([{ "allowElseIf": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (bar) { return true; } else { baz(); } }", None),
+        ("function foo() { if (bar) return true; else baz(); }", None),
+        ("function foo() { if (bar) { foo(); return true; } else { baz(); } }", None),
+        (
+            "function foo() { if (a) { return 1; } else if (b) { return 2; } else { return 3; } }",
+            None,
+        ),
+        (
+            "function foo() { if (a) { if (b) { return 1; } else { return 2; } } else { foo(); } }",
+            None,
+        ),
+        (
+            "function foo() { if (a) { return 1; } else if (b) { foo(); } }",
+            Some(json!([{ "allowElseIf": false }])),
+        ),
+        ("function foo() { if (a) { return 1; } else { let a = 2; } }", None),
+        ("function foo(x) { if (a) { return 1; } else { const x = 2; } }", None),
+        ("function foo() { if (a) { return 1; } else { function bar() {} } bar(); }", None),
+        ("function foo() { if (a) { return 1; } /* comment */ else { foo(); } }", None),
+        ("function foo() { if (a) return 1\nelse (b)() }", None),
+        ("if (a) { return 1; } else { foo(); }", None),
+        ("function foo() { if (a) { try { return b(); } catch { return c; } } else { d(); } }", None),
+        (
+            "function foo() { if (a) { switch (b) { case 1: return 1; default: return 2; } } else { c(); } }",
+            None,
+        ),
+        ("function foo() { if (a) { for (;;) { break; } return 1; } else { b(); } }", None),
+    ];
+
+    let fix = vec![
+        (
+            "function foo() {\n    if (bar) {\n        return true;\n    } else {\n        baz();\n        qux();\n    }\n}",
+            "function foo() {\n    if (bar) {\n        return true;\n    }\n    baz();\n    qux();\n}",
+            None,
+        ),
+        (
+            "function foo() {\n    if (bar) {\n        return true;\n    } else {\n        if (baz) {\n            qux();\n        }\n    }\n}",
+            "function foo() {\n    if (bar) {\n        return true;\n    }\n    if (baz) {\n        qux();\n    }\n}",
+            None,
+        ),
+        (
+            "function foo() { if (bar) return true; else baz(); }",
+            "function foo() { if (bar) return true;\nbaz(); }",
+            None,
+        ),
+        (
+            "function foo() {\n  if (a) {\n    return 1;\n  } else if (b) {\n    return 2;\n  } else {\n    return 3;\n  }\n}",
+            "function foo() {\n  if (a) {\n    return 1;\n  } else if (b) {\n    return 2;\n  }\n  return 3;\n}",
+            None,
+        ),
+        (
+            "function foo() {\n  if (a) {\n    return 1;\n  } else if (b) {\n    foo();\n  }\n}",
+            "function foo() {\n  if (a) {\n    return 1;\n  }\n  if (b) {\n    foo();\n  }\n}",
+            Some(json!([{ "allowElseIf": false }])),
+        ),
+        (
+            "function foo() {\n  if (a) {\n    return 1;\n  } else {\n    var x = `a\n    b`;\n  }\n}",
+            "function foo() {\n  if (a) {\n    return 1;\n  }\n  var x = `a\n    b`;\n}",
+            None,
+        ),
+        (
+            "function foo() { if (a) { return 1; } else { let a = 2; } }",
+            "function foo() { if (a) { return 1; } else { let a = 2; } }",
+            None,
+        ),
+        (
+            "function foo() { if (a) { return 1; } else { let b = 2; } }",
+            "function foo() { if (a) { return 1; }\nlet b = 2; }",
+            None,
+        ),
+        (
+            "function foo(x) { if (a) { return 1; } else { const x = 2; } }",
+            "function foo(x) { if (a) { return 1; } else { const x = 2; } }",
+            None,
+        ),
+        (
+            "function foo() { if (a) { return 1; } /* comment */ else { foo(); } }",
+            "function foo() { if (a) { return 1; } /* comment */ else { foo(); } }",
+            None,
+        ),
+        (
+            "function foo() { if (a) return 1\nelse (b)() }",
+            "function foo() { if (a) return 1\nelse (b)() }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoElseReturn::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
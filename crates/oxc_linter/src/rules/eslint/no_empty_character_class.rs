@@ -1,12 +1,10 @@
 // Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-empty-character-class.js
-use lazy_static::lazy_static;
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use regex::Regex;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::parse_regex_literal, AstNode};
 
 fn no_empty_character_class_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Empty character class")
@@ -34,26 +32,16 @@ declare_oxc_lint!(
 
 impl Rule for NoEmptyCharacterClass {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        lazy_static! {
-            /*
-            * plain-English description of the following regexp:
-            * 0. `^` fix the match at the beginning of the string
-            * 1. `([^\\[]|\\.|\[([^\\\]]|\\.)+\])*`: regexp contents; 0 or more of the following
-            * 1.0. `[^\\[]`: any character that's not a `\` or a `[` (anything but escape sequences and character classes)
-            * 1.1. `\\.`: an escape sequence
-            * 1.2. `\[([^\\\]]|\\.)+\]`: a character class that isn't empty
-            * 2. `$`: fix the match at the end of the string
-            */
-            static ref NO_EMPTY_CLASS_REGEX_PATTERN: Regex =
-                Regex::new(r"^([^\\\[]|\\.|\[([^\\\]]|\\.)+\])*$").unwrap();
-        }
-
-        if let AstKind::RegExpLiteral(lit) = node.kind() {
-            if !NO_EMPTY_CLASS_REGEX_PATTERN
-                .is_match(lit.regex.pattern.source_text(ctx.source_text()).as_ref())
-            {
-                ctx.diagnostic(no_empty_character_class_diagnostic(lit.span));
-            }
+        let AstKind::RegExpLiteral(lit) = node.kind() else {
+            return;
+        };
+        // Invalid patterns are reported by `no-invalid-regexp`.
+        let Ok(regex) = parse_regex_literal(lit) else {
+            return;
+        };
+        // `[^]` matches any character.
+        if regex.classes.iter().any(|class| !class.negative && class.body.is_empty()) {
+            ctx.diagnostic(no_empty_character_class_diagnostic(lit.span));
         }
     }
 }
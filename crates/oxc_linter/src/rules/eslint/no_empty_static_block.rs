@@ -74,6 +74,8 @@ fn test() {
 			// comment
 			} }",
         "class Foo { static { bar(); } static { bar(); } }",
+        "class Foo { static { /* first */ /* second */ } }",
+        "class Foo { static { class Bar { static { bar(); } } } }",
     ];
 
     let fail = vec![
@@ -83,6 +85,10 @@ fn test() {
 
 			 } }",
         "class Foo { static { bar(); } static {} }",
+        // Comments outside of the block do not count.
+        "class Foo { /* comment */ static {} }",
+        "class Foo { static {} // comment
+			}",
     ];
 
     Tester::new(NoEmptyStaticBlock::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,543 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BinaryOperator, Expression, LogicalOperator, ParenthesizedExpression,
+        UnaryOperator,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_part,
+    precedence::{GetPrecedence, Precedence},
+};
+
+use crate::{ast_util::expression_precedence, context::LintContext, rule::Rule, AstNode};
+
+fn no_extra_parens_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary parentheses around expression.")
+        .with_help("Remove the parentheses.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoExtraParens {
+    /// Only report parentheses around function expressions.
+    functions_only: bool,
+    conditional_assign: bool,
+    return_assign: bool,
+    nested_binary_expressions: bool,
+    enforce_for_arrow_conditionals: bool,
+}
+
+impl Default for NoExtraParens {
+    fn default() -> Self {
+        Self {
+            functions_only: false,
+            conditional_assign: true,
+            return_assign: true,
+            nested_binary_expressions: true,
+            enforce_for_arrow_conditionals: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary parentheses.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Parentheses that don't change the order of evaluation or how the code is parsed are
+    /// noise. This rule reports them and removes them when fixing. Parentheses are always
+    /// allowed where they are needed, e.g. around a function expression or object literal at
+    /// the start of a statement, around the function of an IIFE, or around a sequence
+    /// expression in an argument list.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "eslint/no-extra-parens": ["error", "all", { "nestedBinaryExpressions": false }]
+    /// ```
+    ///
+    /// The first option is either `"all"` (default), which reports all unnecessary
+    /// parentheses, or `"functions"`, which only reports them around function expressions.
+    ///
+    /// With `"all"`, these exceptions can be configured:
+    /// - `conditionalAssign: false` allows parentheses around assignments in the tests of
+    ///   conditional statements and loops, e.g. `while ((node = node.next))`.
+    /// - `returnAssign: false` allows parentheses around assignments in `return` statements
+    ///   and arrow function bodies.
+    /// - `nestedBinaryExpressions: false` allows parentheses in nested binary and logical
+    ///   expressions, e.g. `a || (b && c)`.
+    /// - `enforceForArrowConditionals: false` allows parentheses around a conditional
+    ///   expression that is the body of an arrow function.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// a = (b * c);
+    /// (a * b) + c;
+    /// for (a in (b, c));
+    /// typeof (a);
+    /// (function(){} ? a() : b());
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// (0).toString();
+    /// ({}.toString.call());
+    /// (function(){}) ? a() : b();
+    /// new (a())();
+    /// a = (b, c);
+    /// ```
+    NoExtraParens,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoExtraParens {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let functions_only = value.get(0).and_then(serde_json::Value::as_str) == Some("functions");
+        let exceptions = value.get(1);
+        let exception = |name: &str, default: bool| {
+            exceptions
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self {
+            functions_only,
+            conditional_assign: exception("conditionalAssign", default.conditional_assign),
+            return_assign: exception("returnAssign", default.return_assign),
+            nested_binary_expressions: exception(
+                "nestedBinaryExpressions",
+                default.nested_binary_expressions,
+            ),
+            enforce_for_arrow_conditionals: exception(
+                "enforceForArrowConditionals",
+                default.enforce_for_arrow_conditionals,
+            ),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ParenthesizedExpression(paren) = node.kind() else {
+            return;
+        };
+        let inner = &paren.expression;
+        if self.functions_only && !matches!(inner, Expression::FunctionExpression(_)) {
+            return;
+        }
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return;
+        };
+        if is_necessary(paren, parent, ctx) || is_at_start_of_statement(paren, node, ctx) {
+            return;
+        }
+        if !self.functions_only && self.is_exception(inner, parent, ctx) {
+            return;
+        }
+
+        let diagnostic = no_extra_parens_diagnostic(paren.span);
+        // Only fix if nothing but whitespace surrounds the expression, so no comments are lost.
+        let contents = ctx.source_range(paren.span.shrink(1));
+        let expression = ctx.source_range(inner.span());
+        if contents.trim() != expression {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let before = ctx.source_text()[..paren.span.start as usize].chars().next_back();
+            let after = ctx.source_text()[paren.span.end as usize..].chars().next();
+            let mut replacement = String::with_capacity(expression.len() + 2);
+            if before.zip(expression.chars().next()).is_some_and(|(a, b)| needs_space(a, b)) {
+                replacement.push(' ');
+            }
+            replacement.push_str(expression);
+            if expression.chars().next_back().zip(after).is_some_and(|(a, b)| needs_space(a, b)) {
+                replacement.push(' ');
+            }
+            fixer.replace(paren.span, replacement)
+        });
+    }
+}
+
+impl NoExtraParens {
+    fn is_exception(&self, inner: &Expression, parent: &AstNode, ctx: &LintContext) -> bool {
+        let is_assignment = matches!(inner, Expression::AssignmentExpression(_));
+        match parent.kind() {
+            AstKind::IfStatement(_)
+            | AstKind::WhileStatement(_)
+            | AstKind::DoWhileStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ConditionalExpression(_)
+                if is_assignment =>
+            {
+                !self.conditional_assign
+            }
+            AstKind::ReturnStatement(_) if is_assignment => !self.return_assign,
+            AstKind::BinaryExpression(_) | AstKind::LogicalExpression(_) => {
+                !self.nested_binary_expressions
+                    && matches!(
+                        inner,
+                        Expression::BinaryExpression(_) | Expression::LogicalExpression(_)
+                    )
+            }
+            AstKind::ExpressionStatement(_) if is_arrow_body(parent, ctx) => {
+                (is_assignment && !self.return_assign)
+                    || (!self.enforce_for_arrow_conditionals
+                        && matches!(inner, Expression::ConditionalExpression(_)))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether removing the parentheses would change how the code is parsed, given the node
+/// they are in.
+fn is_necessary(paren: &ParenthesizedExpression, parent: &AstNode, ctx: &LintContext) -> bool {
+    let inner = &paren.expression;
+    let precedence = expression_precedence(inner);
+    let is_sequence = precedence <= Precedence::Comma;
+    match parent.kind() {
+        AstKind::ParenthesizedExpression(_)
+        | AstKind::IfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::SwitchStatement(_)
+        | AstKind::SwitchCase(_)
+        | AstKind::ThrowStatement(_)
+        | AstKind::ReturnStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::TemplateLiteral(_)
+        | AstKind::PropertyKey(_) => false,
+        AstKind::ExpressionStatement(_) => {
+            // Directives, e.g. `("use strict")`.
+            matches!(inner, Expression::StringLiteral(_))
+                || (is_arrow_body(parent, ctx) && is_sequence)
+        }
+        AstKind::ForOfStatement(_)
+        | AstKind::Argument(_)
+        | AstKind::ExpressionArrayElement(_)
+        | AstKind::SpreadElement(_)
+        | AstKind::ObjectProperty(_)
+        | AstKind::VariableDeclarator(_)
+        | AstKind::AssignmentExpression(_)
+        | AstKind::AssignmentPattern(_)
+        | AstKind::SequenceExpression(_)
+        | AstKind::YieldExpression(_)
+        | AstKind::ExportDefaultDeclaration(_) => is_sequence,
+        AstKind::ConditionalExpression(conditional) => {
+            if conditional.test.span() == paren.span {
+                precedence <= Precedence::Conditional
+            } else {
+                is_sequence
+            }
+        }
+        AstKind::BinaryExpression(binary) => is_necessary_operand(
+            inner,
+            binary.operator.precedence(),
+            binary.operator == BinaryOperator::Exponential,
+            binary.right.span() == paren.span,
+        ),
+        AstKind::LogicalExpression(logical) => {
+            let mixes_coalesce = match inner {
+                Expression::LogicalExpression(inner) => {
+                    (inner.operator == LogicalOperator::Coalesce)
+                        != (logical.operator == LogicalOperator::Coalesce)
+                }
+                _ => false,
+            };
+            mixes_coalesce
+                || is_necessary_operand(
+                    inner,
+                    logical.operator.precedence(),
+                    false,
+                    logical.right.span() == paren.span,
+                )
+        }
+        AstKind::UnaryExpression(unary) => {
+            precedence < Precedence::Prefix
+                || match unary.operator {
+                    // `-(-a)` is not `--a`.
+                    UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus => {
+                        let operator = unary.operator.as_str();
+                        matches!(
+                            inner,
+                            Expression::UnaryExpression(_) | Expression::UpdateExpression(_)
+                        ) && ctx.source_range(inner.span()).starts_with(operator)
+                    }
+                    _ => false,
+                }
+        }
+        AstKind::AwaitExpression(_) => precedence < Precedence::Prefix,
+        // Computed properties allow any expression, e.g. `a[(b)]`.
+        AstKind::MemberExpression(member) => {
+            member.object().span() == paren.span && !is_valid_member_object(inner)
+        }
+        // Arguments have their own node, so the parentheses are around the callee here.
+        AstKind::CallExpression(_) | AstKind::TaggedTemplateExpression(_) => {
+            !is_valid_callee(inner)
+        }
+        AstKind::NewExpression(_) => !matches!(inner, Expression::Identifier(_)),
+        _ => true,
+    }
+}
+
+/// Whether an operand of a binary or logical expression with `operator_precedence` needs
+/// parentheses. Operands with the same precedence need parentheses on the right side, except
+/// for the right-associative `**`.
+fn is_necessary_operand(
+    inner: &Expression,
+    operator_precedence: Precedence,
+    is_exponential: bool,
+    is_right: bool,
+) -> bool {
+    let precedence = expression_precedence(inner);
+    if is_exponential {
+        // `(-a) ** b` is a syntax error without parentheses.
+        return if is_right {
+            precedence < operator_precedence
+        } else {
+            precedence <= operator_precedence
+                || matches!(inner, Expression::UnaryExpression(_) | Expression::AwaitExpression(_))
+        };
+    }
+    if is_right {
+        precedence <= operator_precedence
+    } else {
+        precedence < operator_precedence
+    }
+}
+
+/// Whether `expr` can be the object of a member expression without parentheses.
+fn is_valid_member_object(expr: &Expression) -> bool {
+    match expr {
+        // `(1).toString()` is not `1.toString()`.
+        Expression::NumericLiteral(_) => false,
+        expr => is_valid_callee(expr),
+    }
+}
+
+/// Whether `expr` can be called without parentheses.
+fn is_valid_callee(expr: &Expression) -> bool {
+    match expr {
+        // `(a?.b)()` is not `a?.b()`, `(new A).b` is not `new A.b`, and parentheses around
+        // IIFEs are conventional.
+        Expression::ChainExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::NewExpression(_) => false,
+        expr => expression_precedence(expr) >= Precedence::Call,
+    }
+}
+
+/// Whether `parent` is the expression statement of an arrow function with an expression body.
+fn is_arrow_body(parent: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(parent.id()).nth(2).is_some_and(
+        |node| matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression),
+    )
+}
+
+/// Whether the parentheses are needed because the expression would otherwise be parsed as a
+/// statement or declaration, e.g. `({}).toString()` or `(function () {}).call()`.
+fn is_at_start_of_statement(
+    paren: &ParenthesizedExpression,
+    node: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    if !starts_with_ambiguous_token(&paren.expression) {
+        return false;
+    }
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::ExportDefaultDeclaration(_) => return true,
+            kind if kind.span().start != paren.span.start => return false,
+            AstKind::ExpressionStatement(_) => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Whether the leftmost token of `expr` would start a block, declaration or `let` declaration
+/// at the start of a statement.
+fn starts_with_ambiguous_token(expr: &Expression) -> bool {
+    match expr {
+        Expression::ObjectExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_) => true,
+        Expression::Identifier(ident) => ident.name == "let",
+        Expression::StaticMemberExpression(member) => starts_with_ambiguous_token(&member.object),
+        Expression::ComputedMemberExpression(member) => starts_with_ambiguous_token(&member.object),
+        Expression::PrivateFieldExpression(member) => starts_with_ambiguous_token(&member.object),
+        Expression::CallExpression(call) => starts_with_ambiguous_token(&call.callee),
+        Expression::TaggedTemplateExpression(tagged) => starts_with_ambiguous_token(&tagged.tag),
+        Expression::BinaryExpression(binary) => starts_with_ambiguous_token(&binary.left),
+        Expression::LogicalExpression(logical) => starts_with_ambiguous_token(&logical.left),
+        Expression::ConditionalExpression(conditional) => {
+            starts_with_ambiguous_token(&conditional.test)
+        }
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.first().is_some_and(starts_with_ambiguous_token)
+        }
+        Expression::AssignmentExpression(assignment) => match &assignment.left {
+            AssignmentTarget::ObjectAssignmentTarget(_) => true,
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => ident.name == "let",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether removing parentheses between `before` and `after` would join two tokens, e.g.
+/// `typeof(a)` or `a+(+b)`.
+fn needs_space(before: char, after: char) -> bool {
+    (is_identifier_part(before) && is_identifier_part(after))
+        || (before == '+' && after == '+')
+        || (before == '-' && after == '-')
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let functions = || Some(serde_json::json!(["functions"]));
+    let all_with = |exceptions: serde_json::Value| Some(serde_json::json!(["all", exceptions]));
+
+    let pass = vec![
+        ("a = b * c;", None),
+        ("a * (b * c);", None),
+        ("a - (b - c);", None),
+        ("(a + b) * c;", None),
+        ("a * (b + c);", None),
+        ("(a, b) ? c : d;", None),
+        ("(a ? b : c) ? d : e;", None),
+        ("(a = b) ? c : d;", None),
+        ("a = (b, c);", None),
+        ("f((a, b));", None),
+        ("[(a, b)];", None),
+        ("for (x of (a, b));", None),
+        ("(a ?? b) || c;", None),
+        ("a ?? (b && c);", None),
+        ("(-a) ** b;", None),
+        ("(a ** b) ** c;", None),
+        ("-(a ** b);", None),
+        ("-(-a);", None),
+        ("+(+a);", None),
+        ("-(--a);", None),
+        ("typeof (a + b);", None),
+        ("(a + b).c;", None),
+        ("(0).toString();", None),
+        ("(new A).b;", None),
+        ("(a?.b).c;", None),
+        ("(a?.b)();", None),
+        ("(a, b)();", None),
+        ("new (a())();", None),
+        ("new (a.b())();", None),
+        ("(function() {})();", None),
+        ("(() => {})();", None),
+        ("var x = (function() {})();", None),
+        ("({}).toString();", None),
+        ("({}.toString.call());", None),
+        ("(function() {}).call(a);", None),
+        ("(function() {}) ? a() : b();", None),
+        ("(class {}).name;", None),
+        ("({ a } = b);", None),
+        ("(new A()).b;", None),
+        ("(a ? b : c) || d;", None),
+        ("a = (b, c) ? d : e;", None),
+        ("export default (function() {});", None),
+        ("export default (class {});", None),
+        ("export default (function() {})();", None),
+        ("export default (a, b);", None),
+        ("('use strict');", None),
+        ("() => ({});", None),
+        ("() => ({}).x;", None),
+        ("() => (a, b);", None),
+        ("a(b) ? c : d;", functions()),
+        ("a = (b * c);", functions()),
+        ("while ((a = b));", all_with(serde_json::json!({ "conditionalAssign": false }))),
+        ("if ((a = b));", all_with(serde_json::json!({ "conditionalAssign": false }))),
+        (
+            "function f() { return (a = b); }",
+            all_with(serde_json::json!({ "returnAssign": false })),
+        ),
+        ("() => (a = b);", all_with(serde_json::json!({ "returnAssign": false }))),
+        ("a + (b * c);", all_with(serde_json::json!({ "nestedBinaryExpressions": false }))),
+        ("(a && b) || c;", all_with(serde_json::json!({ "nestedBinaryExpressions": false }))),
+        (
+            "() => (a ? b : c);",
+            all_with(serde_json::json!({ "enforceForArrowConditionals": false })),
+        ),
+    ];
+
+    let fail = vec![
+        ("a = (b * c);", None),
+        ("(a * b) + c;", None),
+        ("a + (b * c);", None),
+        ("((a));", None),
+        ("(a);", None),
+        ("if ((a)) {}", None),
+        ("while ((a)) {}", None),
+        ("while ((a = b));", None),
+        ("switch ((a)) {}", None),
+        ("for (a in (b, c));", None),
+        ("for (a of (b));", None),
+        ("throw (a);", None),
+        ("function f() { return (a); }", None),
+        ("function f() { return (a = b); }", None),
+        ("typeof (a);", None),
+        ("!(a);", None),
+        ("-(+a);", None),
+        ("async function f() { await (a); }", None),
+        ("f((a));", None),
+        ("[(a)];", None),
+        ("x = { a: (b) };", None),
+        ("x = { [(a)]: b };", None),
+        ("a[(b)];", None),
+        ("a[(b, c)];", None),
+        ("`${(a)}`;", None),
+        ("(a).b;", None),
+        ("(a.b).c;", None),
+        ("(a()).b;", None),
+        ("(a.b)();", None),
+        ("new (A)();", None),
+        ("(a)`b`;", None),
+        ("a ? (b) : c;", None),
+        ("(a || b) ? c : d;", None),
+        ("(a * b) * c;", None),
+        ("var x = (a);", None),
+        ("function f(a = (b)) {}", None),
+        ("() => (a);", None),
+        ("() => (a ? b : c);", None),
+        ("(a ** b) + c;", None),
+        ("a ** (b ** c);", None),
+        ("x = (function() {});", functions()),
+        ("a + (b * c);", all_with(serde_json::json!({ "conditionalAssign": false }))),
+        ("a + (b);", all_with(serde_json::json!({ "nestedBinaryExpressions": false }))),
+    ];
+
+    let fix = vec![
+        ("a = (b * c);", "a = b * c;"),
+        ("((a));", "(a);"),
+        ("(a * b) + c;", "a * b + c;"),
+        ("typeof(a);", "typeof a;"),
+        ("(a)in b;", "a in b;"),
+        ("a+(+b);", "a+ +b;"),
+        ("a-(-b);", "a- -b;"),
+        ("f(( a ));", "f(a);"),
+        ("f((/* c */ a));", "f((/* c */ a));"),
+        ("(a.b)();", "a.b();"),
+        ("`${(a)}`;", "`${a}`;"),
+    ];
+
+    Tester::new(NoExtraParens::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
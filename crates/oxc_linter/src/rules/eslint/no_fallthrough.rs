@@ -20,12 +20,16 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-fn no_fallthrough_case_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Expected a 'break' statement before 'case'.").with_label(span)
+fn no_fallthrough_case_diagnostic(span: Span, next_case_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a 'break' statement before 'case'.")
+        .with_label(span.label("This case falls through"))
+        .add_related_label(next_case_span, "into this one")
 }
 
-fn no_fallthrough_default_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Expected a 'break' statement before 'default'.").with_label(span)
+fn no_fallthrough_default_diagnostic(span: Span, next_case_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a 'break' statement before 'default'.")
+        .with_label(span.label("This case falls through"))
+        .add_related_label(next_case_span, "into this one")
 }
 
 fn no_unused_fallthrough_diagnostic(span: Span) -> OxcDiagnostic {
@@ -337,17 +341,26 @@ impl Rule for NoFallthrough {
             };
 
             if is_illegal_fallthrough {
-                let span = next_case.span;
+                let span = fallthrough_case_span(case);
                 if next_case.is_default_case() {
-                    ctx.diagnostic(no_fallthrough_default_diagnostic(span));
+                    ctx.diagnostic(no_fallthrough_default_diagnostic(span, next_case.span));
                 } else {
-                    ctx.diagnostic(no_fallthrough_case_diagnostic(span));
+                    ctx.diagnostic(no_fallthrough_case_diagnostic(span, next_case.span));
                 }
             }
         }
     }
 }
 
+/// The `case test` or `default` part of the case that falls through.
+fn fallthrough_case_span(case: &SwitchCase) -> Span {
+    match &case.test {
+        Some(test) => Span::new(case.span.start, test.span().end),
+        // `default`
+        None => Span::sized(case.span.start, 7),
+    }
+}
+
 fn possible_fallthrough_comment_span(case: &SwitchCase) -> (u32, Option<u32>) {
     if let Ok(Statement::BlockStatement(block)) = case.consequent.iter().exactly_one() {
         let span = block.span;
@@ -0,0 +1,477 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, Expression, UnaryExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode};
+
+fn no_implicit_coercion_diagnostic(span: Span, recommendation: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected implicit coercion.")
+        .with_help(format!("Use `{recommendation}` instead."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoImplicitCoercion(Box<NoImplicitCoercionConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoImplicitCoercionConfig {
+    /// Check coercions to boolean, `!!foo` and `~foo.indexOf(bar)`.
+    boolean: bool,
+    /// Check coercions to number, `+foo`, `- -foo`, `1 * foo` and `foo - 0`.
+    number: bool,
+    /// Check coercions to string, `"" + foo` and `foo += ""`.
+    string: bool,
+    /// Operators that are allowed to coerce: `!!`, `~`, `+`, `- -`, `-` and `*`.
+    allow: Vec<String>,
+}
+
+impl Default for NoImplicitCoercionConfig {
+    fn default() -> Self {
+        Self { boolean: true, number: true, string: true, allow: vec![] }
+    }
+}
+
+impl std::ops::Deref for NoImplicitCoercion {
+    type Target = NoImplicitCoercionConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow shorthand type conversions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shorthand type conversions such as `!!foo` or `+foo` are terse, but harder to read and
+    /// search for than the explicit `Boolean(foo)` or `Number(foo)`.
+    ///
+    /// `~foo.indexOf(bar)` is only fixed to `foo.includes(bar)` where its value is only used as
+    /// a condition, e.g. `if (~foo.indexOf(bar))`.
+    ///
+    /// ### Options
+    ///
+    /// - `boolean` (default `true`): check `!!foo` and `~foo.indexOf(bar)`.
+    /// - `number` (default `true`): check `+foo`, `- -foo`, `1 * foo` and `foo - 0`.
+    /// - `string` (default `true`): check `"" + foo` and `foo += ""`.
+    /// - `allow` (default `[]`): operators that are allowed to coerce, any of `"!!"`, `"~"`,
+    ///   `"+"`, `"- -"`, `"-"` and `"*"`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var b = !!foo;
+    /// var n = +foo;
+    /// var s = "" + foo;
+    /// if (~foo.indexOf(".")) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var b = Boolean(foo);
+    /// var n = Number(foo);
+    /// var s = String(foo);
+    /// if (foo.includes(".")) {}
+    /// ```
+    NoImplicitCoercion,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoImplicitCoercion {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let flag = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true)
+        };
+        Self(Box::new(NoImplicitCoercionConfig {
+            boolean: flag("boolean"),
+            number: flag("number"),
+            string: flag("string"),
+            allow: config
+                .and_then(|config| config.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|allow| {
+                    allow
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::UnaryExpression(expr) => self.check_unary(expr, node, ctx),
+            AstKind::BinaryExpression(expr) => {
+                let (left, right) =
+                    (expr.left.without_parentheses(), expr.right.without_parentheses());
+                let operand = match expr.operator {
+                    // `1 * foo`, `foo * 1`
+                    BinaryOperator::Multiplication if self.number && !self.is_allowed("*") => {
+                        // `1 * foo * 2` is arithmetic rather than a coercion.
+                        if is_multiplied_by_number(node, ctx) {
+                            return;
+                        }
+                        if is_one(left) && !is_numeric(right) {
+                            right
+                        } else if is_one(right) && !is_numeric(left) {
+                            left
+                        } else {
+                            return;
+                        }
+                    }
+                    // `foo - 0`
+                    BinaryOperator::Subtraction
+                        if self.number
+                            && !self.is_allowed("-")
+                            && is_zero(right)
+                            && !is_numeric(left) =>
+                    {
+                        left
+                    }
+                    // `"" + foo`, `foo + ""`
+                    BinaryOperator::Addition if self.string && !self.is_allowed("+") => {
+                        if is_empty_string(left) && !is_string_type(right) {
+                            right
+                        } else if is_empty_string(right) && !is_string_type(left) {
+                            left
+                        } else {
+                            return;
+                        }
+                    }
+                    _ => return,
+                };
+                let function =
+                    if expr.operator == BinaryOperator::Addition { "String" } else { "Number" };
+                report_call(expr.span, function, operand, ctx);
+            }
+            // `foo += ""`
+            AstKind::AssignmentExpression(expr)
+                if expr.operator == AssignmentOperator::Addition
+                    && self.string
+                    && !self.is_allowed("+")
+                    && is_empty_string(expr.right.without_parentheses()) =>
+            {
+                let target = ctx.source_range(expr.left.span());
+                let recommendation = format!("{target} = String({target})");
+                ctx.diagnostic_with_fix(
+                    no_implicit_coercion_diagnostic(expr.span, &recommendation),
+                    |fixer| {
+                        // Member targets would be evaluated twice.
+                        if !matches!(expr.left, AssignmentTarget::AssignmentTargetIdentifier(_))
+                            || has_comments(expr.span, ctx)
+                        {
+                            return fixer.noop();
+                        }
+                        fixer.replace(expr.span, recommendation.clone())
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoImplicitCoercion {
+    fn is_allowed(&self, operator: &str) -> bool {
+        self.allow.iter().any(|allowed| allowed == operator)
+    }
+
+    fn check_unary<'a>(
+        &self,
+        expr: &UnaryExpression<'a>,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let argument = expr.argument.without_parentheses();
+        match expr.operator {
+            // `!!foo`
+            UnaryOperator::LogicalNot if self.boolean && !self.is_allowed("!!") => {
+                if let Expression::UnaryExpression(inner) = argument {
+                    if inner.operator == UnaryOperator::LogicalNot {
+                        report_call(
+                            expr.span,
+                            "Boolean",
+                            inner.argument.without_parentheses(),
+                            ctx,
+                        );
+                    }
+                }
+            }
+            // `~foo.indexOf(bar)`
+            UnaryOperator::BitwiseNot if self.boolean && !self.is_allowed("~") => {
+                let Expression::CallExpression(call) = argument else {
+                    return;
+                };
+                let Some(member) = call.callee.without_parentheses().as_member_expression() else {
+                    return;
+                };
+                if call.optional
+                    || member.optional()
+                    || !matches!(member.static_property_name(), Some("indexOf" | "lastIndexOf"))
+                {
+                    return;
+                }
+                let recommendation = format!("{} !== -1", ctx.source_range(argument.span()));
+                ctx.diagnostic_with_fix(
+                    no_implicit_coercion_diagnostic(expr.span, &recommendation),
+                    |fixer| {
+                        // `~foo.indexOf(bar)` is truthy exactly if `foo.includes(bar)` is true,
+                        // but evaluates to a number.
+                        if !is_condition(node, ctx) || has_comments(expr.span, ctx) {
+                            return fixer.noop();
+                        }
+                        let object = ctx.source_range(member.object().span());
+                        let arguments =
+                            ctx.source_range(Span::new(member.span().end, call.span.end));
+                        fixer.replace(expr.span, format!("{object}.includes{arguments}"))
+                    },
+                );
+            }
+            // `+foo`
+            UnaryOperator::UnaryPlus
+                if self.number && !self.is_allowed("+") && !is_numeric(argument) =>
+            {
+                report_call(expr.span, "Number", argument, ctx);
+            }
+            // `- -foo`
+            UnaryOperator::UnaryNegation if self.number && !self.is_allowed("- -") => {
+                if let Expression::UnaryExpression(inner) = argument {
+                    let inner_argument = inner.argument.without_parentheses();
+                    if inner.operator == UnaryOperator::UnaryNegation && !is_numeric(inner_argument)
+                    {
+                        report_call(expr.span, "Number", inner_argument, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Report the coercion at `span`, with a fix that replaces it by `function(operand)`.
+fn report_call(span: Span, function: &str, operand: &Expression, ctx: &LintContext) {
+    let source = ctx.source_range(operand.span());
+    let recommendation = if matches!(operand, Expression::SequenceExpression(_)) {
+        format!("{function}(({source}))")
+    } else {
+        format!("{function}({source})")
+    };
+    ctx.diagnostic_with_fix(no_implicit_coercion_diagnostic(span, &recommendation), |fixer| {
+        if has_comments(span, ctx) {
+            return fixer.noop();
+        }
+        fixer.replace(span, recommendation.clone())
+    });
+}
+
+fn has_comments(span: Span, ctx: &LintContext) -> bool {
+    ctx.semantic().trivias().has_comments_between(span)
+}
+
+/// Whether the value of `node` is only used as a condition, e.g. the test of an `if`
+/// statement or the argument of `!`.
+fn is_condition(node: &AstNode, ctx: &LintContext) -> bool {
+    let span = node.kind().span();
+    let Some(parent) = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+    else {
+        return false;
+    };
+    let is_test = |test: &Expression| test.without_parentheses().span() == span;
+    match parent.kind() {
+        AstKind::IfStatement(stmt) => is_test(&stmt.test),
+        AstKind::WhileStatement(stmt) => is_test(&stmt.test),
+        AstKind::DoWhileStatement(stmt) => is_test(&stmt.test),
+        AstKind::ForStatement(stmt) => stmt.test.as_ref().is_some_and(is_test),
+        AstKind::ConditionalExpression(expr) => is_test(&expr.test),
+        AstKind::UnaryExpression(expr) => expr.operator == UnaryOperator::LogicalNot,
+        _ => false,
+    }
+}
+
+/// Whether `expr` is known to be a number: a numeric literal, or a call to `Number`,
+/// `parseInt` or `parseFloat`.
+fn is_numeric(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumericLiteral(_) => true,
+        Expression::CallExpression(call) => {
+            matches!(&call.callee, Expression::Identifier(ident)
+                if matches!(ident.name.as_str(), "Number" | "parseInt" | "parseFloat"))
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is known to be a string: a string or template literal, or a call to `String`.
+fn is_string_type(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::CallExpression(call) => call.callee.is_specific_id("String"),
+        _ => false,
+    }
+}
+
+fn is_empty_string(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(lit) => lit.value.is_empty(),
+        Expression::TemplateLiteral(lit) => {
+            lit.expressions.is_empty() && lit.quasis.iter().all(|quasi| quasi.value.raw.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// Whether `node` is an operand of a multiplication with a number literal.
+fn is_multiplied_by_number<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    outermost_paren_parent(node, ctx).is_some_and(|parent| match parent.kind() {
+        AstKind::BinaryExpression(expr) => {
+            expr.operator == BinaryOperator::Multiplication
+                && [&expr.left, &expr.right].into_iter().any(|operand| {
+                    matches!(operand.without_parentheses(), Expression::NumericLiteral(lit)
+                        if lit.value.is_finite())
+                })
+        }
+        _ => false,
+    })
+}
+
+#[allow(clippy::float_cmp)]
+fn is_one(expr: &Expression) -> bool {
+    matches!(expr, Expression::NumericLiteral(lit) if lit.value == 1.0)
+}
+
+fn is_zero(expr: &Expression) -> bool {
+    matches!(expr, Expression::NumericLiteral(lit) if lit.value == 0.0)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("Boolean(foo)", None),
+        ("foo.indexOf(1) !== -1", None),
+        ("Number(foo)", None),
+        ("parseInt(foo)", None),
+        ("parseFloat(foo)", None),
+        ("String(foo)", None),
+        ("!foo", None),
+        ("~foo", None),
+        ("-foo", None),
+        ("+1234", None),
+        ("-1234", None),
+        ("- -1234", None),
+        ("+Number(lol)", None),
+        ("-parseFloat(lol)", None),
+        ("2 * foo", None),
+        ("1 * 1234", None),
+        ("123 - 0", None),
+        ("1 * Number(foo)", None),
+        ("1 * parseInt(foo)", None),
+        ("1 * parseFloat(foo)", None),
+        ("Number(foo) * 1", None),
+        ("Number(foo) - 0", None),
+        ("foo - 1", None),
+        ("1 * foo * 2", None),
+        ("foo * 2", None),
+        ("'' + 'foo'", None),
+        ("'' + `foo`", None),
+        ("'foo' + ''", None),
+        ("`foo` + ''", None),
+        ("foo + 'bar'", None),
+        ("'' + String(foo)", None),
+        ("String(foo) + ''", None),
+        ("foo += 'bar'", None),
+        ("foo.indexOf(1) !== -1", None),
+        ("~foo.bar(1)", None),
+        ("~foo?.indexOf(1)", None),
+        ("!!foo", Some(json!([{ "boolean": false }]))),
+        ("~foo.indexOf(1)", Some(json!([{ "boolean": false }]))),
+        ("+foo", Some(json!([{ "number": false }]))),
+        ("1 * foo", Some(json!([{ "number": false }]))),
+        ("'' + foo", Some(json!([{ "string": false }]))),
+        ("foo += ''", Some(json!([{ "string": false }]))),
+        ("!!foo", Some(json!([{ "allow": ["!!"] }]))),
+        ("~foo.indexOf(1)", Some(json!([{ "allow": ["~"] }]))),
+        ("+foo", Some(json!([{ "allow": ["+"] }]))),
+        ("- -foo", Some(json!([{ "allow": ["- -"] }]))),
+        ("foo - 0", Some(json!([{ "allow": ["-"] }]))),
+        ("1 * foo", Some(json!([{ "allow": ["*"] }]))),
+        ("'' + foo", Some(json!([{ "allow": ["+"] }]))),
+        ("foo += ''", Some(json!([{ "allow": ["+"] }]))),
+    ];
+
+    let fail = vec![
+        ("!!foo", None),
+        ("!!(foo + bar)", None),
+        ("!!(a, b)", None),
+        ("~foo.indexOf(1)", None),
+        ("~foo.bar.indexOf(2)", None),
+        ("if (~foo.indexOf(1)) {}", None),
+        ("while (!~foo.lastIndexOf(1)) {}", None),
+        ("x = ~foo.bar.indexOf(2) ? a : b", None),
+        ("+foo", None),
+        ("-(-foo)", None),
+        ("+foo.bar", None),
+        ("1 * foo", None),
+        ("foo * 1", None),
+        ("1 * foo.bar", None),
+        ("foo - 0", None),
+        ("- -foo", None),
+        ("'' + foo", None),
+        ("`` + foo", None),
+        ("foo + ''", None),
+        ("foo + ``", None),
+        ("'' + foo.bar", None),
+        ("foo.bar + ''", None),
+        ("foo += ''", None),
+        ("foo.bar += ''", None),
+        ("var a = !!/* comment */ foo;", None),
+    ];
+
+    let fix = vec![
+        ("!!foo", "Boolean(foo)", None),
+        ("!!(foo + bar)", "Boolean(foo + bar)", None),
+        ("!!(a, b)", "Boolean((a, b))", None),
+        ("~foo.indexOf(1)", "~foo.indexOf(1)", None),
+        ("if (~foo.indexOf(1)) {}", "if (foo.includes(1)) {}", None),
+        ("while (!~foo.lastIndexOf(1)) {}", "while (!foo.includes(1)) {}", None),
+        ("x = ~foo.bar.indexOf(2) ? a : b", "x = foo.bar.includes(2) ? a : b", None),
+        ("+foo", "Number(foo)", None),
+        ("-(-foo)", "Number(foo)", None),
+        ("+foo.bar", "Number(foo.bar)", None),
+        ("1 * foo", "Number(foo)", None),
+        ("foo * 1", "Number(foo)", None),
+        ("foo - 0", "Number(foo)", None),
+        ("- -foo", "Number(foo)", None),
+        ("'' + foo", "String(foo)", None),
+        ("foo + ``", "String(foo)", None),
+        ("foo.bar + ''", "String(foo.bar)", None),
+        ("foo += ''", "foo = String(foo)", None),
+        ("foo.bar += ''", "foo.bar += ''", None),
+        ("var a = !!/* comment */ foo;", "var a = !!/* comment */ foo;", None),
+    ];
+
+    Tester::new(NoImplicitCoercion::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,203 @@
+use oxc_ast::{
+    ast::{IfStatement, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_lonely_if_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `if` as the only statement in an `else` block.")
+        .with_help("Combine the `else` and the inner `if` into `else if`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLonelyIf;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `if` statements as the only statement in `else` blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An `else` block that contains nothing but an `if` statement adds a level of nesting
+    /// without need. It can be written as an `else if` instead.
+    ///
+    /// The fix keeps comments between `else` and the inner `if`. It is not applied when there
+    /// are comments after the inner `if`, or when removing the braces would change how
+    /// automatic semicolon insertion applies to the code that follows.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// if (condition) {
+    ///     // ...
+    /// } else {
+    ///     if (anotherCondition) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// if (condition) {
+    ///     // ...
+    /// } else if (anotherCondition) {
+    ///     // ...
+    /// }
+    ///
+    /// if (condition) {
+    ///     // ...
+    /// } else {
+    ///     if (anotherCondition) {
+    ///         // ...
+    ///     }
+    ///     doSomething();
+    /// }
+    /// ```
+    NoLonelyIf,
+    pedantic,
+    conditional_fix
+);
+
+impl Rule for NoLonelyIf {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else {
+            return;
+        };
+        let Some(Statement::BlockStatement(block)) = &if_stmt.alternate else {
+            return;
+        };
+        let [Statement::IfStatement(inner)] = block.body.as_slice() else {
+            return;
+        };
+
+        let diagnostic = no_lonely_if_diagnostic(inner.span);
+        // Comments after the inner `if` would end up after the `else if`, possibly swallowing
+        // the code that follows.
+        let trailing = ctx.source_range(Span::new(inner.span.end, block.span.end - 1));
+        if !trailing.trim().is_empty() || is_asi_hazard(inner, block.span, ctx) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            // Everything from the opening brace to the end of the inner `if`, which keeps
+            // comments in front of it.
+            let text = ctx.source_range(Span::new(block.span.start + 1, inner.span.end));
+            let text = text.trim_start();
+            let before_block = &ctx.source_text()[..block.span.start as usize];
+            let replacement =
+                if before_block.ends_with("else") { format!(" {text}") } else { text.to_string() };
+            fixer.replace(block.span, replacement)
+        });
+    }
+}
+
+/// Whether removing the braces around `inner` would join its last statement with the code
+/// after the `else` block, e.g. `else { if (a) foo() }\n(bar)`.
+fn is_asi_hazard(inner: &IfStatement, block_span: Span, ctx: &LintContext) -> bool {
+    // The statement the inner `if` ends with.
+    let last = inner.alternate.as_ref().unwrap_or(&inner.consequent);
+    if matches!(last, Statement::BlockStatement(_)) {
+        return false;
+    }
+    let last_text = ctx.source_range(last.span());
+    if last_text.ends_with(';') {
+        return false;
+    }
+    let after_block = &ctx.source_text()[block_span.end as usize..];
+    let next = after_block.trim_start();
+    if next.is_empty() {
+        return false;
+    }
+    let same_line = !after_block[..after_block.len() - next.len()].contains(['\n', '\r']);
+    same_line
+        || next.starts_with(['(', '[', '/', '+', '`', '-'])
+        || last_text.ends_with("++")
+        || last_text.ends_with("--")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (a) {;} else if (b) {;}",
+        "if (a) {;} else { if (b) {;} ; }",
+        "if (a) {;} else { if (b) {;} foo(); }",
+        "if (a) {;} else { foo(); if (b) {;} }",
+        "if (a) { if (b) {;} }",
+        "if (a) {;} else { while (b) {;} }",
+        "if (a) {;} else if (b) { if (c) {;} } else {;}",
+    ];
+
+    let fail = vec![
+        "if (a) {;} else { if (b) {;} }",
+        "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n}",
+        "if (a) {;} else {if (b) {;}}",
+        "if (a) {} else{if (b) {}}",
+        "if (a) {} else { if (b) {} else {} }",
+        "if (a) {} else { /* comment */ if (b) {} }",
+        "if (a) {} else {\n  // comment\n  if (b) {}\n}",
+        "if (a) {} else /* comment */ { if (b) {} }",
+        "if (a) {} else { if (b) {} /* comment */ }",
+        "if (a) {} else { if (b) {} // comment\n}",
+        "if (a) {} else { if (b) foo() }",
+        "if (a) {} else { if (b) foo(); }\nbar()",
+        "if (a) {} else { if (b) foo() }\nbar()",
+        "if (a) {} else { if (b) foo() } bar()",
+        "if (a) {} else { if (b) foo() }\n(bar)",
+        "if (a) {} else { if (b) foo() }\n[1, 2].forEach(baz)",
+        "if (a) {} else { if (b) foo++ }\nbar()",
+        "if (a) {} else { if (b) {} else foo() }\n(bar)",
+        "if (a) { if (b) {} else { if (c) {} } }",
+    ];
+
+    let fix = vec![
+        ("if (a) {;} else { if (b) {;} }", "if (a) {;} else if (b) {;}"),
+        (
+            "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n}",
+            "if (a) {\n  foo();\n} else if (b) {\n    bar();\n  }",
+        ),
+        ("if (a) {;} else {if (b) {;}}", "if (a) {;} else if (b) {;}"),
+        ("if (a) {} else{if (b) {}}", "if (a) {} else if (b) {}"),
+        ("if (a) {} else { if (b) {} else {} }", "if (a) {} else if (b) {} else {}"),
+        ("if (a) {} else { /* comment */ if (b) {} }", "if (a) {} else /* comment */ if (b) {}"),
+        (
+            "if (a) {} else {\n  // comment\n  if (b) {}\n}",
+            "if (a) {} else // comment\n  if (b) {}",
+        ),
+        ("if (a) {} else /* comment */ { if (b) {} }", "if (a) {} else /* comment */ if (b) {}"),
+        (
+            "if (a) {} else { if (b) {} /* comment */ }",
+            "if (a) {} else { if (b) {} /* comment */ }",
+        ),
+        ("if (a) {} else { if (b) {} // comment\n}", "if (a) {} else { if (b) {} // comment\n}"),
+        ("if (a) {} else { if (b) foo() }", "if (a) {} else if (b) foo()"),
+        ("if (a) {} else { if (b) foo(); }\nbar()", "if (a) {} else if (b) foo();\nbar()"),
+        ("if (a) {} else { if (b) foo() }\nbar()", "if (a) {} else if (b) foo()\nbar()"),
+        ("if (a) {} else { if (b) foo() } bar()", "if (a) {} else { if (b) foo() } bar()"),
+        ("if (a) {} else { if (b) foo() }\n(bar)", "if (a) {} else { if (b) foo() }\n(bar)"),
+        (
+            "if (a) {} else { if (b) foo() }\n[1, 2].forEach(baz)",
+            "if (a) {} else { if (b) foo() }\n[1, 2].forEach(baz)",
+        ),
+        ("if (a) {} else { if (b) foo++ }\nbar()", "if (a) {} else { if (b) foo++ }\nbar()"),
+        (
+            "if (a) {} else { if (b) {} else foo() }\n(bar)",
+            "if (a) {} else { if (b) {} else foo() }\n(bar)",
+        ),
+    ];
+
+    Tester::new(NoLonelyIf::NAME, pass, fail)
+        .with_snapshot_suffix("eslint")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
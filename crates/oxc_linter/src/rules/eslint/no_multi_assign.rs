@@ -0,0 +1,153 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_multi_assign_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected chained assignment.")
+        .with_help("Separate each assignment into its own statement.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMultiAssign {
+    ignore_non_declaration: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of chained assignment expressions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Chaining the assignment of variables can lead to unexpected results and be difficult to
+    /// read. For example, in `const a = b = c = 1;` only `a` is declared as a constant, while `b`
+    /// and `c` are assigned to existing, or implicitly global, variables.
+    ///
+    /// Compound assignments such as `a += b += c` are reported the same way.
+    ///
+    /// ### Options
+    ///
+    /// - `ignoreNonDeclaration` (default `false`): only report chains that initialize a
+    ///   declared variable, e.g. `let a = b = c`, and allow `a = b = c`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var a = b = c = 5;
+    /// const foo = bar = "baz";
+    /// a = b = "quux";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var a = 5;
+    /// var b = 5;
+    /// var c = 5;
+    /// ```
+    NoMultiAssign,
+    style
+);
+
+impl Rule for NoMultiAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_non_declaration = value
+            .get(0)
+            .and_then(|config| config.get("ignoreNonDeclaration"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { ignore_non_declaration }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                if decl
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| is_assignment_expression(init.without_parentheses()))
+                {
+                    ctx.diagnostic(no_multi_assign_diagnostic(decl.span));
+                }
+            }
+            AstKind::AssignmentExpression(expr) if !self.ignore_non_declaration => {
+                if is_assignment_expression(expr.right.without_parentheses()) {
+                    ctx.diagnostic(no_multi_assign_diagnostic(expr.span));
+                }
+            }
+            // `class C { field = foo = 0 }`
+            AstKind::PropertyDefinition(prop) if !self.ignore_non_declaration => {
+                if prop
+                    .value
+                    .as_ref()
+                    .is_some_and(|value| is_assignment_expression(value.without_parentheses()))
+                {
+                    ctx.diagnostic(no_multi_assign_diagnostic(prop.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_assignment_expression(expr: &Expression) -> bool {
+    matches!(expr, Expression::AssignmentExpression(_))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a, b, c,\nd = 0;", None),
+        ("var a = 1; var b = 2; var c = 3;\nvar d = 0;", None),
+        ("var a = 1 + (b === 10 ? 5 : 4);", None),
+        ("const a = 1, b = 2, c = 3;", None),
+        ("const a = 1;\nconst b = 2;\n const c = 3;", None),
+        ("for(var a = 0, b = 0;;){}", None),
+        ("for(let a = 0, b = 0;;){}", None),
+        ("for(const a = 0, b = 0;;){}", None),
+        ("export let a, b;", None),
+        ("export let a,\n b = 0;", None),
+        (
+            "const x = {};const y = {};x.one = y.one = 1;",
+            Some(json!([{ "ignoreNonDeclaration": true }])),
+        ),
+        ("let a, b;a = b = 1", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("class C { [foo = 0] = 0 }", None),
+        ("a = b === c", None),
+        ("a = (b, c = d)", None),
+        ("class C { field = foo = 0 }", Some(json!([{ "ignoreNonDeclaration": true }]))),
+    ];
+
+    let fail = vec![
+        ("var a = b = c;", None),
+        ("var a = b = c = d;", None),
+        ("let foo = bar = cee = 100;", None),
+        ("a=b=c=d=e", None),
+        ("a=b=c", None),
+        ("a\n=b\n=c", None),
+        ("var a = (b) = (((c)))", None),
+        ("var a = ((b)) = (c)", None),
+        ("var a = b = ( (c * 12) + 2)", None),
+        ("var a =\n((b))\n = (c)", None),
+        ("a = b = '=' + c + 'foo';", None),
+        ("a = b = 7 * 12 + 5;", None),
+        ("const x = {};\nconst y = x.one = 1;", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("let a, b;a = b = 1", Some(json!([{}]))),
+        ("let x, y;x = y = 'baz'", Some(json!([{ "ignoreNonDeclaration": false }]))),
+        ("const a = b = 1", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("class C { field = foo = 0 }", None),
+        ("class C { static field = foo = 0 }", None),
+        ("a += b += c", None),
+        ("a = (b = c)", None),
+    ];
+
+    Tester::new(NoMultiAssign::NAME, pass, fail).test_and_snapshot();
+}
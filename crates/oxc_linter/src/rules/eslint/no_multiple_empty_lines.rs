@@ -0,0 +1,230 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{source_lines, SourceLine},
+};
+
+fn consecutive_diagnostic(max: usize, span: Span) -> OxcDiagnostic {
+    let lines = if max == 1 { "line" } else { "lines" };
+    OxcDiagnostic::warn(format!("More than {max} blank {lines} not allowed.")).with_label(span)
+}
+
+fn beginning_diagnostic(max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Too many blank lines at the beginning of file. Max of {max} allowed."
+    ))
+    .with_label(span)
+}
+
+fn end_diagnostic(max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Too many blank lines at the end of file. Max of {max} allowed."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMultipleEmptyLines(Box<NoMultipleEmptyLinesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoMultipleEmptyLinesConfig {
+    max: usize,
+    max_eof: usize,
+    max_bof: usize,
+}
+
+impl std::ops::Deref for NoMultipleEmptyLines {
+    type Target = NoMultipleEmptyLinesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoMultipleEmptyLinesConfig {
+    fn default() -> Self {
+        Self { max: 2, max_eof: 2, max_bof: 2 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow multiple empty lines.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some developers prefer to have multiple blank lines removed, while others feel that it
+    /// helps improve readability. Whitespace is useful for separating logical sections of code,
+    /// but excess whitespace takes up more of the screen.
+    ///
+    /// ### Options
+    ///
+    /// - `max` (default `2`): maximum number of consecutive empty lines.
+    /// - `maxEOF` (defaults to `max`): maximum number of consecutive empty lines at the end of
+    ///   the file.
+    /// - `maxBOF` (defaults to `max`): maximum number of consecutive empty lines at the
+    ///   beginning of the file.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `{ "max": 2 }` option:
+    /// ```javascript
+    /// var foo = 5;
+    ///
+    ///
+    ///
+    /// var bar = 3;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `{ "max": 2 }` option:
+    /// ```javascript
+    /// var foo = 5;
+    ///
+    ///
+    /// var bar = 3;
+    /// ```
+    NoMultipleEmptyLines,
+    style,
+    fix
+);
+
+impl Rule for NoMultipleEmptyLines {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let get_usize = |key: &str| {
+            config
+                .and_then(|config| config.get(key))
+                .and_then(Value::as_u64)
+                .and_then(|v| usize::try_from(v).ok())
+        };
+        let max = get_usize("max").unwrap_or(2);
+        Self(Box::new(NoMultipleEmptyLinesConfig {
+            max,
+            max_eof: get_usize("maxEOF").unwrap_or(max),
+            max_bof: get_usize("maxBOF").unwrap_or(max),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if ctx.source_text().is_empty() {
+            return;
+        }
+
+        // Empty lines inside template literals are part of the string value.
+        let template_spans = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::TemplateLiteral(template) => Some(template.quasis.iter().map(|q| q.span)),
+                _ => None,
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        let is_empty = |line: &SourceLine| {
+            line.is_blank()
+                && !template_spans.iter().any(|template| template.contains_inclusive(line.span))
+        };
+
+        let mut lines = source_lines(ctx.source_text());
+        // A trailing line break ends the last line instead of starting a new one.
+        if lines.len() > 1 && lines.last().is_some_and(|line| line.text.is_empty()) {
+            lines.pop();
+        }
+
+        let mut run_start = 0;
+        for (i, line) in lines.iter().enumerate() {
+            if is_empty(line) {
+                continue;
+            }
+            self.check_run(&lines[run_start..i], run_start == 0, false, ctx);
+            run_start = i + 1;
+        }
+        self.check_run(&lines[run_start..], run_start == 0, true, ctx);
+    }
+}
+
+impl NoMultipleEmptyLines {
+    /// Report a run of consecutive empty lines if it is longer than allowed.
+    fn check_run(&self, run: &[SourceLine], at_start: bool, at_end: bool, ctx: &LintContext) {
+        // A file with only empty lines is checked against `max`.
+        let max = match (at_start, at_end) {
+            (true, false) => self.max_bof,
+            (false, true) => self.max_eof,
+            _ => self.max,
+        };
+        if run.len() <= max {
+            return;
+        }
+
+        let excess = &run[max..];
+        let span = Span::new(excess[0].span.start, excess[excess.len() - 1].terminator.end);
+        let diagnostic = match (at_start, at_end) {
+            (true, false) => beginning_diagnostic(max, span),
+            (false, true) => end_diagnostic(max, span),
+            _ => consecutive_diagnostic(max, span),
+        };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(span));
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// valid 1\nvar a = 5;\nvar b = 3;\n\n", Some(json!([{ "max": 1 }]))),
+        ("// valid 2\n\nvar a = 5;\n\nvar b = 3;", Some(json!([{ "max": 1 }]))),
+        ("// valid 3\nvar a = 5;\n\n\n\n\nvar b = 3;\n", Some(json!([{ "max": 4 }]))),
+        ("// valid 4\nvar a = 5;\n\nvar b = 3;\n", None),
+        ("// valid 5\nvar a = 5;\n\n\nvar b = 3;\n", None),
+        ("// valid 6\nvar a = 5;\n/* comment */\n\n\nvar b = 3;\n", Some(json!([{ "max": 2 }]))),
+        ("// valid 7\nvar a = `\n\n\n\nfoo`;", Some(json!([{ "max": 1 }]))),
+        ("var a = `foo\n\n\n${bar}\n\n\nbaz`;", Some(json!([{ "max": 0 }]))),
+        ("var a = 5;\n", Some(json!([{ "max": 0, "maxEOF": 0 }]))),
+        ("var a = 5;\n\n", Some(json!([{ "max": 0, "maxEOF": 1 }]))),
+        ("var a = 5;", Some(json!([{ "max": 0, "maxEOF": 0 }]))),
+        ("\nvar a = 5;", Some(json!([{ "max": 0, "maxBOF": 1 }]))),
+        ("var a = 5;", Some(json!([{ "max": 0, "maxBOF": 0 }]))),
+        ("var a = 5;\r\n\r\nvar b = 3;\r\n", Some(json!([{ "max": 1 }]))),
+        ("", Some(json!([{ "max": 0 }]))),
+    ];
+
+    let fail = vec![
+        ("// invalid 1\nvar a = 5;\n\n\nvar b = 3;", Some(json!([{ "max": 1 }]))),
+        ("// invalid 2\n\n\n\n\nvar a = 5;", Some(json!([{ "max": 2 }]))),
+        ("var a = 5;\n\n\n\n", Some(json!([{ "max": 2 }]))),
+        ("var a = 5;\n\n\n\n", Some(json!([{ "max": 0, "maxEOF": 2 }]))),
+        ("var a = 5;\n\n", Some(json!([{ "max": 1, "maxEOF": 0 }]))),
+        ("\n\nvar a = 5;", Some(json!([{ "max": 2, "maxBOF": 1 }]))),
+        ("\nvar a = 5;", Some(json!([{ "max": 2, "maxBOF": 0 }]))),
+        ("var a = 5;\n  \n\t\nvar b = 3;", Some(json!([{ "max": 1 }]))),
+        ("var a = 5;\r\n\r\n\r\nvar b = 3;", Some(json!([{ "max": 1 }]))),
+        ("var a = `foo`;\n\n\n\nvar b = `bar`;", None),
+    ];
+
+    let fix = vec![
+        ("var a = 5;\n\n\nvar b = 3;", "var a = 5;\n\nvar b = 3;", Some(json!([{ "max": 1 }]))),
+        ("var a = 5;\n\n\n\n\nvar b = 3;\n", "var a = 5;\n\n\nvar b = 3;\n", None),
+        ("var a = 5;\n\n\n", "var a = 5;\n", Some(json!([{ "max": 2, "maxEOF": 0 }]))),
+        ("\n\n\nvar a = 5;", "var a = 5;", Some(json!([{ "max": 2, "maxBOF": 0 }]))),
+        (
+            "\n\n\nvar a = 5;\n\n\n\nvar b = 3;\n\n\n",
+            "\nvar a = 5;\n\nvar b = 3;\n\n",
+            Some(json!([{ "max": 1, "maxBOF": 1, "maxEOF": 1 }])),
+        ),
+        (
+            "var a = 5;\r\n\r\n\r\nvar b = 3;",
+            "var a = 5;\r\n\r\nvar b = 3;",
+            Some(json!([{ "max": 1 }])),
+        ),
+    ];
+
+    Tester::new(NoMultipleEmptyLines::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,93 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_nested_ternary_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not nest ternary expressions.")
+        .with_help("Use an `if` statement or extract the nested ternary into a variable.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNestedTernary;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow nested ternary expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Nesting ternary expressions makes it hard to see which condition leads to which value.
+    ///
+    /// A ternary expression is reported if its consequent or alternate is a ternary expression
+    /// itself, even when wrapped in parentheses. Ternary expressions in the condition are
+    /// allowed.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const thing = foo ? bar : baz === qux ? quxx : foobar;
+    ///
+    /// foo ? (baz === qux ? quxx() : foobar()) : bar();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const thing = foo ? bar : foobar;
+    ///
+    /// let thing;
+    /// if (foo) {
+    ///     thing = bar;
+    /// } else if (baz === qux) {
+    ///     thing = quxx;
+    /// } else {
+    ///     thing = foobar;
+    /// }
+    /// ```
+    NoNestedTernary,
+    style
+);
+
+impl Rule for NoNestedTernary {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ConditionalExpression(expr) = node.kind() else {
+            return;
+        };
+        let is_ternary = |expr: &Expression| {
+            matches!(expr.without_parentheses(), Expression::ConditionalExpression(_))
+        };
+        if is_ternary(&expr.consequent) || is_ternary(&expr.alternate) {
+            ctx.diagnostic(no_nested_ternary_diagnostic(expr.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "foo ? doBar() : doBaz();",
+        "var foo = bar === baz ? qux : quxx;",
+        "var foo = (bar ? baz : qux) ? a : b;",
+        "foo ? () => (a ? b : c) : d;",
+        "foo ? { a: b ? c : d } : e;",
+    ];
+
+    let fail = vec![
+        "foo ? bar : baz === qux ? quxx : foobar;",
+        "foo ? baz === qux ? quxx : foobar : bar;",
+        "foo ? (bar ? baz : qux) : quxx;",
+        "foo ? bar : (baz ? qux : quxx);",
+        "a ? b : c ? d : e ? f : g;",
+    ];
+
+    Tester::new(NoNestedTernary::NAME, pass, fail)
+        .with_snapshot_suffix("eslint")
+        .test_and_snapshot();
+}
@@ -7,16 +7,8 @@ use regex::{Captures, Regex};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-fn replacement(escape_sequence: &str, replacement: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("Don't use '{escape_sequence}' escape sequence."))
-        .with_help(format!("Replace '{escape_sequence}' with '{replacement}'. This maintains the current functionality."))
-        .with_label(span)
-}
-
-fn escape_backslash(escape_sequence: &str, replacement: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("Don't use '{escape_sequence}' escape sequence."))
-        .with_help(format!("Replace '{escape_sequence}' with '{replacement}' to include the actual backslash character."))
-        .with_label(span)
+fn no_nonoctal_decimal_escape_diagnostic(escape_sequence: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Don't use '{escape_sequence}' escape sequence.")).with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -29,6 +21,9 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// ECMAScript specification treats \8 and \9 in string literals as a legacy feature
     ///
+    /// This rule offers suggestions to either drop the backslash, which keeps
+    /// the current behavior, or to escape it, which is likely what was meant.
+    ///
     /// ### Example
     /// ```javascript
     /// incorrect:
@@ -40,13 +35,13 @@ declare_oxc_lint!(
     /// ```
     NoNonoctalDecimalEscape,
     correctness,
-    pending
+    suggestion
 );
 
 impl Rule for NoNonoctalDecimalEscape {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
-            check_string(ctx, literal.span.source_text(ctx.source_text()));
+            check_string(ctx, literal.span);
         }
     }
 }
@@ -83,12 +78,14 @@ fn quick_test(s: &str) -> bool {
 }
 
 #[allow(clippy::cast_possible_truncation)]
-fn check_string(ctx: &LintContext<'_>, string: &str) {
+fn check_string(ctx: &LintContext<'_>, span: Span) {
     lazy_static! {
         static ref NONOCTAL_REGEX: Regex =
             Regex::new(r"(?:[^\\]|(?P<previousEscape>\\.))*?(?P<decimalEscape>\\[89])").unwrap();
     }
 
+    let string = span.source_text(ctx.source_text());
+
     // Need at least 2 characters
     if string.len() <= 1 {
         return;
@@ -98,36 +95,62 @@ fn check_string(ctx: &LintContext<'_>, string: &str) {
         return;
     }
 
+    // Captures are relative to the start of the literal.
+    let to_span =
+        |start: usize, end: usize| Span::new(span.start + start as u32, span.start + end as u32);
+
     let mut start: usize = 0;
     while let (Some(captures), new_start) = NONOCTAL_REGEX.sticky_captures(string, start) {
         let previous_escape = captures.name("previousEscape");
         let decimal_escape = captures.name("decimalEscape").unwrap();
-        let decimal_escape_span =
-            Span::new(decimal_escape.start() as u32, decimal_escape.end() as u32);
+        let decimal_escape_span = to_span(decimal_escape.start(), decimal_escape.end());
         let decimal_escape_str = decimal_escape.as_str();
+        let digit = &decimal_escape_str[1..];
 
-        if let Some(prev_match) = previous_escape {
-            if prev_match.as_str().eq("\\0") {
-                ctx.diagnostic(replacement(
-                    &(prev_match.as_str().to_string() + decimal_escape_str),
-                    "\\u00008",
-                    Span::new(prev_match.start() as u32, decimal_escape_span.end),
-                ));
-                ctx.diagnostic(replacement(decimal_escape_str, "\\u0038", decimal_escape_span));
-            }
-        } else {
-            ctx.diagnostic(replacement(
-                decimal_escape_str,
-                &decimal_escape_str[1..],
-                decimal_escape_span,
-            ));
-        }
-
-        ctx.diagnostic(escape_backslash(
-            decimal_escape_str,
-            &format!("\\{decimal_escape_str}"),
-            decimal_escape_span,
-        ));
+        ctx.diagnostic_with_suggestions(
+            no_nonoctal_decimal_escape_diagnostic(decimal_escape_str, decimal_escape_span),
+            |fixer| {
+                let mut suggestions = Vec::with_capacity(3);
+                match previous_escape {
+                    // `\0\8` would turn into the octal escape `\08` if the backslash was dropped.
+                    Some(prev_match) if prev_match.as_str() == "\\0" => {
+                        let escapes = format!("{}{decimal_escape_str}", prev_match.as_str());
+                        suggestions.push(
+                            fixer
+                                .replace(
+                                    to_span(prev_match.start(), decimal_escape.end()),
+                                    format!("\\u0000{digit}"),
+                                )
+                                .with_message(format!(
+                                    "Replace '{escapes}' with '\\u0000{digit}'. This maintains the current functionality."
+                                )),
+                        );
+                        suggestions.push(
+                            fixer.replace(decimal_escape_span, format!("\\u003{digit}")).with_message(
+                                format!(
+                                    "Replace '{decimal_escape_str}' with '\\u003{digit}'. This maintains the current functionality."
+                                ),
+                            ),
+                        );
+                    }
+                    _ => {
+                        suggestions.push(fixer.replace(decimal_escape_span, digit).with_message(
+                            format!(
+                                "Replace '{decimal_escape_str}' with '{digit}'. This maintains the current functionality."
+                            ),
+                        ));
+                    }
+                }
+                suggestions.push(
+                    fixer
+                        .replace(decimal_escape_span, format!("\\{decimal_escape_str}"))
+                        .with_message(format!(
+                            "Replace '{decimal_escape_str}' with '\\{decimal_escape_str}' to include the actual backslash character."
+                        )),
+                );
+                suggestions
+            },
+        );
 
         start = new_start;
     }
@@ -227,5 +250,24 @@ fn test() {
         r"'\0\\n\8'",
     ];
 
-    Tester::new(NoNonoctalDecimalEscape::NAME, pass, fail).test_and_snapshot();
+    let suggestions = vec![
+        (r"'\8'", vec![r"'8'", r"'\\8'"]),
+        (r"'foo\9bar'", vec![r"'foo9bar'", r"'foo\\9bar'"]),
+        (r"'\1\9'", vec![r"'\19'", r"'\1\\9'"]),
+        (r"'\0\8'", vec![r"'\u00008'", r"'\0\u0038'", r"'\0\\8'"]),
+        (r"'\0\9'", vec![r"'\u00009'", r"'\0\u0039'", r"'\0\\9'"]),
+        (
+            r"var foo = '\8'; bar('\9')",
+            vec![
+                r"var foo = '8'; bar('\9')",
+                r"var foo = '\\8'; bar('\9')",
+                r"var foo = '\8'; bar('9')",
+                r"var foo = '\8'; bar('\\9')",
+            ],
+        ),
+    ];
+
+    Tester::new(NoNonoctalDecimalEscape::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
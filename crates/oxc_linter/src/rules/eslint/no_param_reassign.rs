@@ -0,0 +1,319 @@
+use oxc_ast::{
+    ast::{Expression, ForStatementLeft, SimpleAssignmentTarget, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    ast_util::{
+        assignment_target_symbols, assignment_targets, get_symbol_id_of_variable,
+        get_write_references, outermost_paren_parent,
+    },
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn assignment_to_param_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Assignment to function parameter '{name}'."))
+        .with_help("Assign to a new local variable instead.")
+        .with_label(span)
+}
+
+fn assignment_to_param_property_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Assignment to property of function parameter '{name}'."))
+        .with_help("Copy the parameter before modifying it.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoParamReassign(Box<NoParamReassignConfig>);
+
+/// The options object of `no-param-reassign`.
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoParamReassignConfig {
+    /// Also report modifications of the properties of parameters.
+    props: bool,
+    /// Names of parameters whose properties may be modified when `props` is `true`.
+    ignore_property_modifications_for: Vec<String>,
+}
+
+impl std::ops::Deref for NoParamReassign {
+    type Target = NoParamReassignConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow reassigning function parameters.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assigning to a parameter hides the value the function was called with, which makes the
+    /// function harder to follow. In sloppy mode code, it also changes the `arguments` object.
+    ///
+    /// Assignments, compound assignments such as `a += 1`, updates such as `a++` and
+    /// destructuring assignments to a parameter are reported.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "eslint/no-param-reassign": [
+    ///     "error",
+    ///     { "props": true, "ignorePropertyModificationsFor": ["acc"] }
+    /// ]
+    /// ```
+    ///
+    /// - `props` (default `false`): also report modifications of the properties of parameters,
+    ///   e.g. `a.b = 1`, `a.b++`, `delete a.b` or `for (a.b in obj)`.
+    /// - `ignorePropertyModificationsFor` (default `[]`): names of parameters whose properties
+    ///   may be modified when `props` is `true`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function foo(bar) {
+    ///     bar = 13;
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     bar++;
+    /// }
+    ///
+    /// function foo({ bar }) {
+    ///     [bar] = [1];
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function foo(bar) {
+    ///     const baz = bar;
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     bar.prop = "value";
+    /// }
+    /// ```
+    NoParamReassign,
+    restriction
+);
+
+impl Rule for NoParamReassign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|config| serde_json::from_value::<NoParamReassignConfig>(config.clone()).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn schema(gen: &mut SchemaGenerator) -> Option<Schema> {
+        Some(gen.subschema_for::<NoParamReassignConfig>())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // The targets written by `node`, and the variables they resolve to.
+        let (target_spans, mut symbols) = match node.kind() {
+            AstKind::AssignmentExpression(expr) => {
+                // `a = b = 1` is checked as a whole from its outermost assignment.
+                if matches!(
+                    outermost_paren_parent(node, ctx).map(AstNode::kind),
+                    Some(AstKind::AssignmentExpression(parent))
+                        if parent.right.without_parentheses().span() == expr.span
+                ) {
+                    return;
+                }
+                let mut target_spans = vec![expr.left.span()];
+                let mut chained = expr;
+                while let Expression::AssignmentExpression(right) =
+                    chained.right.without_parentheses()
+                {
+                    target_spans.push(right.left.span());
+                    chained = right;
+                }
+                (target_spans, assignment_targets(expr, ctx))
+            }
+            AstKind::UpdateExpression(expr) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.argument
+                else {
+                    return;
+                };
+                (vec![ident.span], get_symbol_id_of_variable(ident, ctx).into_iter().collect())
+            }
+            AstKind::ForInStatement(stmt) => for_statement_left_targets(&stmt.left, ctx),
+            AstKind::ForOfStatement(stmt) => for_statement_left_targets(&stmt.left, ctx),
+            _ => return,
+        };
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        for symbol_id in symbols {
+            if !is_parameter(symbol_id, ctx) {
+                continue;
+            }
+            let name = ctx.symbols().get_name(symbol_id);
+            for reference in get_write_references(symbol_id, ctx) {
+                let span = ctx.semantic().reference_span(reference);
+                if target_spans.iter().any(|target| target.contains_inclusive(span)) {
+                    ctx.diagnostic(assignment_to_param_diagnostic(name, span));
+                }
+            }
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        if !is_parameter(symbol_id, ctx) {
+            return;
+        }
+        let name = ctx.symbols().get_name(symbol_id);
+        if !self.props || self.ignore_property_modifications_for.iter().any(|it| it == name) {
+            return;
+        }
+        for reference in ctx.semantic().symbol_references(symbol_id) {
+            if let Some(span) = modified_property_span(reference, ctx) {
+                ctx.diagnostic(assignment_to_param_property_diagnostic(name, span));
+            }
+        }
+    }
+}
+
+fn is_parameter(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    // Rest parameters are declared by their `BindingRestElement`.
+    matches!(
+        ctx.nodes().kind(declaration),
+        AstKind::FormalParameter(_) | AstKind::BindingRestElement(_)
+    )
+}
+
+fn for_statement_left_targets(
+    left: &ForStatementLeft,
+    ctx: &LintContext,
+) -> (Vec<Span>, Vec<SymbolId>) {
+    match left.as_assignment_target() {
+        Some(target) => (vec![target.span()], assignment_target_symbols(target, ctx)),
+        None => (vec![], vec![]),
+    }
+}
+
+/// The span of the member expression modified through `reference`, e.g. `a.b.c` in
+/// `a.b.c = 1` or `delete a.b.c`, if any.
+fn modified_property_span(reference: &Reference, ctx: &LintContext) -> Option<Span> {
+    if reference.is_write() {
+        return None;
+    }
+    let mut span = ctx.semantic().reference_span(reference);
+    let mut is_member = false;
+    for parent in ctx.nodes().iter_parents(reference.node_id()).skip(1) {
+        match parent.kind() {
+            AstKind::MemberExpression(member) if member.object().span() == span => {
+                is_member = true;
+            }
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_) => {}
+            // Assignments, updates, destructuring and `for-in` / `for-of` loop heads.
+            AstKind::SimpleAssignmentTarget(_) => return is_member.then_some(span),
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete => {
+                return is_member.then_some(span);
+            }
+            _ => return None,
+        }
+        span = parent.kind().span();
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let props = || Some(json!([{ "props": true }]));
+
+    let pass = vec![
+        ("function foo(a) { var b = a; }", None),
+        ("function foo(a) { for (b in a); }", None),
+        ("function foo(a) { for (b of a); }", None),
+        ("function foo(a) { a.prop = 'value'; }", None),
+        ("function foo(a) { for (a.prop in obj); }", None),
+        ("function foo(a) { for (a.prop of arr); }", None),
+        ("function foo(a) { (function() { var a = 12; a++; })(); }", None),
+        ("function foo() { someGlobal = 13; }", None),
+        ("function foo() { (function() { var a = 12; a++; })(); }", None),
+        ("function foo(a) { delete a.b; }", None),
+        ("function foo(a) { ++a.b; }", None),
+        ("function foo(a) { [a.b] = []; }", None),
+        ("function foo(a) { bar(a.b).c = 0; }", props()),
+        ("function foo(a) { data[a.b] = 0; }", props()),
+        ("function foo(a) { +a.b; }", props()),
+        ("function foo(a) { (a ? [] : [])[0] = 1; }", props()),
+        ("function foo(a) { a.b(); }", props()),
+        ("function foo(a) { a.b = 0; }", Some(json!([{ "props": false }]))),
+        (
+            "function foo(a) { a.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a, b) { a.b = 0; x.y = 1; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a", "x"] }])),
+        ),
+        (
+            "function foo(a) { a.b.c = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        ("const foo = ([a]) => { b = a; };", None),
+        ("class Foo { bar(a) { this.a = a; } }", None),
+    ];
+
+    let fail = vec![
+        ("function foo(bar) { bar = 13; }", None),
+        ("function foo(bar) { bar += 13; }", None),
+        ("function foo(bar) { (function() { bar = 13; })(); }", None),
+        ("function foo(bar) { ++bar; }", None),
+        ("function foo(bar) { bar++; }", None),
+        ("function foo(bar) { --bar; }", None),
+        ("function foo(bar) { bar--; }", None),
+        ("function foo({bar}) { bar = 13; }", None),
+        ("function foo([, {bar}]) { bar = 13; }", None),
+        ("function foo(bar) { ({bar} = {}); }", None),
+        ("function foo(bar) { ({x: [, bar = 0]} = {}); }", None),
+        ("function foo(bar) { for (bar in baz); }", None),
+        ("function foo(bar) { for (bar of baz); }", None),
+        ("const foo = (bar) => { bar = 13; };", None),
+        ("class Foo { set bar(value) { value = 1; } }", None),
+        ("function foo(bar = 0, ...rest) { bar = 1; rest = []; }", None),
+        ("function foo(bar) { bar.a = 0; }", props()),
+        ("function foo(bar) { delete bar.a; }", props()),
+        ("function foo(bar) { ++bar.a; }", props()),
+        ("function foo(bar) { bar.a.b++; }", props()),
+        ("function foo(bar) { bar[0] = 1; }", props()),
+        ("function foo(bar) { (bar).a = 0; }", props()),
+        ("function foo(bar) { for (bar.a in {}); }", props()),
+        ("function foo(bar) { for (bar.a of []); }", props()),
+        ("function foo(bar) { [bar.a] = []; }", props()),
+        ("function foo(bar) { ({foo: bar.a} = {}); }", props()),
+        ("function foo(bar) { [bar.a = 0] = []; }", props()),
+        (
+            "function foo(a, bar) { a.b = 0; bar.c = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        ("function foo(bar) { bar = 0; bar.a = 1; }", props()),
+    ];
+
+    Tester::new(NoParamReassign::NAME, pass, fail).test_and_snapshot();
+}
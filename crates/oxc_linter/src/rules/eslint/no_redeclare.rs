@@ -10,10 +10,9 @@ use oxc_syntax::symbol::SymbolId;
 use crate::{context::LintContext, rule::Rule};
 
 fn no_redeclare_diagnostic(id_name: &str, decl_span: Span, re_decl_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(format!("'{id_name}' is already defined.")).with_labels([
-        decl_span.label(format!("'{id_name}' is already defined.")),
-        re_decl_span.label("It can not be redeclare here."),
-    ])
+    OxcDiagnostic::warn(format!("'{id_name}' is already defined."))
+        .with_label(re_decl_span.label("It can not be redeclare here."))
+        .add_related_label(decl_span, format!("'{id_name}' is already defined."))
 }
 
 fn no_redeclare_as_builtin_in_diagnostic(builtin_name: &str, span: Span) -> OxcDiagnostic {
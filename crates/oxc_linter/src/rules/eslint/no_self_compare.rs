@@ -1,15 +1,17 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::cmp::ContentEq;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{cmp::ContentEq, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-fn no_self_compare_diagnostic(span: Span, span1: Span) -> OxcDiagnostic {
+fn no_self_compare_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow comparisons where both sides are exactly the same")
         .with_help("If you are testing for NaN, you can use Number.isNaN function.")
-        .with_labels([span, span1])
+        .with_label(span)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -25,6 +27,10 @@ declare_oxc_lint!(
     /// Comparing a variable against itself is usually an error, either a typo or refactoring error.
     /// It is confusing to the reader and may potentially introduce a runtime error.
     ///
+    /// Operands are compared structurally, so member accesses such as `a.b === a.b` and
+    /// `a[0] === a[0]` are reported. Operands containing calls, such as `a.b() === a.b()`,
+    /// may evaluate to different values and are not reported.
+    ///
     /// ### Example
     /// ```javascript
     /// var x = 10;
@@ -46,15 +52,55 @@ impl Rule for NoSelfCompare {
             return;
         }
 
-        if binary_expr.left.content_eq(&binary_expr.right) {
-            ctx.diagnostic(no_self_compare_diagnostic(
-                binary_expr.left.span(),
-                binary_expr.right.span(),
-            ));
+        if is_same_operand(&binary_expr.left, &binary_expr.right) {
+            ctx.diagnostic(no_self_compare_diagnostic(binary_expr.span));
+        }
+    }
+}
+
+/// Whether `left` and `right` are the same identifier, literal, or member access path.
+fn is_same_operand(left: &Expression, right: &Expression) -> bool {
+    match (left.get_inner_expression(), right.get_inner_expression()) {
+        (Expression::Identifier(left), Expression::Identifier(right)) => left.name == right.name,
+        (Expression::ThisExpression(_), Expression::ThisExpression(_))
+        | (Expression::Super(_), Expression::Super(_)) => true,
+        (Expression::ChainExpression(left), Expression::ChainExpression(right)) => {
+            match (left.expression.as_member_expression(), right.expression.as_member_expression())
+            {
+                (Some(left), Some(right)) => is_same_member_expression(left, right),
+                _ => false,
+            }
         }
+        (left, right) if left.is_literal() && right.is_literal() => left.content_eq(right),
+        (Expression::TemplateLiteral(left), Expression::TemplateLiteral(right)) => {
+            left.expressions.is_empty() && left.content_eq(right)
+        }
+        (left, right) => match (left.as_member_expression(), right.as_member_expression()) {
+            (Some(left), Some(right)) => is_same_member_expression(left, right),
+            _ => false,
+        },
     }
 }
 
+fn is_same_member_expression(left: &MemberExpression, right: &MemberExpression) -> bool {
+    let same_property = match (left, right) {
+        (
+            MemberExpression::StaticMemberExpression(left),
+            MemberExpression::StaticMemberExpression(right),
+        ) => left.property.name == right.property.name,
+        (
+            MemberExpression::ComputedMemberExpression(left),
+            MemberExpression::ComputedMemberExpression(right),
+        ) => is_same_operand(&left.expression, &right.expression),
+        (
+            MemberExpression::PrivateFieldExpression(left),
+            MemberExpression::PrivateFieldExpression(right),
+        ) => left.field.name == right.field.name,
+        _ => false,
+    };
+    same_property && is_same_operand(left.object(), right.object())
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -66,6 +112,16 @@ fn test() {
         ("foo.bar.baz === foo.bar.qux", None),
         ("class C { #field; foo() { this.#field === this['#field']; } }", None),
         ("class C { #field; foo() { this['#field'] === this.#field; } }", None),
+        ("class C { #a; #b; foo() { this.#a === this.#b; } }", None),
+        ("foo.bar().baz.qux >= foo.bar ().baz .qux", None),
+        ("a.b() === a.b()", None),
+        ("a() === a()", None),
+        ("a?.() === a?.()", None),
+        ("a[b] === a[c]", None),
+        ("a.b === b.b", None),
+        ("a[0] === a[1]", None),
+        ("`${a}` === `${a}`", None),
+        ("new A() === new A()", None),
     ];
 
     let fail = vec![
@@ -82,8 +138,17 @@ fn test() {
         ("x < x", None),
         ("x >= x", None),
         ("x <= x", None),
-        ("foo.bar().baz.qux >= foo.bar ().baz .qux", None),
         ("class C { #field; foo() { this.#field === this.#field; } }", None),
+        ("a.b === a.b", None),
+        ("a.b.c !== a.b.c", None),
+        ("a[b] === a[b]", None),
+        ("a[0] === a[0]", None),
+        ("a['b'].c === a['b'].c", None),
+        ("(a.b) === a.b", None),
+        ("a?.b === a?.b", None),
+        ("this.a === this.a", None),
+        ("`a` === `a`", None),
+        ("null === null", None),
     ];
 
     Tester::new(NoSelfCompare::NAME, pass, fail).test_and_snapshot();
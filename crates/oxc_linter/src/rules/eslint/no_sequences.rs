@@ -0,0 +1,209 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_sequences_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of comma operator.")
+        .with_help("Split the expression into separate statements.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoSequences {
+    allow_in_parentheses: bool,
+}
+
+impl Default for NoSequences {
+    fn default() -> Self {
+        Self { allow_in_parentheses: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow comma operators.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The comma operator evaluates each of its operands and returns the value of the last one.
+    /// It is rarely intentional outside the head of a `for` loop, and can hide side effects or
+    /// a mistyped `;`.
+    ///
+    /// Sequence expressions in the initialization and update parts of a `for` loop are always
+    /// allowed.
+    ///
+    /// ### Options
+    ///
+    /// - `allowInParentheses` (default `true`): allow sequence expressions wrapped in
+    ///   parentheses. Where the grammar already requires parentheses, e.g. the test of an `if`
+    ///   statement or the body of an arrow function, a second pair is needed.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// foo = doSomething(), val;
+    ///
+    /// if (doSomething(), !!test);
+    ///
+    /// const foo = () => (bar(), baz);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// foo = (doSomething(), val);
+    ///
+    /// if ((doSomething(), !!test));
+    ///
+    /// const foo = () => ((bar(), baz));
+    ///
+    /// for (i = 0, j = 10; i < j; i++, j--);
+    /// ```
+    NoSequences,
+    restriction
+);
+
+impl Rule for NoSequences {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_in_parentheses = value
+            .get(0)
+            .and_then(|config| config.get("allowInParentheses"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+        Self { allow_in_parentheses }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SequenceExpression(expr) = node.kind() else {
+            return;
+        };
+        // Sequences are always allowed in the initialization and update of a `for` loop.
+        let parent = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+        match parent.map(AstNode::kind) {
+            Some(AstKind::ForStatementInit(_)) => return,
+            Some(AstKind::ForStatement(for_stmt))
+                if for_stmt
+                    .update
+                    .as_ref()
+                    .is_some_and(|update| update.without_parentheses().span() == expr.span) =>
+            {
+                return;
+            }
+            _ => {}
+        }
+        if self.allow_in_parentheses && is_parenthesized(node, ctx) {
+            return;
+        }
+        let Some(first) = expr.expressions.first() else {
+            return;
+        };
+        ctx.diagnostic(no_sequences_diagnostic(comma_after(first.span().end, ctx)));
+    }
+}
+
+/// Whether the sequence expression is wrapped in parentheses other than those required by the
+/// grammar, e.g. `if ((a, b))` or `() => ((a, b))`.
+fn is_parenthesized(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+    if !matches!(parents.next().map(AstNode::kind), Some(AstKind::ParenthesizedExpression(_))) {
+        return false;
+    }
+    // The parentheses around an arrow function body are part of its syntax.
+    !parents.next().is_some_and(|parent| {
+        matches!(parent.kind(), AstKind::ExpressionStatement(_))
+            && ctx.nodes().iter_parents(parent.id()).nth(2).is_some_and(|node| {
+                matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+            })
+    })
+}
+
+/// The span of the first comma after `start`, ignoring commas in comments.
+#[allow(clippy::cast_possible_truncation)]
+fn comma_after(start: u32, ctx: &LintContext) -> Span {
+    let text = &ctx.source_text()[start as usize..];
+    let mut offset = 0;
+    while offset < text.len() {
+        let rest = &text[offset..];
+        if rest.starts_with(',') {
+            let start = start + offset as u32;
+            return Span::new(start, start + 1);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            offset += comment.find("*/").map_or(rest.len(), |end| end + 4);
+        } else if let Some(comment) = rest.strip_prefix("//") {
+            offset += comment.find('\n').map_or(rest.len(), |end| end + 2);
+        } else {
+            offset += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    Span::new(start, start)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let disallow = || Some(json!([{ "allowInParentheses": false }]));
+
+    let pass = vec![
+        ("var arr = [1, 2];", None),
+        ("var obj = {a: 1, b: 2};", None),
+        ("var a = 1, b = 2;", None),
+        ("var foo = (1, 2);", None),
+        ("(0,eval)(\"foo()\");", None),
+        ("for (i = 1, j = 2;; i++, j++);", None),
+        ("foo(a, (b, c), d);", None),
+        ("do {} while ((doSomething(), !!test));", None),
+        ("for ((doSomething(), somethingElse()); (doSomething(), !!test); );", None),
+        ("if ((doSomething(), !!test));", None),
+        ("switch ((doSomething(), val)) {}", None),
+        ("while ((doSomething(), !!test));", None),
+        ("with ((doSomething(), val)) {}", None),
+        ("a => ((doSomething(), a))", None),
+        ("for ((i = 0, j = 0); test; );", None),
+        ("for (; test; (i++, j++));", None),
+        ("for (i = 1, j = 2;; i++, j++);", disallow()),
+        ("for (; test; i++, j++);", disallow()),
+        ("for ((i = 0, j = 0); test; );", disallow()),
+        ("for (; test; (i++, j++));", disallow()),
+        ("const foo = () => { return ((bar = 123), 10) }", None),
+        ("const foo = () => (((bar = 123), 10));", None),
+    ];
+
+    let fail = vec![
+        ("1, 2;", None),
+        ("a = 1, 2", None),
+        ("do {} while (doSomething(), !!test);", None),
+        ("for (; doSomething(), !!test; );", None),
+        ("if (doSomething(), !!test);", None),
+        ("switch (doSomething(), val) {}", None),
+        ("while (doSomething(), !!test);", None),
+        ("with (doSomething(), val) {}", None),
+        ("a => (doSomething(), a)", None),
+        ("(1), 2", None),
+        ("((1)) , (2)", None),
+        ("while((1) , 2);", None),
+        ("a /* , */ , b", None),
+        ("var foo = (1, 2);", disallow()),
+        ("(0,eval)(\"foo()\");", disallow()),
+        ("foo(a, (b, c), d);", disallow()),
+        ("do {} while ((doSomething(), !!test));", disallow()),
+        ("for (; (doSomething(), !!test); );", disallow()),
+        ("if ((doSomething(), !!test));", disallow()),
+        ("switch ((doSomething(), val)) {}", disallow()),
+        ("while ((doSomething(), !!test));", disallow()),
+        ("with ((doSomething(), val)) {}", disallow()),
+        ("a => ((doSomething(), a))", disallow()),
+    ];
+
+    Tester::new(NoSequences::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,280 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_shadow_diagnostic(name: &str, span: Span, shadowed_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already declared in the upper scope."))
+        .with_label(span.label(format!("'{name}' shadows a variable from an outer scope")))
+        .add_related_label(shadowed_span, format!("'{name}' is first declared here"))
+}
+
+fn no_shadow_global_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already a global variable.")).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadowConfig {
+    hoist: Hoist,
+    allow: Vec<CompactStr>,
+    builtin_globals: bool,
+}
+
+impl std::ops::Deref for NoShadow {
+    type Target = NoShadowConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Whether to report shadowing before the outer variable is declared.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Hoist {
+    /// Report shadowing before the outer variables or functions are defined.
+    All,
+    /// Report shadowing before the outer functions are defined.
+    #[default]
+    Functions,
+    /// Never report shadowing before the outer variables or functions are defined.
+    Never,
+}
+
+impl From<&str> for Hoist {
+    fn from(raw: &str) -> Self {
+        match raw {
+            "all" => Self::All,
+            "never" => Self::Never,
+            _ => Self::Functions,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable declarations from shadowing variables declared in the outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shadowing is the process by which a local variable shares the same name as a variable in
+    /// its containing scope. It's confusing to read, and it's impossible to access the outer
+    /// variable from the inner scope.
+    ///
+    /// ### Options
+    ///
+    /// - `hoist` (default `"functions"`): `"all"` reports shadowing before the outer variables
+    ///   and functions are defined, `"functions"` only before the outer functions are defined,
+    ///   and `"never"` never reports shadowing before the outer declaration.
+    /// - `allow`: a list of names for which shadowing is allowed.
+    /// - `builtinGlobals` (default `false`): also report shadowing of built-in global variables.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///   var a = 10;
+    /// }
+    ///
+    /// try {} catch (a) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///   var c = 10;
+    /// }
+    /// ```
+    NoShadow,
+    suspicious
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        Self(Box::new(NoShadowConfig {
+            hoist: config.get("hoist").and_then(Value::as_str).map(Hoist::from).unwrap_or_default(),
+            allow: config
+                .get("allow")
+                .and_then(Value::as_array)
+                .map(|allow| allow.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+                .unwrap_or_default(),
+            builtin_globals: config
+                .get("builtinGlobals")
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let scopes = ctx.scopes();
+        let scope_id = symbols.get_scope_id(symbol_id);
+        if is_type_only(symbols.get_flags(symbol_id)) {
+            return;
+        }
+        let name = symbols.get_name(symbol_id);
+        if self.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+        let span = symbols.get_span(symbol_id);
+
+        let Some(shadowed_id) = find_shadowed(scope_id, name, ctx) else {
+            // In scripts, top level declarations are in the same scope as the globals.
+            let shadows_global = self.builtin_globals
+                && ctx.env_contains_var(name)
+                && (scope_id != scopes.root_scope_id() || ctx.source_type().is_module());
+            if shadows_global {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+        if is_type_only(symbols.get_flags(shadowed_id))
+            || is_on_initializer(symbol_id, shadowed_id, ctx)
+        {
+            return;
+        }
+
+        let shadowed_span = symbols.get_span(shadowed_id);
+        if span.end < shadowed_span.start && !self.report_before_declaration(shadowed_id, ctx) {
+            return;
+        }
+
+        ctx.diagnostic(no_shadow_diagnostic(name, span, shadowed_span));
+    }
+}
+
+impl NoShadow {
+    /// Whether to report shadowing of `shadowed_id` before it has been declared.
+    fn report_before_declaration(&self, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+        match self.hoist {
+            Hoist::All => true,
+            Hoist::Functions => matches!(
+                ctx.nodes().kind(ctx.symbols().get_declaration(shadowed_id)),
+                AstKind::Function(func) if func.is_declaration()
+            ),
+            Hoist::Never => false,
+        }
+    }
+}
+
+/// Find a binding named `name` in the scopes enclosing `scope_id`.
+fn find_shadowed(scope_id: ScopeId, name: &str, ctx: &LintContext) -> Option<SymbolId> {
+    let scopes = ctx.scopes();
+    scopes.ancestors(scope_id).skip(1).find_map(|scope_id| scopes.get_binding(scope_id, name))
+}
+
+fn is_type_only(flags: SymbolFlags) -> bool {
+    flags.intersects(
+        SymbolFlags::TypeAlias
+            | SymbolFlags::Interface
+            | SymbolFlags::TypeParameter
+            | SymbolFlags::TypeImport,
+    )
+}
+
+/// Whether `symbol_id` is the name of a function or class expression that initializes
+/// `shadowed_id`, e.g. `var a = function a() {};`.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let symbols = ctx.symbols();
+    let declaration = nodes.kind(symbols.get_declaration(symbol_id));
+    if !matches!(declaration, AstKind::Function(_) | AstKind::Class(_)) {
+        return false;
+    }
+    let AstKind::VariableDeclarator(declarator) = nodes.kind(symbols.get_declaration(shadowed_id))
+    else {
+        return false;
+    };
+    declarator
+        .init
+        .as_ref()
+        .is_some_and(|init| init.without_parentheses().span() == declaration.span())
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("{ const a = 0; } const a = 1;", None),
+        ("{ const a = 0; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { let a; } var a;", None),
+        ("function foo() { var a; } let a;", None),
+        ("function foo(a) { } let a;", None),
+        ("function foo(a) { } var a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "hoist": "never", "allow": ["cb"] }])),
+        ),
+        ("try {} catch (e) {} try {} catch (e) {}", None),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "functions" }]))),
+        ("function foo() { let a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("type Foo = string; function bar<Foo>(a: Foo) {}", None),
+        ("interface Foo {} function bar() { const Foo = 1; }", None),
+        ("if (a) function foo() {}", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "all" }]))),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } function a() {}", None),
+        ("function foo() { var a; } function a() {}", Some(json!([{ "hoist": "functions" }]))),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("(function a() { (function a(){}); })()", None),
+        ("let x = foo((x,y) => {});\nlet y;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        // in modules, the module scope is nested in the global scope
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        ("var a; try {} catch (a) {}", None),
+        ("function foo(a) { try {} catch (a) {} }", None),
+        ("var e; (function () { try {} catch ({ e }) {} })()", None),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        ("let a; { const b = (a) => a; }", None),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).test_and_snapshot();
+}
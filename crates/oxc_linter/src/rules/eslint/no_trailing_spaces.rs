@@ -0,0 +1,164 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::source_lines};
+
+fn no_trailing_spaces_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Trailing spaces not allowed.")
+        .with_help("Remove the trailing whitespace.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTrailingSpaces {
+    skip_blank_lines: bool,
+    ignore_comments: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow trailing whitespace at the end of lines.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Sometimes in the course of editing files, you can end up with extra whitespace at the end
+    /// of lines. These whitespace differences can be picked up by source control systems and
+    /// flagged as diffs, causing frustration for developers.
+    ///
+    /// ### Options
+    ///
+    /// - `skipBlankLines` (default `false`): allow trailing whitespace on empty lines.
+    /// - `ignoreComments` (default `false`): allow trailing whitespace in comments.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var foo = 0;//•••••
+    /// var baz = 5;//••
+    /// //•••••
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var foo = 0;
+    /// var baz = 5;
+    /// ```
+    NoTrailingSpaces,
+    style,
+    fix
+);
+
+impl Rule for NoTrailingSpaces {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            skip_blank_lines: config
+                .and_then(|config| config.get("skipBlankLines"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+            ignore_comments: config
+                .and_then(|config| config.get("ignoreComments"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn run_once(&self, ctx: &LintContext) {
+        // Whitespace inside template literals is part of the string value.
+        let template_spans = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::TemplateLiteral(template) => Some(template.quasis.iter().map(|q| q.span)),
+                _ => None,
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        for line in source_lines(ctx.source_text()) {
+            let trimmed = line.text.trim_end_matches(is_trailing_space);
+            if trimmed.len() == line.text.len() {
+                continue;
+            }
+            if self.skip_blank_lines && trimmed.is_empty() {
+                continue;
+            }
+
+            let span = Span::new(line.span.start + trimmed.len() as u32, line.span.end);
+            if template_spans.iter().any(|template| template.contains_inclusive(span)) {
+                continue;
+            }
+            if self.ignore_comments
+                && ctx.semantic().trivias().comments().any(|comment| {
+                    Span::new(comment.real_span_start(), comment.real_span_end())
+                        .contains_inclusive(span)
+                })
+            {
+                continue;
+            }
+
+            ctx.diagnostic_with_fix(no_trailing_spaces_diagnostic(span), |fixer| {
+                fixer.delete_range(span)
+            });
+        }
+    }
+}
+
+fn is_trailing_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\u{a0}' | '\u{2000}'..='\u{200b}' | '\u{3000}' | '\u{feff}')
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 5;", None),
+        ("var a = 5,\n    b = 3;", None),
+        ("var a = 5;\n\nvar b = 3;", None),
+        ("var a = `\n  foo   \n`;", None),
+        ("var a = `foo   \n${bar}   \nbaz  `;", None),
+        ("var a = 5;\n   \nvar b = 3;", Some(json!([{ "skipBlankLines": true }]))),
+        ("var a = 5;\n\t\t\nvar b = 3;", Some(json!([{ "skipBlankLines": true }]))),
+        ("\n   ", Some(json!([{ "skipBlankLines": true }]))),
+        ("var a = 5; // comment   ", Some(json!([{ "ignoreComments": true }]))),
+        ("/* \n * comment   \n */", Some(json!([{ "ignoreComments": true }]))),
+        ("//   \nvar a = 5;", Some(json!([{ "ignoreComments": true }]))),
+    ];
+
+    let fail = vec![
+        ("var a = 5;   ", None),
+        ("var a = 5; \nvar b = 3;", None),
+        ("var a = 5;\t\nvar b = 3;", None),
+        ("var a = 5;\r\nvar b = 3;  \r\n", None),
+        ("var a = 5;\n   \nvar b = 3;", None),
+        ("\u{a0}var a = 5;\u{a0}", None),
+        ("var a = 5; // comment   ", None),
+        ("/* \n * comment   \n */", None),
+        ("var a = 5;   \n   \n", Some(json!([{ "skipBlankLines": true }]))),
+        ("var a = 5; /* comment */   ", Some(json!([{ "ignoreComments": true }]))),
+        ("var a = `foo`;  ", None),
+    ];
+
+    let fix = vec![
+        ("var a = 5;   ", "var a = 5;", None),
+        ("var a = 5; \nvar b = 3;\t", "var a = 5;\nvar b = 3;", None),
+        ("var a = 5;  \r\nvar b = 3;", "var a = 5;\r\nvar b = 3;", None),
+        ("var a = 5;\n   \nvar b = 3;", "var a = 5;\n\nvar b = 3;", None),
+        (
+            "var a = 5;  \n   \nvar b = 3;",
+            "var a = 5;\n   \nvar b = 3;",
+            Some(json!([{ "skipBlankLines": true }])),
+        ),
+        ("var a = 5;  // comment   ", "var a = 5;  // comment", None),
+    ];
+
+    Tester::new(NoTrailingSpaces::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
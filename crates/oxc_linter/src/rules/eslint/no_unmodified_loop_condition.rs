@@ -0,0 +1,298 @@
+use oxc_ast::{
+    ast::{
+        CallExpression, Expression, IdentifierReference, MemberExpression, NewExpression,
+        SimpleAssignmentTarget, TaggedTemplateExpression, YieldExpression,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_unmodified_loop_condition_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is not modified in this loop."))
+        .with_help("Modify the variable in the loop, or the loop will run forever or never.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unmodified loop conditions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables in a loop condition often need to be modified in the loop. If they are not,
+    /// the loop either never runs or never ends, which is probably a mistake.
+    ///
+    /// Conditions that contain function calls, member accesses, `new` or `yield` expressions
+    /// are ignored, since those can change their value on every iteration. For comparisons
+    /// like `i < length`, it is enough if any of the variables is modified. Variables that are
+    /// written to in another function are assumed to be modified by calling it in the loop.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// let node = something;
+    /// while (node) {
+    ///     doSomething(node);
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++i) {
+    ///     doSomething(items[j]);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// let node = something;
+    /// while (node) {
+    ///     doSomething(node);
+    ///     node = node.parent;
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++j) {
+    ///     doSomething(items[j]);
+    /// }
+    ///
+    /// while (node !== root) {
+    ///     doSomething(node);
+    ///     node = node.parent;
+    /// }
+    /// ```
+    NoUnmodifiedLoopCondition,
+    suspicious
+);
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // The test, and the ranges in which modifications count as being in the loop.
+        let (test, ranges) = match node.kind() {
+            AstKind::WhileStatement(stmt) => (&stmt.test, vec![stmt.span]),
+            AstKind::DoWhileStatement(stmt) => (&stmt.test, vec![stmt.span]),
+            AstKind::ForStatement(stmt) => {
+                let Some(test) = &stmt.test else {
+                    return;
+                };
+                let mut ranges = vec![test.span(), stmt.body.span()];
+                ranges.extend(stmt.update.as_ref().map(GetSpan::span));
+                (test, ranges)
+            }
+            _ => return,
+        };
+
+        let mut conditions = vec![];
+        collect_conditions(test, None, &mut conditions);
+
+        let loop_function = enclosing_function(node.id(), ctx);
+        let is_modified = |ident: &IdentifierReference| {
+            let Some(symbol_id) = ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+            else {
+                // Global variables may be modified anywhere.
+                return true;
+            };
+            ctx.symbols().get_resolved_references(symbol_id).any(|reference| {
+                if !reference.is_write() {
+                    return false;
+                }
+                let node_id = reference.node_id();
+                let span = ctx.nodes().get_node(node_id).kind().span();
+                ranges.iter().any(|range| range.contains_inclusive(span))
+                    // Writes in closures may happen whenever they are called.
+                    || enclosing_function(node_id, ctx) != loop_function
+            })
+        };
+
+        let mut reported_groups = vec![];
+        for (ident, group) in &conditions {
+            match group {
+                None => {
+                    if !is_modified(ident) {
+                        ctx.diagnostic(no_unmodified_loop_condition_diagnostic(
+                            &ident.name,
+                            ident.span,
+                        ));
+                    }
+                }
+                Some(group) => {
+                    if reported_groups.contains(group) {
+                        continue;
+                    }
+                    reported_groups.push(*group);
+                    let members = conditions
+                        .iter()
+                        .filter(|(_, other)| other.as_ref() == Some(group))
+                        .map(|(ident, _)| *ident)
+                        .collect::<Vec<_>>();
+                    if members.iter().any(|ident| is_modified(ident)) {
+                        continue;
+                    }
+                    for ident in members {
+                        ctx.diagnostic(no_unmodified_loop_condition_diagnostic(
+                            &ident.name,
+                            ident.span,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Collect the variables in a loop condition, along with the comparison or conditional
+/// expression they belong to. Variables in the same group only need one of them modified.
+///
+/// Stops at anything that may have side effects or change its value without any variable
+/// being modified, like function calls and member expressions.
+fn collect_conditions<'a, 'b>(
+    expr: &'b Expression<'a>,
+    group: Option<Span>,
+    conditions: &mut Vec<(&'b IdentifierReference<'a>, Option<Span>)>,
+) {
+    match expr {
+        Expression::Identifier(ident) => conditions.push((ident, group)),
+        Expression::ParenthesizedExpression(paren) => {
+            collect_conditions(&paren.expression, group, conditions);
+        }
+        Expression::UpdateExpression(update) => {
+            if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument {
+                conditions.push((ident, group));
+            }
+        }
+        Expression::UnaryExpression(unary) => {
+            collect_conditions(&unary.argument, group, conditions);
+        }
+        Expression::LogicalExpression(logical) => {
+            collect_conditions(&logical.left, group, conditions);
+            collect_conditions(&logical.right, group, conditions);
+        }
+        Expression::BinaryExpression(binary) => {
+            if group.is_none() && has_dynamic_expressions(expr) {
+                return;
+            }
+            let group = group.or(Some(binary.span));
+            collect_conditions(&binary.left, group, conditions);
+            collect_conditions(&binary.right, group, conditions);
+        }
+        Expression::ConditionalExpression(conditional) => {
+            if group.is_none() && has_dynamic_expressions(expr) {
+                return;
+            }
+            let group = group.or(Some(conditional.span));
+            collect_conditions(&conditional.test, group, conditions);
+            collect_conditions(&conditional.consequent, group, conditions);
+            collect_conditions(&conditional.alternate, group, conditions);
+        }
+        _ => {}
+    }
+}
+
+fn has_dynamic_expressions(expr: &Expression) -> bool {
+    let mut finder = DynamicExpressionFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+#[derive(Debug, Default)]
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _expr: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_member_expression(&mut self, _expr: &MemberExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_new_expression(&mut self, _expr: &NewExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _expr: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+}
+
+fn enclosing_function(node_id: AstNodeId, ctx: &LintContext) -> Option<AstNodeId> {
+    ctx.nodes()
+        .iter_parents(node_id)
+        .find(|node| {
+            matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .map(AstNode::id)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "let foo = 0; while (foo) { ++foo; }",
+        "var foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 0; while (++foo < bar) { }",
+        "var foo = 0, obj = {}; while (foo === obj.bar) { }",
+        "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+        "var foo = 0, f = {}; while (foo === f()) { }",
+        "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? 1 : 0) { foo = 0; }",
+        "var foo = 0, bar = 9; while (foo < bar) { bar -= 1; }",
+        "var foo = 0, bar = 0, baz = 0; while (foo ? bar : baz) { foo += 1; }",
+        "var foo = 0, bar = 0, baz = 0; while (foo ? bar : baz) { bar += 1; }",
+        "var foo = 0, bar = 0, baz = 0; while (foo ? bar : baz) { baz += 1; }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "var foo = 0; do { } while (foo++);",
+        "for (var foo = 0; foo; ++foo) { }",
+        "for (var foo = 0; foo;) { ++foo }",
+        "var foo = 0, bar = 0; for (bar; foo;) { ++foo }",
+        "var foo; if (foo) { } else { while (foo) { foo = 1; } }",
+        "var a = 0; for (; a < 10; a++) { }",
+        "while (window.ok) { }",
+        "while (globalFlag) { }",
+        "let done = false; const stop = () => { done = true; }; while (!done) { step(stop); }",
+        "function foo() { let x = true; setTimeout(() => { x = false; }); while (x) { wait(); } }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { } foo = 1;",
+        "for (var i = 0, j = 0; j < 10; ++i) { }",
+        "let node = getNode(); while (node) { doSomething(node); }",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, pass, fail).test_and_snapshot();
+}
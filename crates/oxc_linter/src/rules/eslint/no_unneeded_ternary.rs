@@ -0,0 +1,286 @@
+use oxc_ast::{
+    ast::{ConditionalExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    operator::{LogicalOperator, UnaryOperator},
+    precedence::Precedence,
+};
+
+use crate::{
+    ast_util::{expression_precedence, get_boolean_literal_value, invert_condition},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn boolean_literals_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary use of boolean literals in conditional expression.")
+        .with_help("Use the condition, or its negation, instead.")
+        .with_label(span)
+}
+
+fn identical_branches_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary use of conditional expression with identical branches.")
+        .with_help("Use the value of the branches instead.")
+        .with_label(span)
+}
+
+fn default_assignment_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary use of conditional expression for default assignment.")
+        .with_help("Use the `||` operator instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnneededTernary {
+    default_assignment: bool,
+}
+
+impl Default for NoUnneededTernary {
+    fn default() -> Self {
+        Self { default_assignment: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow ternary operators when simpler alternatives exist.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A ternary expression choosing between two boolean literals can be replaced by its
+    /// condition, possibly negated or converted with `Boolean()`. A ternary expression with
+    /// identical branches can be replaced by the value of its branches.
+    ///
+    /// The fix is only offered for identical branches if the condition is an identifier, since
+    /// other conditions may have side effects.
+    ///
+    /// ### Options
+    ///
+    /// - `defaultAssignment` (default `true`): allow the conditional default assignment pattern
+    ///   `x ? x : y`. With `false`, it is reported and fixed to `x || y`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const isYes = answer === 1 ? true : false;
+    ///
+    /// const isNo = answer === 1 ? false : true;
+    ///
+    /// const hasValue = value ? true : false;
+    ///
+    /// const result = cond ? x : x;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const isYes = answer === 1;
+    ///
+    /// const isNo = answer !== 1;
+    ///
+    /// const hasValue = Boolean(value);
+    ///
+    /// const result = cond ? x : y;
+    /// ```
+    NoUnneededTernary,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoUnneededTernary {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default_assignment = value
+            .get(0)
+            .and_then(|config| config.get("defaultAssignment"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+        Self { default_assignment }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ConditionalExpression(expr) = node.kind() else {
+            return;
+        };
+        let test = expr.test.without_parentheses();
+        let consequent = expr.consequent.without_parentheses();
+        let alternate = expr.alternate.without_parentheses();
+
+        if ctx.source_range(consequent.span()) == ctx.source_range(alternate.span()) {
+            ctx.diagnostic_with_fix(identical_branches_diagnostic(expr.span), |fixer| {
+                if !matches!(test, Expression::Identifier(_)) || has_comments(expr, ctx) {
+                    return fixer.noop();
+                }
+                fixer.replace(expr.span, ctx.source_range(expr.consequent.span()).to_string())
+            });
+        } else if let (Some(consequent), Some(_)) =
+            (get_boolean_literal_value(consequent), get_boolean_literal_value(alternate))
+        {
+            ctx.diagnostic_with_fix(boolean_literals_diagnostic(expr.span), |fixer| {
+                if has_comments(expr, ctx) {
+                    return fixer.noop();
+                }
+                let replacement = if !consequent {
+                    invert_condition(test, ctx)
+                } else if is_boolean_expression(test) {
+                    ctx.source_range(test.span()).to_string()
+                } else if matches!(test, Expression::SequenceExpression(_)) {
+                    format!("Boolean(({}))", ctx.source_range(test.span()))
+                } else {
+                    format!("Boolean({})", ctx.source_range(test.span()))
+                };
+                fixer.replace(expr.span, replacement)
+            });
+        } else if !self.default_assignment && is_default_assignment(test, consequent) {
+            ctx.diagnostic_with_fix(default_assignment_diagnostic(expr.span), |fixer| {
+                if has_comments(expr, ctx) {
+                    return fixer.noop();
+                }
+                let alternate_source = ctx.source_range(alternate.span());
+                // `??` cannot be mixed with `||` without parentheses.
+                let needs_parens = expression_precedence(alternate) < Precedence::LogicalOr
+                    || matches!(
+                        alternate,
+                        Expression::LogicalExpression(logical)
+                            if logical.operator == LogicalOperator::Coalesce
+                    );
+                let replacement = if needs_parens {
+                    format!("{} || ({alternate_source})", ctx.source_range(test.span()))
+                } else {
+                    format!("{} || {alternate_source}", ctx.source_range(test.span()))
+                };
+                fixer.replace(expr.span, replacement)
+            });
+        }
+    }
+}
+
+fn has_comments(expr: &ConditionalExpression, ctx: &LintContext) -> bool {
+    ctx.semantic().trivias().has_comments_between(expr.span)
+}
+
+/// Whether `expr` always evaluates to a boolean, e.g. `a === b` or `!a`.
+fn is_boolean_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::BinaryExpression(binary) => {
+            binary.operator.is_equality()
+                || binary.operator.is_compare()
+                || binary.operator.is_relational()
+        }
+        Expression::UnaryExpression(unary) => unary.operator == UnaryOperator::LogicalNot,
+        _ => false,
+    }
+}
+
+/// Whether the conditional expression is the default assignment pattern `x ? x : y`.
+fn is_default_assignment(test: &Expression, consequent: &Expression) -> bool {
+    match (test, consequent) {
+        (Expression::Identifier(test), Expression::Identifier(consequent)) => {
+            test.name == consequent.name
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let no_default_assignment = || Some(json!([{ "defaultAssignment": false }]));
+
+    let pass = vec![
+        ("config.newIsCap = config.newIsCap !== false", None),
+        ("var a = x === 2 ? 'Yes' : 'No';", None),
+        ("var a = x === 2 ? true : 'No';", None),
+        ("var a = x === 2 ? 'Yes' : false;", None),
+        ("var a = x === 2 ? 'true' : 'false';", None),
+        ("var a = foo ? foo : bar;", None),
+        ("var result = value || (canSet ? 'unset' : 'can not set')", None),
+        ("var a = foo ? bar : foo;", no_default_assignment()),
+        ("foo ? bar : foo;", no_default_assignment()),
+        ("var a = f(x ? x : 1)", None),
+        ("f(x ? x : 1);", None),
+        ("foo ? foo : bar;", None),
+        ("var a = foo ? 'Yes' : foo;", no_default_assignment()),
+        ("var a = foo.bar ? foo.bar : 1;", no_default_assignment()),
+        ("var a = cond ? a : b;", None),
+    ];
+
+    let fail = vec![
+        ("var a = x === 2 ? true : false;", None),
+        ("var a = x >= 2 ? true : false;", None),
+        ("var a = x ? true : false;", None),
+        ("var a = x === 1 ? false : true;", None),
+        ("var a = x != 1 ? false : true;", None),
+        ("var a = foo() ? false : true;", None),
+        ("var a = !foo() ? false : true;", None),
+        ("var a = foo + bar ? false : true;", None),
+        ("var a = x instanceof foo ? false : true;", None),
+        ("var a = foo ? false : false;", None),
+        ("var a = foo() ? false : false;", None),
+        ("var a = x instanceof foo ? true : false;", None),
+        ("var a = !foo ? true : false;", None),
+        ("var a = (a, b) ? true : false;", None),
+        ("var a = cond ? x : x;", None),
+        ("var a = cond ? (x + 1) : x + 1;", None),
+        ("var a = cond() ? x : x;", None),
+        ("var a = foo ? foo : 'No';", no_default_assignment()),
+        (
+            "var a = ((foo)) ? (((((foo))))) : ((((((((((((((bar))))))))))))));",
+            no_default_assignment(),
+        ),
+        ("var a = b ? b : c => c;", no_default_assignment()),
+        ("var a = b ? b : c = 0;", no_default_assignment()),
+        ("var a = b ? b : (c => c);", no_default_assignment()),
+        ("var a = b ? b : c ? d : e;", no_default_assignment()),
+        ("var a = b ? b : c ?? d;", no_default_assignment()),
+        ("var a = b ? b : c || d;", no_default_assignment()),
+        ("var a = x ? /* comment */ true : false;", None),
+    ];
+
+    let fix = vec![
+        ("var a = x === 2 ? true : false;", "var a = x === 2;", None),
+        ("var a = x >= 2 ? true : false;", "var a = x >= 2;", None),
+        ("var a = x ? true : false;", "var a = Boolean(x);", None),
+        ("var a = x === 1 ? false : true;", "var a = x !== 1;", None),
+        ("var a = x != 1 ? false : true;", "var a = x == 1;", None),
+        ("var a = foo() ? false : true;", "var a = !foo();", None),
+        ("var a = !foo() ? false : true;", "var a = foo();", None),
+        ("var a = foo + bar ? false : true;", "var a = !(foo + bar);", None),
+        ("var a = x instanceof foo ? false : true;", "var a = !(x instanceof foo);", None),
+        ("var a = foo ? false : false;", "var a = false;", None),
+        ("var a = foo() ? false : false;", "var a = foo() ? false : false;", None),
+        ("var a = x instanceof foo ? true : false;", "var a = x instanceof foo;", None),
+        ("var a = !foo ? true : false;", "var a = !foo;", None),
+        ("var a = (a, b) ? true : false;", "var a = Boolean((a, b));", None),
+        ("var a = cond ? x : x;", "var a = x;", None),
+        ("var a = cond ? (x + 1) : x + 1;", "var a = (x + 1);", None),
+        ("var a = cond() ? x : x;", "var a = cond() ? x : x;", None),
+        ("var a = foo ? foo : 'No';", "var a = foo || 'No';", no_default_assignment()),
+        (
+            "var a = ((foo)) ? (((((foo))))) : ((((((((((((((bar))))))))))))));",
+            "var a = foo || bar;",
+            no_default_assignment(),
+        ),
+        ("var a = b ? b : c => c;", "var a = b || (c => c);", no_default_assignment()),
+        ("var a = b ? b : c = 0;", "var a = b || (c = 0);", no_default_assignment()),
+        ("var a = b ? b : (c => c);", "var a = b || (c => c);", no_default_assignment()),
+        ("var a = b ? b : c ? d : e;", "var a = b || (c ? d : e);", no_default_assignment()),
+        ("var a = b ? b : c ?? d;", "var a = b || (c ?? d);", no_default_assignment()),
+        ("var a = b ? b : c || d;", "var a = b || c || d;", no_default_assignment()),
+        (
+            "var a = x ? /* comment */ true : false;",
+            "var a = x ? /* comment */ true : false;",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUnneededTernary::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -1,9 +1,12 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Expression, LabeledStatement, Statement},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn no_unused_labels_diagnostic(label_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow unused labels")
@@ -34,9 +37,13 @@ declare_oxc_lint!(
     ///     doSomething(student);
     /// }
     /// ```
+    ///
+    /// The fix removes the label. It is not offered when the body is a string literal at the
+    /// start of a script or function, since removing the label would turn it into a directive,
+    /// e.g. `A: "use strict";`.
     NoUnusedLabels,
     correctness,
-    fix
+    conditional_fix
 );
 
 impl Rule for NoUnusedLabels {
@@ -51,12 +58,34 @@ impl Rule for NoUnusedLabels {
             };
             ctx.diagnostic_with_fix(
                 no_unused_labels_diagnostic(stmt.label.name.as_str(), stmt.label.span),
-                |fixer| fixer.replace_with(stmt, &stmt.body),
+                |fixer| {
+                    if would_become_directive(stmt, node, ctx) {
+                        return fixer.noop();
+                    }
+                    fixer.replace_with(stmt, &stmt.body)
+                },
             );
         }
     }
 }
 
+/// Whether the body of `stmt` would be parsed as a directive once the label is removed, i.e. it
+/// is a string literal statement that starts a script or function body.
+fn would_become_directive(stmt: &LabeledStatement, node: &AstNode, ctx: &LintContext) -> bool {
+    let Statement::ExpressionStatement(body) = &stmt.body else {
+        return false;
+    };
+    if !matches!(body.expression, Expression::StringLiteral(_)) {
+        return false;
+    }
+    let statements = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::Program(program)) => &program.body,
+        Some(AstKind::FunctionBody(body)) => &body.statements,
+        _ => return false,
+    };
+    statements.first().is_some_and(|first| first.span() == stmt.span)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -84,6 +113,9 @@ fn test() {
         ("A: { var A = 0; console.log(A); }", None),
         ("A: /* comment */ foo", None),
         ("A /* comment */: foo", None),
+        ("A: 'use strict';", None),
+        ("function foo() { A: 'use strict'; }", None),
+        ("foo(); A: 'bar';", None),
     ];
     let fix = vec![
         ("A: var foo = 0;", "var foo = 0;", None),
@@ -94,6 +126,10 @@ fn test() {
             "A: for (var i = 0; i < 10; ++i) { break A; }",
             None,
         ),
+        ("A: 'use strict';", "A: 'use strict';", None),
+        ("function foo() { A: 'use strict'; }", "function foo() { A: 'use strict'; }", None),
+        ("foo(); A: 'bar';", "foo(); 'bar';", None),
+        ("function foo() { A: ('use strict'); }", "function foo() { ('use strict'); }", None),
     ];
 
     Tester::new(NoUnusedLabels::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
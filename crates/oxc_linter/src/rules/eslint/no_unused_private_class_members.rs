@@ -298,6 +298,10 @@ fn test() {
                 this.#x = 1;
             }
         }",
+        r"class C { #brand; static is(obj) { return #brand in obj; } }",
+        r"class C { static #count = 0; static next() { return C.#count; } }",
+        r"class C { #x; equals(other) { return this.#x === other.#x; } }",
+        r"class C { static #helper() {} static run() { C.#helper(); } }",
     ];
 
     let fail = vec![
@@ -443,6 +447,8 @@ fn test() {
 			        }
 			    }
 			}",
+        r"class C { static #unused = 1; }",
+        r"class C { static #unusedMethod() {} }",
     ];
 
     Tester::new(NoUnusedPrivateClassMembers::NAME, pass, fail).test_and_snapshot();
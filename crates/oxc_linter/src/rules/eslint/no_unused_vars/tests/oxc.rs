@@ -275,6 +275,31 @@ fn test_vars_reassignment() {
         .test_and_snapshot();
 }
 
+#[test]
+fn test_vars_write_only() {
+    let pass = vec![
+        ("let x; x = 1; console.log(x);", None),
+        ("let x = 0; x = 1; f(x);", None),
+        ("let x; ({ x } = obj); f(x);", None),
+        ("function foo(a) { a = a || 1; return a; } foo();", None),
+        ("let _x; _x = 1;", Some(json!([{ "varsIgnorePattern": "^_" }]))),
+    ];
+
+    let fail = vec![
+        ("let x; x = 1;", None),
+        ("let x = 0; x = 1; x = 2;", None),
+        ("let x; x += 1;", None),
+        ("let x; ({ x } = obj);", None),
+        ("let x; [x] = arr;", None),
+        ("let x; function foo() { x = 1; } foo();", None),
+        ("function foo(a) { a = 1; } foo();", None),
+        ("function foo(a) { a++; } foo();", None),
+        ("const foo = (a, b) => { b = a; }; foo();", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).test();
+}
+
 #[test]
 fn test_vars_destructure() {
     let pass = vec![
@@ -0,0 +1,358 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::ast::{Disjunction, LookAroundAssertionKind, Pattern, Term};
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{self, parse_regex_literal},
+    AstNode,
+};
+
+fn no_useless_backreference_diagnostic(
+    backreference: &str,
+    group: &str,
+    problem: Problem,
+    span: Span,
+) -> OxcDiagnostic {
+    let reason = match problem {
+        Problem::Nested => "from within that group",
+        Problem::Forward => "which appears later in the pattern",
+        Problem::Backward => "which appears before in the same lookbehind",
+        Problem::Disjunctive => "which is in another alternative",
+        Problem::IntoNegativeLookaround => "which is in a negative lookaround",
+    };
+    OxcDiagnostic::warn(format!(
+        "Backreference '{backreference}' will be ignored. It references group '{group}' {reason}."
+    ))
+    .with_help("A backreference to a group that has not matched always matches an empty string.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessBackreference;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow useless backreferences in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In JavaScript regular expressions, a backreference to a group that has not matched
+    /// (yet) is not an error. It always successfully matches an empty string, which is almost
+    /// certainly not what the author intended. A backreference is useless when it is:
+    ///
+    /// - inside the group it references, e.g. `/(a\1)/`
+    /// - before the group it references, e.g. `/\1(a)/`
+    /// - after the group it references within a lookbehind, e.g. `/(?<=(a)\1)b/`
+    /// - in a different alternative than the group it references, e.g. `/(a)|\1b/`
+    /// - outside of a negative lookaround that contains the group, e.g. `/(?!(a))\1b/`
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /^(?:(a)|\1b)$/;
+    /// /^(?:(a)|b(?:c|\1))$/;
+    /// /\1(a)/;
+    /// /(?<foo>a\k<foo>)/;
+    /// /(?<=(a)\1)b/;
+    /// /(?!(a))\1b/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /^(?:(a)|(b)\2)$/;
+    /// /(a)\1/;
+    /// /(?<foo>a)\k<foo>/;
+    /// /(?<=\1(a))b/;
+    /// /(?!(a)\1)b/;
+    /// ```
+    NoUselessBackreference,
+    correctness
+);
+
+impl Rule for NoUselessBackreference {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(literal) = node.kind() else {
+            return;
+        };
+        let Ok(regex) = parse_regex_literal(literal) else {
+            return;
+        };
+
+        let collector = Collector::collect(regex.pattern);
+        for backreference in &collector.backreferences {
+            let groups = collector
+                .groups
+                .iter()
+                .filter(|group| match backreference.reference {
+                    utils::Backreference::Indexed(reference) => group.index == reference.index,
+                    utils::Backreference::Named(reference) => {
+                        group.name.as_ref() == Some(&reference.name)
+                    }
+                })
+                .collect::<Vec<_>>();
+            // With duplicate named groups, the backreference is only useless if it is useless
+            // for all of them.
+            let problems =
+                groups.iter().map(|group| find_problem(backreference, group)).collect::<Vec<_>>();
+            if problems.is_empty() || problems.iter().any(Option::is_none) {
+                continue;
+            }
+            let (Some(problem), group) = (problems[0], groups[0]) else {
+                continue;
+            };
+            let source_text = ctx.source_text();
+            let span = backreference.reference.span();
+            ctx.diagnostic(no_useless_backreference_diagnostic(
+                span.source_text(source_text),
+                group.span.source_text(source_text),
+                problem,
+                span,
+            ));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Problem {
+    /// The backreference is inside the group.
+    Nested,
+    /// The backreference comes before the group.
+    Forward,
+    /// The backreference comes after the group, but they are matched right-to-left in a
+    /// lookbehind.
+    Backward,
+    /// The backreference and the group are in different alternatives.
+    Disjunctive,
+    /// The group is in a negative lookaround that does not contain the backreference.
+    IntoNegativeLookaround,
+}
+
+fn find_problem(backreference: &Backreference, group: &Group) -> Option<Problem> {
+    let group_id = group.path.last().map(|frame| frame.id);
+    if backreference.path.iter().any(|frame| Some(frame.id) == group_id) {
+        return Some(Problem::Nested);
+    }
+
+    // Length of the path from the root to the lowest common ancestor.
+    let common =
+        backreference.path.iter().zip(&group.path).take_while(|(a, b)| a.id == b.id).count();
+    let group_cut = &group.path[common..];
+    let is_matching_backward = group.path[..common]
+        .iter()
+        .rev()
+        .find_map(|frame| match frame.kind {
+            FrameKind::LookAround { behind, .. } => Some(behind),
+            _ => None,
+        })
+        .unwrap_or(false);
+
+    if group_cut.first().is_some_and(|frame| frame.kind == FrameKind::Alternative) {
+        Some(Problem::Disjunctive)
+    } else if !is_matching_backward && backreference.reference.span().end <= group.span.start {
+        Some(Problem::Forward)
+    } else if is_matching_backward && group.span.end <= backreference.reference.span().start {
+        Some(Problem::Backward)
+    } else if group_cut
+        .iter()
+        .any(|frame| matches!(frame.kind, FrameKind::LookAround { negative: true, .. }))
+    {
+        Some(Problem::IntoNegativeLookaround)
+    } else {
+        None
+    }
+}
+
+/// A node on the path from the root of the pattern to a group or backreference.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    id: usize,
+    kind: FrameKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrameKind {
+    Alternative,
+    LookAround {
+        behind: bool,
+        negative: bool,
+    },
+    /// Capturing groups, non-capturing groups and quantifiers.
+    Other,
+}
+
+#[derive(Debug)]
+struct Group<'a> {
+    span: Span,
+    index: u32,
+    name: Option<Atom<'a>>,
+    /// Path from the root, ending with the group itself.
+    path: Vec<Frame>,
+}
+
+#[derive(Debug)]
+struct Backreference<'r, 'a> {
+    reference: utils::Backreference<'r, 'a>,
+    /// Path from the root to the backreference.
+    path: Vec<Frame>,
+}
+
+#[derive(Debug, Default)]
+struct Collector<'r, 'a> {
+    groups: Vec<Group<'a>>,
+    backreferences: Vec<Backreference<'r, 'a>>,
+    path: Vec<Frame>,
+    next_id: usize,
+}
+
+impl<'r, 'a> Collector<'r, 'a> {
+    fn collect(pattern: &'r Pattern<'a>) -> Self {
+        let mut collector = Self::default();
+        collector.visit_disjunction(&pattern.body);
+        collector
+    }
+
+    fn enter(&mut self, kind: FrameKind) {
+        self.path.push(Frame { id: self.next_id, kind });
+        self.next_id += 1;
+    }
+
+    fn leave(&mut self) {
+        self.path.pop();
+    }
+
+    fn visit_disjunction(&mut self, disjunction: &'r Disjunction<'a>) {
+        for alternative in &disjunction.body {
+            self.enter(FrameKind::Alternative);
+            for term in &alternative.body {
+                self.visit_term(term);
+            }
+            self.leave();
+        }
+    }
+
+    fn visit_term(&mut self, term: &'r Term<'a>) {
+        match term {
+            Term::LookAroundAssertion(assertion) => {
+                let (behind, negative) = match assertion.kind {
+                    LookAroundAssertionKind::Lookahead => (false, false),
+                    LookAroundAssertionKind::NegativeLookahead => (false, true),
+                    LookAroundAssertionKind::Lookbehind => (true, false),
+                    LookAroundAssertionKind::NegativeLookbehind => (true, true),
+                };
+                self.enter(FrameKind::LookAround { behind, negative });
+                self.visit_disjunction(&assertion.body);
+                self.leave();
+            }
+            Term::Quantifier(quantifier) => {
+                self.enter(FrameKind::Other);
+                self.visit_term(&quantifier.body);
+                self.leave();
+            }
+            Term::CapturingGroup(group) => {
+                self.enter(FrameKind::Other);
+                // Groups are numbered in the order of their opening parentheses.
+                #[allow(clippy::cast_possible_truncation)]
+                let index = self.groups.len() as u32 + 1;
+                self.groups.push(Group {
+                    span: group.span,
+                    index,
+                    name: group.name.clone(),
+                    path: self.path.clone(),
+                });
+                self.visit_disjunction(&group.body);
+                self.leave();
+            }
+            Term::IgnoreGroup(group) => {
+                self.enter(FrameKind::Other);
+                self.visit_disjunction(&group.body);
+                self.leave();
+            }
+            Term::IndexedReference(reference) => self.backreferences.push(Backreference {
+                reference: utils::Backreference::Indexed(reference),
+                path: self.path.clone(),
+            }),
+            Term::NamedReference(reference) => self.backreferences.push(Backreference {
+                reference: utils::Backreference::Named(reference),
+                path: self.path.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "'\\1(a)'",
+        "regExp('\\1(a)')",
+        "new Regexp('\\1(a)', 'u')",
+        "/(?:)/",
+        "/(?:a)/",
+        "/(a)/",
+        "/\\1/",
+        "/\\k<foo>/",
+        "/(a)\\1/",
+        "/(a)(?:b)\\1/",
+        "/(a)\\1(?:b)/",
+        "/(a)(?:b|\\1)/",
+        "/(a)(?:\\1)/",
+        "/(a)(?:b)+\\1/",
+        "/(a)(?:b)*\\1/",
+        "/(a)(b)\\1\\2/",
+        "/(a)(b)\\2\\1/",
+        "/(a)\\1(b)\\2/",
+        "/(?<foo>a)\\k<foo>/",
+        "/(?<foo>a)(?<bar>b)\\k<bar>\\k<foo>/u",
+        "/(a)|(b)\\2/",
+        "/(?:a|(b))\\1/",
+        "/(a)?(?:b|\\1)/",
+        "/(a)+\\1/",
+        "/(?=(a))\\1/",
+        "/(?<=(a))\\1/",
+        "/(?=(a)\\1)/",
+        "/(?!(a)\\1)/",
+        "/(?<=\\1(a))/",
+        "/(?<!\\1(a))/",
+        "/(?<=\\1(?:a(b)))(?:c)/",
+        "/(?<!(?<!\\1(a)))/",
+        "/(?<=(?<=\\1(a)))/",
+        "/(?=(?:(a)\\1))/",
+        "/(?:(a))(?:\\1)/",
+        "/(?:(a)\\1)+/",
+    ];
+
+    let fail = vec![
+        "/(a\\1)/",
+        "/(a(b)\\1)/",
+        "/(?<foo>a\\k<foo>)/",
+        "/(a|b\\1)/",
+        "/\\1(a)/",
+        "/(?:\\1)(a)/",
+        "/\\k<foo>(?<foo>a)/",
+        "/\\1(?:a(b))/",
+        "/(a)|\\1b/",
+        "/(?:(a)|\\1b)/",
+        "/(?:a|(b))|\\1/",
+        "/(a)|(?:b|\\1)/",
+        "/(?<=(a)\\1)b/",
+        "/(?<!(a)\\1)b/",
+        "/(?<=(?:(a)|b)\\1)/",
+        "/(?!(a))\\1b/",
+        "/(?<!(a))\\1b/",
+        "/(?!(?!(a)))\\1/",
+        "/(?=(?!(a)))\\1/",
+        "/(?:(?!(a)))\\1/",
+        "/(?<foo>a)|\\k<foo>b/",
+        "/(a)\\2(b)\\1|\\2/",
+    ];
+
+    Tester::new(NoUselessBackreference::NAME, pass, fail).test_and_snapshot();
+}
@@ -7,10 +7,18 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::{identifier::is_line_terminator, operator::BinaryOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::flatten_string_concat, context::LintContext, rule::Rule, AstNode};
 
-#[derive(Debug, Default, Clone)]
-pub struct NoUselessConcat;
+#[derive(Debug, Clone)]
+pub struct NoUselessConcat {
+    allow_multiline: bool,
+}
+
+impl Default for NoUselessConcat {
+    fn default() -> Self {
+        Self { allow_multiline: true }
+    }
+}
 
 fn no_useless_concat_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected string concatenation of literals.")
@@ -27,15 +35,48 @@ declare_oxc_lint!(
     ///
     /// It’s unnecessary to concatenate two strings together.
     ///
+    /// The fix joins the two literals. It is not offered if there are comments or parentheses
+    /// around the `+`, or if the joined literal would have a different value, e.g. for
+    /// `'\0' + '1'` or octal escapes that are not allowed in template literals.
+    ///
+    /// ### Options
+    ///
+    /// - `allowMultiline` (default `true`): allow concatenations that split a string across
+    ///   lines, i.e. with a line break between the two literals.
+    ///
     /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// var foo = "a" + "b";
+    ///
+    /// var foo = `a` + "b";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var foo = "ab";
+    ///
+    /// var foo = "a" + bar;
+    ///
+    /// var foo = "a long line " +
+    ///     "continued";
     /// ```
     NoUselessConcat,
-    suspicious
+    suspicious,
+    conditional_fix
 );
 
 impl Rule for NoUselessConcat {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_multiline = value
+            .get(0)
+            .and_then(|config| config.get("allowMultiline"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true);
+        Self { allow_multiline }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expr) = node.kind() else {
             return;
@@ -45,35 +86,139 @@ impl Rule for NoUselessConcat {
             return;
         }
 
-        let left = get_left(binary_expr);
+        // The operand right before the `+`, e.g. `b` in `a + b + c`.
+        let Some(left) = flatten_string_concat(&binary_expr.left).pop() else {
+            return;
+        };
         let right = get_right(binary_expr);
 
-        if left.is_string_literal() && right.is_string_literal() {
-            let left_span = left.span();
-            let right_span = right.span();
-            let span = Span::new(left_span.end, right_span.start);
-            let source_text = span.source_text(ctx.source_text());
-            if source_text.chars().any(is_line_terminator) {
-                return;
-            }
-            let span = Span::new(left_span.start, right_span.end);
-            ctx.diagnostic(no_useless_concat_diagnostic(span));
+        if !left.is_string_literal() || !right.is_string_literal() {
+            return;
         }
+        let between = Span::new(left.span().end, right.span().start);
+        let between_text = between.source_text(ctx.source_text());
+        if self.allow_multiline && between_text.chars().any(is_line_terminator) {
+            return;
+        }
+        ctx.diagnostic_with_fix(
+            no_useless_concat_diagnostic(operator_span(between, ctx)),
+            |fixer| {
+                if between_text.trim() != "+" {
+                    return fixer.noop();
+                }
+                match join_literals(left, right, ctx) {
+                    Some(joined) => {
+                        fixer.replace(Span::new(left.span().start, right.span().end), joined)
+                    }
+                    None => fixer.noop(),
+                }
+            },
+        );
     }
 }
 
-fn get_left<'a>(expr: &'a BinaryExpression<'a>) -> &'a Expression<'a> {
-    let mut left = &expr.left;
-    loop {
-        if let Expression::BinaryExpression(binary_expr) = left {
-            if binary_expr.operator == BinaryOperator::Addition {
-                left = &binary_expr.right;
-                continue;
+/// The span of the `+` between the two operands in `between`, ignoring comments.
+#[allow(clippy::cast_possible_truncation)]
+fn operator_span(between: Span, ctx: &LintContext) -> Span {
+    let text = between.source_text(ctx.source_text());
+    let mut offset = 0;
+    while offset < text.len() {
+        let rest = &text[offset..];
+        if rest.starts_with('+') {
+            let start = between.start + offset as u32;
+            return Span::new(start, start + 1);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            offset += comment.find("*/").map_or(rest.len(), |end| end + 4);
+        } else if let Some(comment) = rest.strip_prefix("//") {
+            offset += comment.find('\n').map_or(rest.len(), |end| end + 2);
+        } else {
+            offset += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    between
+}
+
+/// The source of a single literal with the value of `left` followed by `right`, or `None` if
+/// the literals cannot be joined without changing the value.
+///
+/// Two string literals are joined into a string literal with the quotes of `left`. If either
+/// is a template literal, the result is a template literal.
+fn join_literals(left: &Expression, right: &Expression, ctx: &LintContext) -> Option<String> {
+    let left_raw = literal_raw(ctx.source_range(left.span()));
+    let right_raw = literal_raw(ctx.source_range(right.span()));
+    // `'\0' + '1'` would become the legacy octal escape `'\01'`.
+    if ends_with_null_escape(left_raw) && right_raw.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    if let (Expression::StringLiteral(_), Expression::StringLiteral(_)) = (left, right) {
+        let quote = ctx.source_range(left.span()).chars().next()?;
+        return Some(format!("{quote}{left_raw}{}{quote}", escape_quotes(right_raw, quote)));
+    }
+    let left_raw = match left {
+        Expression::StringLiteral(_) => string_raw_to_template_raw(left_raw)?,
+        _ => left_raw.to_string(),
+    };
+    let right_raw = match right {
+        Expression::StringLiteral(_) => string_raw_to_template_raw(right_raw)?,
+        _ => right_raw.to_string(),
+    };
+    Some(format!("`{left_raw}{right_raw}`"))
+}
+
+/// The source of a string or template literal without its quotes or backticks.
+fn literal_raw(source: &str) -> &str {
+    source.get(1..source.len().saturating_sub(1)).unwrap_or_default()
+}
+
+/// Whether the raw contents of a literal end with the escape sequence `\0`.
+fn ends_with_null_escape(raw: &str) -> bool {
+    let Some(rest) = raw.strip_suffix('0') else {
+        return false;
+    };
+    (rest.len() - rest.trim_end_matches('\\').len()) % 2 == 1
+}
+
+/// Escape the unescaped occurrences of `quote` in the raw contents of a string literal.
+fn escape_quotes(raw: &str, quote: char) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            escaped.push(c);
+            escaped.extend(chars.next());
+            continue;
+        }
+        if c == quote {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Convert the raw contents of a string literal to the raw contents of a template literal
+/// with the same value, or `None` if it has an octal escape, which template literals do not
+/// allow.
+fn string_raw_to_template_raw(raw: &str) -> Option<String> {
+    let mut template = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next()?;
+                let is_null = escaped == '0' && !chars.peek().is_some_and(char::is_ascii_digit);
+                if escaped.is_ascii_digit() && !is_null {
+                    return None;
+                }
+                template.push(c);
+                template.push(escaped);
             }
+            '`' => template.push_str("\\`"),
+            '$' if chars.peek() == Some(&'{') => template.push_str("\\$"),
+            _ => template.push(c),
         }
-        break;
     }
-    left
+    Some(template)
 }
 
 fn get_right<'a>(expr: &'a BinaryExpression<'a>) -> &'a Expression<'a> {
@@ -92,46 +237,85 @@ fn get_right<'a>(expr: &'a BinaryExpression<'a>) -> &'a Expression<'a> {
 
 #[test]
 fn test() {
+    use serde_json::json;
+
     use crate::tester::Tester;
 
     let pass = vec![
-        "var a = 1 + 1;",
-        "var a = 1 * '2';",
-        "var a = 1 - 2;",
-        "var a = foo + bar;",
-        "var a = 'foo' + bar;",
-        "var foo = 'foo' +
+        ("var a = 1 + 1;", None),
+        ("var a = 1 * '2';", None),
+        ("var a = 1 - 2;", None),
+        ("var a = foo + bar;", None),
+        ("var a = 'foo' + bar;", None),
+        (
+            "var foo = 'foo' +
         'bar';",
-        "var string = (number + 1) + 'px';",
-        "'a' + 1",
-        "1 + '1'",
-        "1 + `1`",
-        "`1` + 1",
-        "(1 + +2) + `b`",
-        "
+            None,
+        ),
+        ("var string = (number + 1) + 'px';", None),
+        ("'a' + 1", None),
+        ("1 + '1'", None),
+        ("1 + `1`", None),
+        ("`1` + 1", None),
+        ("(1 + +2) + `b`", None),
+        (
+            "
           'a'
           + 'b'
           + 'c'
         ",
+            None,
+        ),
+        ("'a' +\n'b'", Some(json!([{ "allowMultiline": true }]))),
     ];
 
     let fail = vec![
-        "'a' + 'b'",
-        "'a' +
+        ("'a' + 'b'", None),
+        (
+            "'a' +
         'b' + 'c'",
-        "foo + 'a' + 'b'",
-        "'a' + 'b' + 'c'",
-        "(foo + 'a') + ('b' + 'c')",
-        "`a` + 'b'",
-        "`a` + `b`",
-        "foo + `a` + `b`",
-        "foo + 'a' + 'b'",
-        "'a' +
+            None,
+        ),
+        ("foo + 'a' + 'b'", None),
+        ("'a' + 'b' + 'c'", None),
+        ("(foo + 'a') + ('b' + 'c')", None),
+        ("`a` + 'b'", None),
+        ("`a` + `b`", None),
+        ("foo + `a` + `b`", None),
+        ("foo + 'a' + 'b'", None),
+        (
+            "'a' +
         'b' + 'c'
         + 'd'
         ",
-        "'a' + 'b' + 'c' + 'd' + 'e' + foo",
+            None,
+        ),
+        ("'a' + 'b' + 'c' + 'd' + 'e' + foo", None),
+        ("'a' + \"b\"", None),
+        ("'a' + \"b'c\"", None),
+        ("'`${a}' + `b`", None),
+        ("'a' + `b${c}`", None),
+        ("'a' + /* c */ 'b'", None),
+        ("'\\0' + '1'", None),
+        ("(foo + 'a') + 'b'", None),
+        ("'a' +\n'b'", Some(json!([{ "allowMultiline": false }]))),
+    ];
+
+    let fix = vec![
+        ("'a' + 'b'", "'ab'", None),
+        ("foo + 'a' + 'b'", "foo + 'ab'", None),
+        ("`a` + 'b'", "`ab`", None),
+        ("`a` + `b`", "`ab`", None),
+        ("'a' + \"b\"", "'ab'", None),
+        ("'a' + \"b'c\"", "'ab\\'c'", None),
+        ("'`${a}' + `b`", "`\\`\\${a}b`", None),
+        ("'a' + `b${c}`", "`ab${c}`", None),
+        ("'a' + /* c */ 'b'", "'a' + /* c */ 'b'", None),
+        ("'\\0' + '1'", "'\\0' + '1'", None),
+        ("'\\0' + 'a'", "'\\0a'", None),
+        ("(foo + 'a') + 'b'", "(foo + 'a') + 'b'", None),
+        ("'a' +\n'b'", "'ab'", Some(json!([{ "allowMultiline": false }]))),
     ];
 
-    Tester::new(NoUselessConcat::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoUselessConcat::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -241,8 +241,10 @@ fn check_template(string: &str) -> Vec<usize> {
                         offsets.push(byte_offset - c.len_utf8());
                     }
                 }
+                // `\${` prevents an interpolation. A quasi is always followed by `${` or the
+                // closing backtick, so `\$` at its end is never needed.
                 '$' => {
-                    if chars.peek().is_some_and(|c| *c != '{') {
+                    if chars.peek() != Some(&'{') {
                         offsets.push(byte_offset - c.len_utf8());
                     }
                 }
@@ -369,6 +371,10 @@ fn test() {
         "var foo = /[\\p{ASCII}]/u",
         "var foo = /[\\P{ASCII}]/u",
         "`${/\\s+/g}`",
+        "var foo = `\\${foo}`;",
+        "var foo = `a\\${foo}b`;",
+        "var foo = `${a}\\${b}`;",
+        "var foo = `\\${\\${foo}`;",
     ];
 
     let fail = vec![
@@ -424,6 +430,10 @@ fn test() {
         r"var foo = /\（([^\）\（]+)\）$|\(([^\)\)]+)\)$/;",
         r#"var stringLiteralWithNextLine = "line 1\line 2";"#,
         r"var stringLiteralWithNextLine = `line 1\line 2`;",
+        "var foo = `\\q`;",
+        "var foo = `${bar}\\q`;",
+        "var foo = `a\\$${foo}`;",
+        "var foo = `\\$`;",
     ];
 
     let fix = vec![
@@ -452,6 +462,8 @@ fn test() {
         ("let foo = '\\ ';", "let foo = ' ';", None),
         ("let foo = /\\ /;", "let foo = / /;", None),
         ("var foo = `\\$\\{{${foo}`;", "var foo = `$\\{{${foo}`;", None),
+        ("var foo = `\\q${bar}`;", "var foo = `q${bar}`;", None),
+        ("var foo = `a\\$${foo}`;", "var foo = `a$${foo}`;", None),
     ];
 
     Tester::new(NoUselessEscape::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{AssignmentTarget, AssignmentTargetProperty, BindingPatternKind},
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty,
+        BindingPatternKind, IdentifierReference,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -44,6 +47,17 @@ declare_oxc_lint!(
     ///
     /// It is unnecessary to rename a variable to the same name.
     ///
+    /// ### Options
+    ///
+    /// - `ignoreDestructuring` (default `false`): do not check destructuring patterns.
+    /// - `ignoreImport` (default `false`): do not check import specifiers.
+    /// - `ignoreExport` (default `false`): do not check export specifiers.
+    ///
+    /// Renames with a computed key, such as `{ [foo]: foo }`, are never reported.
+    ///
+    /// The fix removes the redundant `key:` or `as name`. It is skipped when a comment would
+    /// be removed with it.
+    ///
     /// ### Example
     ///
     /// Examples of **incorrect** code for this rule:
@@ -60,9 +74,49 @@ declare_oxc_lint!(
     /// export { baz };
     /// ```
     NoUselessRename,
-    correctness
+    correctness,
+    conditional_fix
 );
 
+/// Report a destructuring rename, fixed by removing the key, e.g. `foo:` in `{ foo: foo = 1 }`.
+fn report_destructuring(span: Span, key_span: Span, value_span: Span, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(no_useless_rename_diagnostic(span), |fixer| {
+        let between = Span::new(key_span.end, value_span.start);
+        // Keep comments, and parentheses as in `({ foo: (foo) } = obj)`.
+        if fixer.source_range(between).trim() != ":" {
+            return fixer.noop();
+        }
+        fixer.delete_range(Span::new(key_span.start, value_span.start))
+    });
+}
+
+/// Report an import or export rename, fixed by replacing the whole specifier with the local
+/// name, e.g. `foo as foo` with `foo`.
+fn report_specifier(specifier_span: Span, local_span: Span, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(no_useless_rename_diagnostic(local_span), |fixer| {
+        if ctx.semantic().trivias().has_comments_between(specifier_span) {
+            return fixer.noop();
+        }
+        fixer.replace(specifier_span, fixer.source_range(local_span))
+    });
+}
+
+/// The identifier assigned to by `target`, including the `foo` in `foo = 1`.
+fn assignment_target_identifier<'b, 'a>(
+    target: &'b AssignmentTargetMaybeDefault<'a>,
+) -> Option<&'b IdentifierReference<'a>> {
+    match target {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            match &target.binding {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident),
+                _ => None,
+            }
+        }
+        AssignmentTargetMaybeDefault::AssignmentTargetIdentifier(ident) => Some(ident),
+        _ => None,
+    }
+}
+
 impl Rule for NoUselessRename {
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
@@ -99,22 +153,22 @@ impl Rule for NoUselessRename {
                         continue;
                     };
 
-                    let renamed_key = match &property.value.kind {
+                    let renamed = match &property.value.kind {
                         BindingPatternKind::AssignmentPattern(assignment_pattern) => {
                             match &assignment_pattern.left.kind {
                                 BindingPatternKind::BindingIdentifier(binding_ident) => {
-                                    &binding_ident.name
+                                    binding_ident
                                 }
                                 _ => continue,
                             }
                         }
 
-                        BindingPatternKind::BindingIdentifier(binding_ident) => &binding_ident.name,
+                        BindingPatternKind::BindingIdentifier(binding_ident) => binding_ident,
                         _ => continue,
                     };
 
-                    if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                    if key == renamed.name {
+                        report_destructuring(property.span, property.key.span(), renamed.span, ctx);
                     }
                 }
             }
@@ -131,15 +185,24 @@ impl Rule for NoUselessRename {
                         continue;
                     };
 
+                    // A computed key, as in `({ ['foo']: foo } = obj)`, starts after the `[`.
+                    if property.name.span().start != property.span.start {
+                        continue;
+                    }
                     let Some(key) = property.name.static_name() else {
                         continue;
                     };
-                    let Some(renamed_key) = property.binding.name() else {
+                    let Some(renamed) = assignment_target_identifier(&property.binding) else {
                         continue;
                     };
 
-                    if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                    if key == renamed.name {
+                        report_destructuring(
+                            property.span,
+                            property.name.span(),
+                            renamed.span,
+                            ctx,
+                        );
                     }
                 }
             }
@@ -148,7 +211,7 @@ impl Rule for NoUselessRename {
                     && import_specifier.imported.span() != import_specifier.local.span
                     && import_specifier.local.name == import_specifier.imported.name()
                 {
-                    ctx.diagnostic(no_useless_rename_diagnostic(import_specifier.local.span));
+                    report_specifier(import_specifier.span, import_specifier.local.span, ctx);
                 }
             }
             AstKind::ExportNamedDeclaration(export_named_decl) => {
@@ -159,7 +222,7 @@ impl Rule for NoUselessRename {
                     if specifier.local.span() != specifier.exported.span()
                         && specifier.local.name() == specifier.exported.name()
                     {
-                        ctx.diagnostic(no_useless_rename_diagnostic(specifier.local.span()));
+                        report_specifier(specifier.span, specifier.local.span(), ctx);
                     }
                 }
             }
@@ -253,6 +316,8 @@ fn test() {
             Some(serde_json::json!([{ "ignoreExport": true }])),
         ),
         (r"const { ...foo } = bar;", None),
+        (r"({[foo]: foo} = obj);", None),
+        (r"({['foo']: foo} = obj);", None),
     ];
 
     let fail = vec![
@@ -397,5 +462,34 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoUselessRename::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"let {foo: foo} = obj;", r"let {foo} = obj;", None),
+        (r"let {'foo': foo} = obj;", r"let {foo} = obj;", None),
+        (r"let {\u0061: a} = obj;", r"let {a} = obj;", None),
+        (r"let {foo: foo = 1, bar: baz} = obj;", r"let {foo = 1, bar: baz} = obj;", None),
+        (r"let {foo: {bar: bar}} = obj;", r"let {foo: {bar}} = obj;", None),
+        (r"function func({foo: foo}) {}", r"function func({foo}) {}", None),
+        (r"({foo: foo} = obj);", r"({foo} = obj);", None),
+        (r"({foo: foo = a} = obj);", r"({foo = a} = obj);", None),
+        (r"({foo: (foo)} = obj);", r"({foo: (foo)} = obj);", None),
+        (r"({foo: foo/* comment */} = {});", r"({foo/* comment */} = {});", None),
+        (r"({foo: /**/foo} = {});", r"({foo: /**/foo} = {});", None),
+        (r"({foo /**/: foo} = {});", r"({foo /**/: foo} = {});", None),
+        (r"import {foo as foo} from 'foo';", r"import {foo} from 'foo';", None),
+        (r"import {'foo' as foo} from 'foo';", r"import {foo} from 'foo';", None),
+        (r"import {foo as foo, bar as bar} from 'foo';", r"import {foo, bar} from 'foo';", None),
+        (r"import {foo as/**/foo} from 'foo';", r"import {foo as/**/foo} from 'foo';", None),
+        (r"var foo = 0; export {foo as foo};", r"var foo = 0; export {foo};", None),
+        (r"var foo = 0; export {foo as 'foo'};", r"var foo = 0; export {foo};", None),
+        (r"export {'foo' as 'foo'} from 'bar';", r"export {'foo'} from 'bar';", None),
+        (r"let foo; export {foo as foo/* comment*/};", r"let foo; export {foo/* comment*/};", None),
+        (r"let foo; export {foo/**/as foo};", r"let foo; export {foo/**/as foo};", None),
+        (
+            r"let {foo: foo} = obj;",
+            r"let {foo: foo} = obj;",
+            Some(serde_json::json!([{ "ignoreDestructuring": true }])),
+        ),
+    ];
+
+    Tester::new(NoUselessRename::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -0,0 +1,386 @@
+use oxc_ast::{
+    ast::{Expression, Function, ObjectProperty, PropertyKey, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn expected_method_shorthand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected method shorthand.").with_label(span)
+}
+
+fn expected_property_shorthand_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected property shorthand.").with_label(span)
+}
+
+fn expected_method_longform_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform method syntax.").with_label(span)
+}
+
+fn expected_property_longform_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform property syntax.").with_label(span)
+}
+
+fn expected_literal_method_longform_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected longform method syntax for string literal keys.").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectShorthand {
+    mode: Mode,
+    avoid_quotes: bool,
+    ignore_constructors: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Methods and properties must use shorthand syntax.
+    #[default]
+    Always,
+    /// Methods must use shorthand syntax.
+    Methods,
+    /// Properties must use shorthand syntax.
+    Properties,
+    /// Shorthand syntax is not allowed.
+    Never,
+}
+
+impl Mode {
+    fn methods(self) -> bool {
+        matches!(self, Self::Always | Self::Methods)
+    }
+
+    fn properties(self) -> bool {
+        matches!(self, Self::Always | Self::Properties)
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow method and property shorthand syntax for object literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `{ foo: foo }` and `{ foo: function() {} }` can be written as `{ foo }` and
+    /// `{ foo() {} }`. Mixing both styles makes object literals harder to read.
+    ///
+    /// ### Options
+    ///
+    /// The first option is one of:
+    /// - `"always"` (default): methods and properties must use shorthand syntax.
+    /// - `"methods"`: methods must use shorthand syntax.
+    /// - `"properties"`: properties must use shorthand syntax.
+    /// - `"never"`: methods and properties must not use shorthand syntax.
+    ///
+    /// The second option is an object:
+    /// - `avoidQuotes` (default `false`): prefer longform syntax when the key is a string
+    ///   literal, e.g. `{ "foo": function() {} }`.
+    /// - `ignoreConstructors` (default `false`): do not report functions whose name starts with
+    ///   an uppercase letter, since methods cannot be called with `new`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const foo = {
+    ///     w: function() {},
+    ///     x: function* () {},
+    ///     [y]: function() {},
+    ///     z: z,
+    /// };
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const foo = {
+    ///     w() {},
+    ///     *x() {},
+    ///     [y]() {},
+    ///     z,
+    /// };
+    /// ```
+    ObjectShorthand,
+    style,
+    fix
+);
+
+impl Rule for ObjectShorthand {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("methods") => Mode::Methods,
+            Some("properties") => Mode::Properties,
+            Some("never") => Mode::Never,
+            _ => Mode::Always,
+        };
+        let option = |name: &str| {
+            value
+                .get(1)
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            mode,
+            avoid_quotes: option("avoidQuotes"),
+            ignore_constructors: option("ignoreConstructors"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectProperty(prop) = node.kind() else {
+            return;
+        };
+        // Getters and setters have no longform.
+        if prop.kind != PropertyKind::Init {
+            return;
+        }
+
+        if self.mode == Mode::Never {
+            if prop.shorthand {
+                ctx.diagnostic_with_fix(
+                    expected_property_longform_diagnostic(prop.span),
+                    |fixer| {
+                        let name = ctx.source_range(prop.key.span());
+                        fixer.replace(prop.span, format!("{name}: {name}"))
+                    },
+                );
+            } else if prop.method {
+                check_longform_method(prop, expected_method_longform_diagnostic, ctx);
+            }
+            return;
+        }
+
+        if prop.method {
+            if self.avoid_quotes && is_string_key(&prop.key) {
+                check_longform_method(prop, expected_literal_method_longform_diagnostic, ctx);
+            }
+            return;
+        }
+        if prop.shorthand {
+            return;
+        }
+
+        if self.mode.methods() {
+            self.check_method(prop, ctx);
+        }
+        if self.mode.properties() {
+            self.check_property(prop, ctx);
+        }
+    }
+}
+
+impl ObjectShorthand {
+    /// `{ foo: function() {} }` -> `{ foo() {} }`
+    fn check_method<'a>(&self, prop: &ObjectProperty<'a>, ctx: &LintContext<'a>) {
+        let Expression::FunctionExpression(func) = prop.value.without_parentheses() else {
+            return;
+        };
+        // Named function expressions can refer to themselves by name.
+        if func.id.is_some() {
+            return;
+        }
+        if self.avoid_quotes && is_string_key(&prop.key) {
+            return;
+        }
+        if self.ignore_constructors && is_constructor_name(&prop.key) {
+            return;
+        }
+
+        let diagnostic = expected_method_shorthand_diagnostic(prop.span);
+        let head = Span::new(prop.span.start, function_tail_start(func));
+        if matches!(prop.value, Expression::ParenthesizedExpression(_))
+            || ctx.semantic().trivias().has_comments_between(head)
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let prefix = match (func.r#async, func.generator) {
+                (true, true) => "async *",
+                (true, false) => "async ",
+                (false, true) => "*",
+                (false, false) => "",
+            };
+            let key = key_text(prop, ctx);
+            let tail = ctx.source_range(Span::new(function_tail_start(func), func.span.end));
+            fixer.replace(prop.span, format!("{prefix}{key}{tail}"))
+        });
+    }
+
+    /// `{ foo: foo }` -> `{ foo }`
+    fn check_property<'a>(&self, prop: &ObjectProperty<'a>, ctx: &LintContext<'a>) {
+        if prop.computed {
+            return;
+        }
+        let Expression::Identifier(ident) = &prop.value else {
+            return;
+        };
+        let key_matches = match &prop.key {
+            PropertyKey::StaticIdentifier(key) => key.name == ident.name,
+            PropertyKey::StringLiteral(key) => !self.avoid_quotes && key.value == ident.name,
+            _ => false,
+        };
+        // `{ __proto__: __proto__ }` sets the prototype, `{ __proto__ }` does not.
+        if !key_matches || ident.name.as_str() == "__proto__" {
+            return;
+        }
+
+        let diagnostic = expected_property_shorthand_diagnostic(prop.span);
+        if ctx.semantic().trivias().has_comments_between(prop.span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            fixer.replace(prop.span, ident.name.to_string())
+        });
+    }
+}
+
+/// `{ foo() {} }` -> `{ foo: function() {} }`
+fn check_longform_method<'a>(
+    prop: &ObjectProperty<'a>,
+    diagnostic: fn(Span) -> OxcDiagnostic,
+    ctx: &LintContext<'a>,
+) {
+    let Expression::FunctionExpression(func) = &prop.value else {
+        return;
+    };
+    let diagnostic = diagnostic(prop.span);
+    let head = Span::new(prop.span.start, function_tail_start(func));
+    if ctx.semantic().trivias().has_comments_between(head) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let keyword = match (func.r#async, func.generator) {
+            (true, true) => "async function*",
+            (true, false) => "async function",
+            (false, true) => "function*",
+            (false, false) => "function",
+        };
+        let key = key_text(prop, ctx);
+        let tail = ctx.source_range(Span::new(function_tail_start(func), func.span.end));
+        fixer.replace(prop.span, format!("{key}: {keyword}{tail}"))
+    });
+}
+
+fn is_string_key(key: &PropertyKey) -> bool {
+    matches!(key, PropertyKey::StringLiteral(_))
+}
+
+fn is_constructor_name(key: &PropertyKey) -> bool {
+    key.static_name().is_some_and(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// The source text of the property key, with brackets if it is computed.
+fn key_text<'a>(prop: &ObjectProperty<'a>, ctx: &LintContext<'a>) -> String {
+    let key = ctx.source_range(prop.key.span());
+    if prop.computed {
+        format!("[{key}]")
+    } else {
+        key.to_string()
+    }
+}
+
+/// Where the part of a function that is the same in both syntaxes starts: its type parameters
+/// or its parameter list.
+fn function_tail_start(func: &Function) -> u32 {
+    func.type_parameters.as_ref().map_or(func.params.span.start, |params| params.span.start)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = { y() {} }", None),
+        ("var x = { y }", None),
+        ("var x = { a: b }", None),
+        ("var x = { a: 'a' }", None),
+        ("var x = { 'a': b }", None),
+        ("var x = { y: function y() {} }", None),
+        ("var x = { y: () => {} }", None),
+        ("var x = { get y() {}, set y(v) {} }", None),
+        ("var x = { ...y }", None),
+        ("var x = { [y]: y }", None),
+        ("var x = { __proto__: __proto__ }", None),
+        ("var x = { y: function() {} }", Some(json!(["properties"]))),
+        ("var x = { y: y }", Some(json!(["methods"]))),
+        ("var x = { y: y, z: function() {} }", Some(json!(["never"]))),
+        ("var x = { 'y': function() {} }", Some(json!(["always", { "avoidQuotes": true }]))),
+        ("var x = { 'y': y }", Some(json!(["always", { "avoidQuotes": true }]))),
+        ("var x = { y() {} }", Some(json!(["always", { "avoidQuotes": true }]))),
+        ("var x = { Y: function() {} }", Some(json!(["always", { "ignoreConstructors": true }]))),
+    ];
+
+    let fail = vec![
+        ("var x = { y: function() {} }", None),
+        ("var x = { y: function(a, b) { return a + b; } }", None),
+        ("var x = { y: async function() {} }", None),
+        ("var x = { y: function*() {} }", None),
+        ("var x = { y: async function* () {} }", None),
+        ("var x = { [y]: function() {} }", None),
+        ("var x = { 'y': function() {} }", None),
+        ("var x = { y: function<T>(a: T) {} }", None),
+        ("var x = { y: /* comment */ function() {} }", None),
+        ("var x = { y: y }", None),
+        ("var x = { 'y': y }", None),
+        ("var x = { y: y, z: z }", None),
+        ("var x = { Y: function() {} }", None),
+        ("var x = { y: function() {} }", Some(json!(["methods"]))),
+        ("var x = { y: y }", Some(json!(["properties"]))),
+        ("var x = { y }", Some(json!(["never"]))),
+        ("var x = { y() {} }", Some(json!(["never"]))),
+        ("var x = { async y() {} }", Some(json!(["never"]))),
+        ("var x = { *y() {} }", Some(json!(["never"]))),
+        ("var x = { async *[y]() {} }", Some(json!(["never"]))),
+        ("var x = { 'y'() {} }", Some(json!(["always", { "avoidQuotes": true }]))),
+    ];
+
+    let fix = vec![
+        ("var x = { y: function() {} }", "var x = { y() {} }", None),
+        (
+            "var x = { y: function(a, b) { return a + b; } }",
+            "var x = { y(a, b) { return a + b; } }",
+            None,
+        ),
+        ("var x = { y: async function() {} }", "var x = { async y() {} }", None),
+        ("var x = { y: function*() {} }", "var x = { *y() {} }", None),
+        ("var x = { y: async function* () {} }", "var x = { async *y() {} }", None),
+        ("var x = { [y]: function() {} }", "var x = { [y]() {} }", None),
+        ("var x = { 'y': function() {} }", "var x = { 'y'() {} }", None),
+        ("var x = { y: function<T>(a: T) {} }", "var x = { y<T>(a: T) {} }", None),
+        (
+            "var x = { y: /* comment */ function() {} }",
+            "var x = { y: /* comment */ function() {} }",
+            None,
+        ),
+        ("var x = { y: y }", "var x = { y }", None),
+        ("var x = { 'y': y }", "var x = { y }", None),
+        ("var x = { y: y, z: z }", "var x = { y, z }", None),
+        ("var x = { y }", "var x = { y: y }", Some(json!(["never"]))),
+        ("var x = { y() {} }", "var x = { y: function() {} }", Some(json!(["never"]))),
+        ("var x = { async y() {} }", "var x = { y: async function() {} }", Some(json!(["never"]))),
+        ("var x = { *y() {} }", "var x = { y: function*() {} }", Some(json!(["never"]))),
+        (
+            "var x = { async *[y]() {} }",
+            "var x = { [y]: async function*() {} }",
+            Some(json!(["never"])),
+        ),
+        (
+            "var x = { 'y'() {} }",
+            "var x = { 'y': function() {} }",
+            Some(json!(["always", { "avoidQuotes": true }])),
+        ),
+    ];
+
+    Tester::new(ObjectShorthand::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,389 @@
+use cow_utils::CowUtils;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn always_padded_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Block must be padded by blank lines.").with_label(span)
+}
+
+fn never_padded_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Block must not be padded by blank lines.").with_label(span)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Padding {
+    Always,
+    Never,
+}
+
+impl Padding {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value.as_str()? {
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaddedBlocks {
+    blocks: Option<Padding>,
+    classes: Option<Padding>,
+    switches: Option<Padding>,
+    allow_single_line_blocks: bool,
+}
+
+impl Default for PaddedBlocks {
+    fn default() -> Self {
+        Self {
+            blocks: Some(Padding::Always),
+            classes: Some(Padding::Always),
+            switches: Some(Padding::Always),
+            allow_single_line_blocks: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow padding within blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some style guides require block statements to start and end with blank lines, while
+    /// others forbid it. Mixing both styles in a codebase makes it harder to read.
+    ///
+    /// ### Options
+    ///
+    /// The first option is either a string (`"always"`, the default, or `"never"`) applied to
+    /// all kinds of blocks, or an object with any of the following keys:
+    /// - `blocks`: block statements, function bodies and static blocks.
+    /// - `classes`: class bodies.
+    /// - `switches`: `switch` statements.
+    ///
+    /// Kinds of blocks missing from the object are not checked.
+    ///
+    /// The second option is an object with `allowSingleLineBlocks` (default `false`), which
+    /// allows blocks that start and end on the same line.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// if (a) {
+    ///     b();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the default `"always"` option:
+    /// ```javascript
+    /// if (a) {
+    ///
+    ///     b();
+    ///
+    /// }
+    /// ```
+    PaddedBlocks,
+    style,
+    fix
+);
+
+impl Rule for PaddedBlocks {
+    fn from_configuration(value: Value) -> Self {
+        let mut rule = Self::default();
+        match value.get(0) {
+            Some(Value::Object(config)) => {
+                rule.blocks = config.get("blocks").and_then(Padding::from_value);
+                rule.classes = config.get("classes").and_then(Padding::from_value);
+                rule.switches = config.get("switches").and_then(Padding::from_value);
+            }
+            Some(value) => {
+                let padding = Padding::from_value(value).unwrap_or(Padding::Always);
+                rule.blocks = Some(padding);
+                rule.classes = Some(padding);
+                rule.switches = Some(padding);
+            }
+            None => {}
+        }
+        rule.allow_single_line_blocks = value
+            .get(1)
+            .and_then(|config| config.get("allowSingleLineBlocks"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (padding, span, search_from) = match node.kind() {
+            AstKind::BlockStatement(block) => (self.blocks, block.span, block.span.start),
+            AstKind::FunctionBody(body) => {
+                // `() => expr`
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+                ) {
+                    return;
+                }
+                (self.blocks, body.span, body.span.start)
+            }
+            // `static {`
+            AstKind::StaticBlock(block) => (self.blocks, block.span, block.span.start + 6),
+            AstKind::ClassBody(body) => (self.classes, body.span, body.span.start),
+            // `switch (discriminant) {`
+            AstKind::SwitchStatement(switch) => {
+                (self.switches, switch.span, switch.discriminant.span().end)
+            }
+            _ => return,
+        };
+        let Some(padding) = padding else {
+            return;
+        };
+        let Some(open_brace) = find_open_brace(search_from, span.end, ctx) else {
+            return;
+        };
+        let close_brace = span.end - 1;
+        let source_text = ctx.source_text();
+
+        if self.allow_single_line_blocks && !has_line_break(source_text, open_brace, close_brace) {
+            return;
+        }
+
+        let (before_first_token, first_token) = first_token_after(open_brace + 1, close_brace, ctx);
+        // Empty block
+        if first_token >= close_brace {
+            return;
+        }
+        let (last_token_end, after_last_token) =
+            last_token_end_before(close_brace, open_brace + 1, ctx);
+
+        let top_padded = is_padded(source_text, before_first_token, first_token);
+        let bottom_padded = is_padded(source_text, last_token_end, after_last_token);
+        let open_brace_span = Span::new(open_brace, open_brace + 1);
+        let close_brace_span = Span::new(close_brace, close_brace + 1);
+
+        match padding {
+            Padding::Always => {
+                if !top_padded {
+                    ctx.diagnostic_with_fix(always_padded_diagnostic(open_brace_span), |fixer| {
+                        fixer.insert_text_before_range(Span::empty(before_first_token), "\n")
+                    });
+                }
+                if !bottom_padded {
+                    ctx.diagnostic_with_fix(always_padded_diagnostic(close_brace_span), |fixer| {
+                        fixer.insert_text_before_range(Span::empty(after_last_token), "\n")
+                    });
+                }
+            }
+            Padding::Never => {
+                if top_padded {
+                    // Remove everything up to the start of the line of the first token, keeping
+                    // its indentation.
+                    let end = line_start(source_text, first_token);
+                    ctx.diagnostic_with_fix(never_padded_diagnostic(open_brace_span), |fixer| {
+                        fixer.replace(Span::new(before_first_token, end), "\n")
+                    });
+                }
+                if bottom_padded {
+                    let end = line_start(source_text, after_last_token);
+                    ctx.diagnostic_with_fix(never_padded_diagnostic(close_brace_span), |fixer| {
+                        fixer.replace(Span::new(last_token_end, end), "\n")
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Find the `{` at or after `start`, skipping comments.
+#[allow(clippy::cast_possible_truncation)]
+fn find_open_brace(start: u32, end: u32, ctx: &LintContext) -> Option<u32> {
+    let source_text = ctx.source_text();
+    let mut pos = start;
+    while pos < end {
+        if let Some(comment) = comment_starting_at(pos, ctx) {
+            pos = comment.end;
+            continue;
+        }
+        let c = source_text[pos as usize..].chars().next()?;
+        if c == '{' {
+            return Some(pos);
+        }
+        pos += c.len_utf8() as u32;
+    }
+    None
+}
+
+/// Start of the first token (including comments) after `start`, along with the end of the token
+/// before it. Comments on the same line as the opening brace are skipped.
+#[allow(clippy::cast_possible_truncation)]
+fn first_token_after(start: u32, end: u32, ctx: &LintContext) -> (u32, u32) {
+    let source_text = ctx.source_text();
+    let mut prev_end = start;
+    loop {
+        let rest = &source_text[prev_end as usize..end as usize];
+        let pos = prev_end + (rest.len() - rest.trim_start().len()) as u32;
+        match comment_starting_at(pos, ctx) {
+            Some(comment) if !has_line_break(source_text, prev_end, pos) => {
+                prev_end = comment.end;
+            }
+            _ => return (prev_end, pos),
+        }
+    }
+}
+
+/// End of the last token (including comments) before `end`, along with the start of the token
+/// after it. Comments on the same line as the closing brace are skipped.
+#[allow(clippy::cast_possible_truncation)]
+fn last_token_end_before(end: u32, start: u32, ctx: &LintContext) -> (u32, u32) {
+    let source_text = ctx.source_text();
+    let mut next_start = end;
+    loop {
+        let pos = start + source_text[start as usize..next_start as usize].trim_end().len() as u32;
+        match comment_ending_at(pos, ctx) {
+            Some(comment) if !has_line_break(source_text, pos, next_start) => {
+                next_start = comment.start;
+            }
+            _ => return (pos, next_start),
+        }
+    }
+}
+
+/// Span of the comment (including its delimiters) starting at `pos`.
+fn comment_starting_at(pos: u32, ctx: &LintContext) -> Option<Span> {
+    ctx.semantic()
+        .trivias()
+        .comments()
+        .find(|comment| comment.real_span_start() == pos)
+        .map(|comment| Span::new(comment.real_span_start(), comment.real_span_end()))
+}
+
+/// Span of the comment (including its delimiters) ending at `pos`.
+fn comment_ending_at(pos: u32, ctx: &LintContext) -> Option<Span> {
+    ctx.semantic()
+        .trivias()
+        .comments()
+        .find(|comment| comment.real_span_end() == pos)
+        .map(|comment| Span::new(comment.real_span_start(), comment.real_span_end()))
+}
+
+fn has_line_break(source_text: &str, start: u32, end: u32) -> bool {
+    source_text[start as usize..end as usize].contains(['\n', '\r', '\u{2028}', '\u{2029}'])
+}
+
+/// Whether the whitespace between `start` and `end` contains a blank line.
+fn is_padded(source_text: &str, start: u32, end: u32) -> bool {
+    let text = &source_text[start as usize..end as usize];
+    text.cow_replace("\r\n", "\n").matches(['\n', '\r', '\u{2028}', '\u{2029}']).count() >= 2
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn line_start(source_text: &str, pos: u32) -> u32 {
+    source_text[..pos as usize]
+        .rfind(['\n', '\r', '\u{2028}', '\u{2029}'])
+        .map_or(0, |i| i as u32 + 1)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("{\n\na();\n\n}", None),
+        ("{\n\n\na();\n\n\n}", None),
+        ("{\n\n//comment\na();\n\n}", None),
+        ("{\n\na();\n//comment\n\n}", None),
+        ("{\n\na()\n//comment\n\n}", None),
+        ("{\n\na = 1\n\n}", None),
+        ("{//comment\n\na();\n\n}", None),
+        ("{ /* comment */\n\na();\n\n/* comment */ }", None),
+        ("{}", None),
+        ("{\n}", None),
+        ("{\n\na();\n\n}", Some(json!(["always"]))),
+        ("switch (a) {\n\ncase 0: foo();\ncase 1: bar();\n\n}", None),
+        ("switch (a) {\n\ncase 0: foo();\n\n}", Some(json!([{ "switches": "always" }]))),
+        ("class A {\n\nconstructor(){}\n\n}", None),
+        ("class A {\n\nconstructor(){}\n\n}", Some(json!([{ "classes": "always" }]))),
+        ("class A {\nconstructor(){}\n}", Some(json!([{ "blocks": "always" }]))),
+        ("class A {\n\nstatic {\n\nfoo();\n\n}\n\n}", None),
+        ("{ a(); }", Some(json!(["always", { "allowSingleLineBlocks": true }]))),
+        ("{a();}", Some(json!(["never"]))),
+        ("{\na();}", Some(json!(["never"]))),
+        ("{a();\n}", Some(json!(["never"]))),
+        ("{\na();\n}", Some(json!(["never"]))),
+        ("{\n//comment\na();\n}", Some(json!(["never"]))),
+        ("{\na();\n//comment\n}", Some(json!(["never"]))),
+        ("switch (a) {\ncase 0: foo();\n}", Some(json!(["never"]))),
+        ("switch (a) {\ncase 0: foo();\n}", Some(json!([{ "switches": "never" }]))),
+        ("switch (a) {\n\ncase 0: foo();\n\n}", Some(json!([{ "blocks": "never" }]))),
+        ("class A {\nconstructor(){\nfoo();\n}\n}", Some(json!(["never"]))),
+        ("class A {\nconstructor(){\n\nfoo();\n\n}\n}", Some(json!([{ "classes": "never" }]))),
+        ("function foo() {\nreturn 1;\n}", Some(json!(["never"]))),
+        ("function foo() {\n\nreturn 1;\n\n}", None),
+        ("class A {\nstatic {\nfoo();\n}\n}", Some(json!(["never"]))),
+    ];
+
+    let fail = vec![
+        ("{\n//comment\na();\n\n}", None),
+        ("{ //comment\na();\n\n}", None),
+        ("{\n\na();\n//comment\n}", None),
+        ("{\n\na()\n//comment\n}", None),
+        ("{\na();\n\n}", None),
+        ("{\n\na();\n}", None),
+        ("{\na();\n}", None),
+        ("{\r\na();\r\n}", None),
+        ("{\na();}", None),
+        ("{a();\n}", None),
+        ("{a();}", None),
+        ("switch (a) {\ncase 0: foo();\ncase 1: bar();\n}", None),
+        (
+            "switch (a) /* comment { */ {\ncase 0: foo();\n}",
+            Some(json!([{ "switches": "always" }])),
+        ),
+        ("class A {\nconstructor(){}\n}", None),
+        ("class A {\nconstructor(){}\n}", Some(json!([{ "classes": "always" }]))),
+        ("function foo() {\nreturn 1;\n}", None),
+        ("class A {\n\nstatic {\nfoo();\n}\n\n}", None),
+        ("{\n\na();\n\n}", Some(json!(["never"]))),
+        ("{\n\n\na();\n\n\n}", Some(json!(["never"]))),
+        ("{\n\n//comment\na();\n}", Some(json!(["never"]))),
+        ("switch (a) {\n\ncase 0: foo();\n\n}", Some(json!(["never"]))),
+        ("switch (a) {\n\ncase 0: foo();\n\n}", Some(json!([{ "switches": "never" }]))),
+        ("class A {\n\nconstructor(){\n\nfoo();\n\n}\n\n}", Some(json!(["never"]))),
+        ("class A {\n\nconstructor(){}\n\n}", Some(json!([{ "classes": "never" }]))),
+        ("function foo() {\n\nreturn 1;\n\n}", Some(json!([{ "blocks": "never" }]))),
+    ];
+
+    let fix = vec![
+        ("{\na();\n}", "{\n\na();\n\n}", None),
+        ("{ /* a */\n\nb();\n\n/* c */ }", "{ /* a */\nb();\n/* c */ }", Some(json!(["never"]))),
+        ("{a();}", "{\na();\n}", None),
+        ("{\r\na();\r\n}", "{\n\r\na();\r\n\n}", None),
+        ("{ //comment\na();\n}", "{ //comment\n\na();\n\n}", None),
+        ("switch (a) {\ncase 0: foo();\n}", "switch (a) {\n\ncase 0: foo();\n\n}", None),
+        ("class A {\nconstructor(){}\n}", "class A {\n\nconstructor(){}\n\n}", None),
+        ("{\n\n    a();\n\n}", "{\n    a();\n}", Some(json!(["never"]))),
+        ("{\n\n\na();\n\n\n}", "{\na();\n}", Some(json!(["never"]))),
+        ("{\n\n  a();\n\n  }", "{\n  a();\n  }", Some(json!(["never"]))),
+        (
+            "switch (a) {\n\ncase 0: foo();\n\n}",
+            "switch (a) {\ncase 0: foo();\n}",
+            Some(json!([{ "switches": "never" }])),
+        ),
+        (
+            "class A {\n\n  constructor(){}\n\n}",
+            "class A {\n  constructor(){}\n}",
+            Some(json!([{ "classes": "never" }])),
+        ),
+    ];
+
+    Tester::new(PaddedBlocks::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
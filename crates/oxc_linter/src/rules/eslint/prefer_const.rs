@@ -0,0 +1,363 @@
+use oxc_ast::{
+    ast::{AssignmentExpression, AssignmentOperator, VariableDeclaration, VariableDeclarationKind},
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    ast_util::{assignment_target_identifiers, get_write_references},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn prefer_const_diagnostic(names: &[&str], spans: Vec<Span>) -> OxcDiagnostic {
+    let quoted = names.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+    let message = match quoted.split_last() {
+        Some((last, [])) => format!("{last} is never reassigned."),
+        Some((last, rest)) => format!("{} and {last} are never reassigned.", rest.join(", ")),
+        None => "Variables are never reassigned.".to_string(),
+    };
+    OxcDiagnostic::warn(format!("{message} Use 'const' instead."))
+        .with_help("Change `let` to `const`.")
+        .with_labels(spans)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst {
+    destructuring: Destructuring,
+}
+
+/// The options object of `prefer-const`.
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
+struct PreferConstConfig {
+    /// How to handle variables declared by destructuring.
+    destructuring: Destructuring,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum Destructuring {
+    /// Report each variable in a destructuring pattern that should be `const`.
+    #[default]
+    Any,
+    /// Only report variables in a destructuring pattern if all of them should be `const`.
+    All,
+}
+
+// doc: https://github.com/eslint/eslint/blob/main/docs/src/rules/prefer-const.md
+// code: https://github.com/eslint/eslint/blob/main/lib/rules/prefer-const.js
+// test: https://github.com/eslint/eslint/blob/main/tests/lib/rules/prefer-const.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `const` declarations for variables that are never reassigned after declared.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a variable is never reassigned, using the `const` declaration is better. `const`
+    /// declaration tells readers, "this variable is never reassigned," reducing cognitive load
+    /// and improving maintainability.
+    ///
+    /// A variable declared without an initializer is reported if it is assigned exactly once,
+    /// by an assignment statement in the same scope as the declaration. This includes
+    /// destructuring assignments like `({ a, b } = obj);`, unless they also assign to a
+    /// property.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "eslint/prefer-const": ["error", { "destructuring": "all" }]
+    /// ```
+    ///
+    /// - `destructuring` (default `"any"`): with `"any"`, every variable in a destructuring
+    ///   pattern that should be `const` is reported. With `"all"`, variables in a destructuring
+    ///   pattern are only reported if all of them should be `const`. This also applies to the
+    ///   variables assigned by a destructuring assignment.
+    ///
+    /// Loop variables of `for-in` and `for-of` loops are reported if the loop body never
+    /// reassigns them, since every iteration gets a new binding. Variables declared in the
+    /// init of a `for (let i = 0; ...)` loop are never reported.
+    ///
+    /// `let` is only replaced with `const` when every variable of the declaration should be
+    /// `const` and is initialized, e.g. `let { a, b } = obj, c = 0; c++;` is reported but not
+    /// fixed.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// export let b = 0;
+    ///
+    /// let { c, d } = obj;
+    ///
+    /// let e;
+    /// e = 0;
+    ///
+    /// for (let f of list) {
+    ///     console.log(f);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const a = 3;
+    /// console.log(a);
+    ///
+    /// let b = 0;
+    /// b++;
+    ///
+    /// let c;
+    /// if (cond) {
+    ///     c = 0;
+    /// }
+    /// ```
+    PreferConst,
+    style,
+    fix
+);
+
+impl Rule for PreferConst {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        // The options object may be passed on its own, or in an array such as
+        // `[{ "destructuring": "all" }]` or `["error", { "destructuring": "all" }]`.
+        let config = match &value {
+            serde_json::Value::Array(values) => values.iter().find(|value| value.is_object()),
+            serde_json::Value::Object(_) => Some(&value),
+            _ => None,
+        }
+        .and_then(|config| serde_json::from_value::<PreferConstConfig>(config.clone()).ok())
+        .unwrap_or_default();
+        Self { destructuring: config.destructuring }
+    }
+
+    fn schema(gen: &mut SchemaGenerator) -> Option<Schema> {
+        Some(gen.subschema_for::<PreferConstConfig>())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else {
+            return;
+        };
+        if decl.kind != VariableDeclarationKind::Let || decl.declare {
+            return;
+        }
+        // Declarations in statement position, including `export let x = 1;`, and `for-in` /
+        // `for-of` loop variables, which get a fresh binding on every iteration. Variables
+        // declared in the init of a C-style `for` loop are shared across iterations.
+        let in_loop_head = match ctx.parent_kind(node) {
+            Some(
+                AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+                | AstKind::TSModuleBlock(_)
+                | AstKind::ExportNamedDeclaration(_),
+            ) => false,
+            Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)) => true,
+            _ => return,
+        };
+
+        // The declaration can only be fixed by replacing `let` if every variable it declares
+        // should be `const` and is initialized.
+        let mut fixable = true;
+        let mut eligible = vec![];
+        for declarator in &decl.declarations {
+            // The loop assigns its variable before each iteration.
+            let has_init = declarator.init.is_some() || in_loop_head;
+            fixable &= has_init;
+
+            let mut bindings = vec![];
+            declarator.id.bound_names(&mut |ident| {
+                bindings.push((ident.name.clone(), ident.span, ident.symbol_id.get()));
+            });
+            let is_pattern = declarator.id.kind.is_destructuring_pattern();
+            let binding_count = bindings.len();
+            let eligible_bindings = bindings
+                .into_iter()
+                .filter(|(_, _, symbol_id)| {
+                    symbol_id.is_some_and(|symbol_id| {
+                        is_const_eligible(symbol_id, has_init, ctx)
+                            && (has_init
+                                || self.destructuring == Destructuring::Any
+                                || are_assigned_together_const_eligible(symbol_id, ctx))
+                    })
+                })
+                .collect::<Vec<_>>();
+            let all_eligible = binding_count == eligible_bindings.len();
+            fixable &= all_eligible;
+            if is_pattern && self.destructuring == Destructuring::All && !all_eligible {
+                continue;
+            }
+            eligible.extend(eligible_bindings);
+        }
+
+        if eligible.is_empty() {
+            return;
+        }
+        // A single diagnostic for the whole declaration, so that destructuring patterns and
+        // declarations with several declarators are neither reported nor fixed more than once.
+        let names = eligible.iter().map(|(name, _, _)| name.as_str()).collect::<Vec<_>>();
+        let spans = eligible.iter().map(|(_, span, _)| *span).collect();
+        let diagnostic = prefer_const_diagnostic(&names, spans);
+        if fixable {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                fixer.replace(let_keyword_span(decl), "const")
+            });
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// Whether the variable `symbol_id` can be declared with `const`.
+///
+/// Initialized variables must never be written to. Variables without an initializer must be
+/// written to exactly once, by an assignment statement in the scope they are declared in, e.g.
+/// `let x; x = 0;`.
+fn is_const_eligible(symbol_id: SymbolId, has_init: bool, ctx: &LintContext) -> bool {
+    let mut writes = get_write_references(symbol_id, ctx);
+    if has_init {
+        return writes.next().is_none();
+    }
+    match (writes.next(), writes.next()) {
+        (Some(write), None) => is_definite_assignment(write, symbol_id, ctx),
+        _ => false,
+    }
+}
+
+/// Whether `reference` is the target of an assignment statement `x = value;` in the scope
+/// the variable is declared in. Destructuring assignments such as `({ x, y } = obj);` count
+/// unless they also assign to a property, e.g. `[x, obj.y] = arr;`.
+fn is_definite_assignment(reference: &Reference, symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let node = ctx.nodes().get_node(reference.node_id());
+    if node.scope_id() != ctx.symbols().get_scope_id(symbol_id) {
+        return false;
+    }
+    assignment_statement(reference, ctx)
+        .is_some_and(|expr| assignment_target_identifiers(&expr.left).is_some())
+}
+
+/// Whether every other variable written to by the destructuring assignment that assigns the
+/// variable `symbol_id` can be declared with `const` as well, e.g. `b` in
+/// `let a, b; ({ a, b } = obj);`.
+fn are_assigned_together_const_eligible(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let Some(expr) = get_write_references(symbol_id, ctx)
+        .next()
+        .and_then(|write| assignment_statement(write, ctx))
+    else {
+        return true;
+    };
+    assignment_target_identifiers(&expr.left).is_some_and(|targets| {
+        targets.iter().all(|ident| {
+            ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                .is_some_and(|target| target == symbol_id || is_const_eligible(target, false, ctx))
+        })
+    })
+}
+
+/// The assignment expression statement `reference` is written to by, with `=` as operator.
+/// `reference` may be the whole target, or part of a destructuring pattern.
+fn assignment_statement<'a>(
+    reference: &Reference,
+    ctx: &LintContext<'a>,
+) -> Option<&'a AssignmentExpression<'a>> {
+    let mut parents = ctx.nodes().iter_parents(reference.node_id()).skip(1).skip_while(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::SimpleAssignmentTarget(_)
+                | AstKind::AssignmentTarget(_)
+                | AstKind::AssignmentTargetPattern(_)
+                | AstKind::ArrayAssignmentTarget(_)
+                | AstKind::ObjectAssignmentTarget(_)
+                | AstKind::AssignmentTargetWithDefault(_)
+        )
+    });
+    let AstKind::AssignmentExpression(expr) = parents.next()?.kind() else {
+        return None;
+    };
+    let statement =
+        parents.find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))?;
+    (expr.operator == AssignmentOperator::Assign
+        && matches!(statement.kind(), AstKind::ExpressionStatement(_)))
+    .then_some(expr)
+}
+
+/// The span of the `let` keyword. For exported declarations this is the span of the inner
+/// declaration, which starts after the `export` keyword.
+fn let_keyword_span(decl: &VariableDeclaration) -> Span {
+    Span::new(decl.span.start, decl.span.start + 3)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    Tester::from_fixture(PreferConst::NAME, "prefer_const.jsonl").test_and_snapshot();
+}
+
+#[test]
+fn test_configuration() {
+    use serde_json::json;
+
+    let destructuring = |value| PreferConst::from_configuration(value).destructuring;
+    assert_eq!(destructuring(json!([{ "destructuring": "all" }])), Destructuring::All);
+    assert_eq!(destructuring(json!(["error", { "destructuring": "all" }])), Destructuring::All);
+    assert_eq!(destructuring(json!({ "destructuring": "all" })), Destructuring::All);
+    assert_eq!(destructuring(json!([{ "destructuring": "any" }])), Destructuring::Any);
+    assert_eq!(destructuring(json!([])), Destructuring::Any);
+    assert_eq!(destructuring(json!(["error"])), Destructuring::Any);
+}
+
+#[test]
+fn test_one_diagnostic_per_declaration() {
+    use std::{env, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::DiagnosticService;
+
+    use crate::{
+        AllowWarnDeny, FixKind, LintFilter, LintService, LintServiceOptions, Linter, OxlintOptions,
+    };
+
+    let filter = LintFilter::new(AllowWarnDeny::Warn, PreferConst::NAME).unwrap();
+    let options = OxlintOptions::default().with_filter(vec![filter]).with_fix(FixKind::SafeFix);
+    let linter = Linter::from_options(options).unwrap();
+    let cwd = env::current_dir().unwrap().into_boxed_path();
+    let paths = vec![Path::new("fixture.js").into()];
+    let service = LintService::from_linter(linter, LintServiceOptions::new(cwd, paths));
+    let allocator = Allocator::default();
+    let diagnostic_service = DiagnosticService::default();
+
+    let source_text = "let { a, b: [c, ...d] } = obj, e = 0;";
+    let messages = service.run_source(&allocator, source_text, false, diagnostic_service.sender());
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].error.to_string(),
+        "'a', 'c', 'd' and 'e' are never reassigned. Use 'const' instead."
+    );
+    assert_eq!(messages[0].error.labels.as_ref().map(Vec::len), Some(4));
+    assert_eq!(messages[0].error.help.as_deref(), Some("Change `let` to `const`."));
+    assert_eq!(messages[0].error.code.to_string(), "eslint(prefer-const)");
+    assert_eq!(
+        messages[0].error.url.as_deref(),
+        Some("https://oxc.rs/docs/guide/usage/linter/rules/eslint/prefer-const.html")
+    );
+    let fix = messages[0].fix.as_ref().unwrap();
+    assert_eq!((fix.span, fix.content.as_ref()), (Span::new(0, 3), "const"));
+}
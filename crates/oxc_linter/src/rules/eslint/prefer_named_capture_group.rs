@@ -0,0 +1,274 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpPattern},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Disjunction, Pattern, Term},
+    FlagsParser, ParserOptions, PatternParser,
+};
+use oxc_semantic::IsGlobalReference;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_named_capture_group_diagnostic(group: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Capture group '{group}' should be converted to a named or non-capturing group."
+    ))
+    .with_help("Named capture groups make it clear what each group matches.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNamedCaptureGroup {
+    check_reg_exp_constructor: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using named capture groups in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Numbered capture groups are referenced by their position, e.g. `match[1]`, which says
+    /// nothing about what the group matches and silently breaks when another group is added
+    /// before it. Named capture groups, e.g. `(?<year>[0-9]{4})`, are self-documenting and can
+    /// be referenced by name.
+    ///
+    /// Naming a group requires picking a name, so this rule only offers suggestions: one that
+    /// inserts a placeholder name, and one that turns the group into a non-capturing group.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "eslint/prefer-named-capture-group": ["error", { "checkRegExpConstructor": true }]
+    /// ```
+    ///
+    /// - `checkRegExpConstructor` (default `false`): also check string literal patterns passed
+    ///   to `RegExp(...)` and `new RegExp(...)`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const foo = /(ba[rz])/;
+    /// const bar = /([0-9]{4})-(\w{5})/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const foo = /(?<id>ba[rz])/;
+    /// const bar = /(?:ba[rz])/;
+    /// const baz = /ba[rz]/;
+    /// const qux = /(?<year>[0-9]{4})-(?=\w{5})/;
+    /// ```
+    PreferNamedCaptureGroup,
+    style,
+    suggestion
+);
+
+impl Rule for PreferNamedCaptureGroup {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let check_reg_exp_constructor = value
+            .get(0)
+            .and_then(|config| config.get("checkRegExpConstructor"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { check_reg_exp_constructor }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => {
+                let RegExpPattern::Pattern(pattern) = &literal.regex.pattern else {
+                    return;
+                };
+                check_pattern(pattern, |span| ctx.source_range(span), true, ctx);
+            }
+            AstKind::NewExpression(expr) if self.check_reg_exp_constructor => {
+                check_reg_exp_constructor(&expr.callee, &expr.arguments, ctx);
+            }
+            AstKind::CallExpression(expr) if self.check_reg_exp_constructor => {
+                check_reg_exp_constructor(&expr.callee, &expr.arguments, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_reg_exp_constructor<'a>(
+    callee: &Expression<'a>,
+    arguments: &[Argument<'a>],
+    ctx: &LintContext<'a>,
+) {
+    if !callee.is_global_reference_name("RegExp", ctx.symbols()) {
+        return;
+    }
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return;
+    };
+
+    let allocator = Allocator::default();
+    let mut options = ParserOptions::default().with_span_offset(pattern.span.start + 1);
+    if let Some(Argument::StringLiteral(flags)) = arguments.get(1) {
+        let Ok(flags) =
+            FlagsParser::new(&allocator, flags.value.as_str(), ParserOptions::default()).parse()
+        else {
+            return;
+        };
+        if flags.unicode_sets {
+            options = options.with_unicode_sets_mode();
+        } else if flags.unicode {
+            options = options.with_unicode_mode();
+        }
+    }
+    let text = pattern.value.as_str();
+    // Invalid patterns are reported by `no-invalid-regexp`.
+    let Ok(parsed) = PatternParser::new(&allocator, text, options).parse() else {
+        return;
+    };
+
+    // Spans only line up with the source code if the string has no escape sequences.
+    let raw = pattern.span.shrink(1).source_text(ctx.source_text());
+    let offset = pattern.span.start + 1;
+    let text_of = |span: Span| &text[(span.start - offset) as usize..(span.end - offset) as usize];
+    check_pattern(&parsed, text_of, raw == text, ctx);
+}
+
+fn check_pattern<'t>(
+    pattern: &Pattern<'_>,
+    text_of: impl Fn(Span) -> &'t str,
+    suggest: bool,
+    ctx: &LintContext<'_>,
+) {
+    let mut groups = Groups::default();
+    groups.visit_disjunction(&pattern.body);
+    if groups.unnamed.is_empty() {
+        return;
+    }
+
+    // At least one of the first `names.len() + 1` candidates is not taken.
+    let temp_name = (1..=groups.names.len() + 1)
+        .map(|i| format!("temp{i}"))
+        .find(|name| !groups.names.contains(name))
+        .unwrap();
+    for span in groups.unnamed {
+        let diagnostic = prefer_named_capture_group_diagnostic(text_of(span), span);
+        if !suggest {
+            ctx.diagnostic(diagnostic);
+            continue;
+        }
+        let after_paren = Span::new(span.start + 1, span.start + 1);
+        ctx.diagnostic_with_suggestions(diagnostic, |fixer| {
+            vec![
+                fixer
+                    .replace(after_paren, format!("?<{temp_name}>"))
+                    .with_message("Convert group to named capture group."),
+                fixer.replace(after_paren, "?:").with_message("Convert group to non-capturing."),
+            ]
+        });
+    }
+}
+
+/// Collects unnamed capturing groups and the names of named ones.
+#[derive(Debug, Default)]
+struct Groups {
+    unnamed: Vec<Span>,
+    names: Vec<String>,
+}
+
+impl Groups {
+    fn visit_disjunction(&mut self, disjunction: &Disjunction<'_>) {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                self.visit_term(term);
+            }
+        }
+    }
+
+    fn visit_term(&mut self, term: &Term<'_>) {
+        match term {
+            Term::LookAroundAssertion(assertion) => self.visit_disjunction(&assertion.body),
+            Term::Quantifier(quantifier) => self.visit_term(&quantifier.body),
+            Term::CapturingGroup(group) => {
+                match &group.name {
+                    Some(name) => self.names.push(name.to_string()),
+                    None => self.unnamed.push(group.span),
+                }
+                self.visit_disjunction(&group.body);
+            }
+            Term::IgnoreGroup(group) => self.visit_disjunction(&group.body),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let check_constructor = || Some(serde_json::json!([{ "checkRegExpConstructor": true }]));
+
+    let pass = vec![
+        ("/normal_regex/", None),
+        ("/(?:[0-9]{4})/", None),
+        ("/(?<year>[0-9]{4})/", None),
+        ("/\\u{1F680}/u", None),
+        ("/(?=a)b/", None),
+        ("/(?!a)b/", None),
+        ("/(?<=a)b/", None),
+        ("/(?<!a)b/", None),
+        ("/(?<a>(?:b))/", None),
+        ("new RegExp('([0-9]{4})')", None),
+        ("RegExp('([0-9]{4})')", None),
+        ("new RegExp()", check_constructor()),
+        ("new RegExp('(?<year>[0-9]{4})-(?<month>[0-9]{2})')", check_constructor()),
+        ("new RegExp('(?:[0-9]{4})')", check_constructor()),
+        ("new RegExp(pattern)", check_constructor()),
+        ("new RegExp('(')", check_constructor()),
+        ("new RegExp('\\\\p{Letter}', 'u')", check_constructor()),
+        ("function foo(RegExp) { new RegExp('(a)') }", check_constructor()),
+        ("new foo.RegExp('(a)')", check_constructor()),
+        ("RegExp.foo('(a)')", check_constructor()),
+    ];
+
+    let fail = vec![
+        ("/([0-9]{4})/", None),
+        ("/([0-9]{4})-(\\w{5})/", None),
+        ("/([0-9]{4})-(5)/", None),
+        ("/(?<temp1>(a))/", None),
+        ("/(?<=(a))b/", None),
+        ("/(?!(a))b/", None),
+        ("/(?:(a))+/", None),
+        ("/(a)|(b)/", None),
+        ("new RegExp('([0-9]{4})')", check_constructor()),
+        ("RegExp('([0-9]{4})')", check_constructor()),
+        ("new RegExp(\"(a)\", 'u')", check_constructor()),
+        ("new RegExp('(\\\\d)')", check_constructor()),
+    ];
+
+    let suggestions = vec![
+        ("/([0-9]{4})/", vec!["/(?<temp1>[0-9]{4})/", "/(?:[0-9]{4})/"], None),
+        (
+            "/(a)-(b)/",
+            vec!["/(?<temp1>a)-(b)/", "/(?:a)-(b)/", "/(a)-(?<temp1>b)/", "/(a)-(?:b)/"],
+            None,
+        ),
+        ("/(?<temp1>(a))/", vec!["/(?<temp1>(?<temp2>a))/", "/(?<temp1>(?:a))/"], None),
+        (
+            "new RegExp('(a)')",
+            vec!["new RegExp('(?<temp1>a)')", "new RegExp('(?:a)')"],
+            check_constructor(),
+        ),
+        // No suggestions when the string contains escapes.
+        ("new RegExp('(\\\\d)')", vec![], check_constructor()),
+    ];
+
+    Tester::new(PreferNamedCaptureGroup::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
+}
@@ -0,0 +1,259 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_object_has_own_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Use 'Object.hasOwn()' instead of 'Object.prototype.hasOwnProperty.call()'.",
+    )
+    .with_label(span)
+}
+
+fn prefer_object_has_own_method_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use 'Object.hasOwn()' instead of calling 'hasOwnProperty' on the object.")
+        .with_help("`hasOwnProperty` can be shadowed by the object, or missing on objects with a null prototype.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferObjectHasOwn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of `Object.prototype.hasOwnProperty.call()` and prefer use of
+    /// `Object.hasOwn()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It is very common to write code like:
+    /// ```javascript
+    /// if (Object.prototype.hasOwnProperty.call(object, "foo")) {
+    ///   console.log("has property foo");
+    /// }
+    /// ```
+    /// This is a common practice because methods on `Object.prototype` can sometimes be
+    /// unavailable or redefined (see the `no-prototype-builtins` rule). Introduced in ES2022,
+    /// `Object.hasOwn()` is a shorter alternative to `Object.prototype.hasOwnProperty.call()`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// Object.prototype.hasOwnProperty.call(obj, "a");
+    /// ({}).hasOwnProperty.call(obj, "a");
+    /// obj.hasOwnProperty("a");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// Object.hasOwn(obj, "a");
+    /// ```
+    PreferObjectHasOwn,
+    style,
+    conditional_fix_suggestion
+);
+
+impl Rule for PreferObjectHasOwn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        if call_expr.optional {
+            return;
+        }
+        let Some(callee) = call_expr.callee.without_parentheses().as_member_expression() else {
+            return;
+        };
+        if callee.optional() {
+            return;
+        }
+
+        match callee.static_property_name() {
+            // `Object.prototype.hasOwnProperty.call(obj, key)`, `{}.hasOwnProperty.call(obj, key)`
+            Some("call") => {
+                let Some(method) = callee.object().without_parentheses().as_member_expression()
+                else {
+                    return;
+                };
+                if method.optional()
+                    || method.static_property_name() != Some("hasOwnProperty")
+                    || !(is_object_prototype(method.object(), ctx)
+                        || is_empty_object(method.object()))
+                {
+                    return;
+                }
+                let diagnostic = prefer_object_has_own_diagnostic(call_expr.span);
+                let Some([object, key]) = plain_arguments::<2>(call_expr) else {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                };
+                // The fix would refer to a local `Object` instead of the global.
+                if ctx.scopes().find_binding(node.scope_id(), "Object").is_some() {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    let mut replacement = has_own_call(object.span(), key.span(), ctx);
+                    // `return{}.hasOwnProperty.call(a, b)` -> `return Object.hasOwn(a, b)`
+                    if ctx.source_text()[..call_expr.span.start as usize]
+                        .ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+                    {
+                        replacement.insert(0, ' ');
+                    }
+                    fixer.replace(call_expr.span, replacement)
+                });
+            }
+            // `obj.hasOwnProperty(key)`
+            Some("hasOwnProperty") => {
+                let object = callee.object();
+                if matches!(object, Expression::Super(_)) || is_object_prototype(object, ctx) {
+                    return;
+                }
+                let Some([key]) = plain_arguments::<1>(call_expr) else {
+                    return;
+                };
+                ctx.diagnostic_with_suggestion(
+                    prefer_object_has_own_method_diagnostic(call_expr.span),
+                    |fixer| {
+                        fixer.replace(call_expr.span, has_own_call(object.span(), key.span(), ctx))
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `Object.prototype`, where `Object` is the global.
+fn is_object_prototype(expr: &Expression, ctx: &LintContext) -> bool {
+    let Some(member) = expr.without_parentheses().as_member_expression() else {
+        return false;
+    };
+    if member.optional() || member.static_property_name() != Some("prototype") {
+        return false;
+    }
+    let Expression::Identifier(ident) = member.object().without_parentheses() else {
+        return false;
+    };
+    ident.is_global_reference_name("Object", ctx.symbols())
+}
+
+/// `{}`
+fn is_empty_object(expr: &Expression) -> bool {
+    matches!(expr.without_parentheses(), Expression::ObjectExpression(object) if object.properties.is_empty())
+}
+
+/// Returns the plain (non-spread) arguments of `call_expr` if there are exactly `N` of them.
+fn plain_arguments<'a, 'b, const N: usize>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<[&'b Expression<'a>; N]> {
+    if call_expr.arguments.len() != N {
+        return None;
+    }
+    let arguments =
+        call_expr.arguments.iter().map(|arg| arg.as_expression()).collect::<Option<Vec<_>>>()?;
+    arguments.try_into().ok()
+}
+
+/// `Object.hasOwn(object, key)`
+fn has_own_call(object: Span, key: Span, ctx: &LintContext) -> String {
+    format!("Object.hasOwn({}, {})", ctx.source_range(object), ctx.source_range(key))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object",
+        "Object(obj, prop)",
+        "Object.hasOwnProperty",
+        "hasOwnProperty(obj, prop)",
+        "foo.hasOwnProperty(obj, prop)",
+        "foo.hasOwnProperty.call(obj, prop)",
+        "Object.prototype.hasOwnProperty",
+        "Object.prototype.hasOwnProperty.call",
+        "Object.prototype.hasOwnProperty.apply(obj, [prop])",
+        "Object.prototype.isPrototypeOf.call(obj, prop)",
+        "Object.prototype[hasOwnProperty].call(obj, prop)",
+        "Object.prototype.hasOwnProperty?.call(obj, prop)",
+        "Object?.prototype.hasOwnProperty.call(obj, prop)",
+        "Foo.prototype.hasOwnProperty.call(obj, prop)",
+        "Object.hasOwn(obj, prop)",
+        "obj?.hasOwnProperty(prop)",
+        "obj.hasOwnProperty?.(prop)",
+        "obj.hasOwnProperty(...args)",
+        "class A extends B { foo() { return super.hasOwnProperty(prop); } }",
+        // `Object` is shadowed by a local variable
+        "const Object = {}; Object.prototype.hasOwnProperty.call(obj, prop)",
+        "function foo(Object) { return Object.prototype.hasOwnProperty.call(obj, prop); }",
+        "let { hasOwnProperty } = Object.prototype; hasOwnProperty.call(obj, prop)",
+        "const hasOwnProperty = Object.prototype.hasOwnProperty; hasOwnProperty.call(obj, prop)",
+        "({ foo }).hasOwnProperty.call(obj, prop)",
+        "({}).hasOwnProperty.apply(obj, [prop])",
+        "({})[hasOwnProperty].call(obj, prop)",
+        "({}).hasOwnProperty?.call(obj, prop)",
+    ];
+
+    let fail = vec![
+        "Object.prototype.hasOwnProperty.call(obj, 'foo')",
+        "Object.prototype.hasOwnProperty.call(obj, property)",
+        "Object.prototype.hasOwnProperty.call(obj)",
+        "Object.prototype.hasOwnProperty.call(obj, ...args)",
+        "Object.prototype['hasOwnProperty'].call(obj, 'foo')",
+        "Object['prototype'].hasOwnProperty['call'](obj, 'foo')",
+        "(Object.prototype.hasOwnProperty).call(obj, 'foo')",
+        "if (Object.prototype.hasOwnProperty.call(obj, key)) {}",
+        "const hasProp = (o, k) => Object.prototype.hasOwnProperty.call(o, k)",
+        "({}).hasOwnProperty.call(obj, 'foo')",
+        "({}.hasOwnProperty.call(obj, 'foo'))",
+        "({})['hasOwnProperty'].call(obj, 'foo')",
+        "const x = {}.hasOwnProperty.call(obj, 'foo')",
+        "function foo() { return{}.hasOwnProperty.call(a, b); }",
+        // still reported, but not fixed
+        "const Object = {}; ({}).hasOwnProperty.call(obj, 'foo')",
+        "obj.hasOwnProperty('foo')",
+        "foo.bar.hasOwnProperty(key)",
+        "this.hasOwnProperty(key)",
+    ];
+
+    let fix = vec![
+        ("Object.prototype.hasOwnProperty.call(obj, 'foo')", "Object.hasOwn(obj, 'foo')", None),
+        ("Object.prototype['hasOwnProperty'].call(a.b, c[d])", "Object.hasOwn(a.b, c[d])", None),
+        (
+            "if (Object.prototype.hasOwnProperty.call(obj, key)) {}",
+            "if (Object.hasOwn(obj, key)) {}",
+            None,
+        ),
+        ("({}).hasOwnProperty.call(obj, 'foo')", "Object.hasOwn(obj, 'foo')", None),
+        ("const x = {}.hasOwnProperty.call(a, b)", "const x = Object.hasOwn(a, b)", None),
+        (
+            "function foo() { return{}.hasOwnProperty.call(a, b); }",
+            "function foo() { return Object.hasOwn(a, b); }",
+            None,
+        ),
+        // not fixable
+        (
+            "const Object = {}; ({}).hasOwnProperty.call(obj, 'foo')",
+            "const Object = {}; ({}).hasOwnProperty.call(obj, 'foo')",
+            None,
+        ),
+        (
+            "Object.prototype.hasOwnProperty.call(obj)",
+            "Object.prototype.hasOwnProperty.call(obj)",
+            None,
+        ),
+        // suggestion
+        ("obj.hasOwnProperty('foo')", "Object.hasOwn(obj, 'foo')", None),
+    ];
+
+    Tester::new(PreferObjectHasOwn::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
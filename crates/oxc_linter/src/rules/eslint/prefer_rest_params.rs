@@ -0,0 +1,121 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+fn prefer_rest_params_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use the rest parameters instead of 'arguments'.")
+        .with_help("Replace `arguments` with a rest parameter, e.g. `function foo(...args) {}`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferRestParams;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require rest parameters instead of `arguments`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// There are rest parameters in ES2015. We can use that feature for variadic functions
+    /// instead of the `arguments` variable. `arguments` does not have methods of
+    /// `Array.prototype`, so it's a bit of an inconvenience.
+    ///
+    /// Accessing a property of `arguments` with dot notation, such as `arguments.length`, is
+    /// allowed.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function foo() {
+    ///   console.log(arguments);
+    /// }
+    ///
+    /// function foo(action) {
+    ///   var args = Array.prototype.slice.call(arguments, 1);
+    ///   action.apply(null, args);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function foo(...args) {
+    ///   console.log(args);
+    /// }
+    ///
+    /// function foo(action, ...args) {
+    ///   action.apply(null, args);
+    /// }
+    ///
+    /// // Note: the implicit arguments can be overwritten.
+    /// function foo(arguments) {
+    ///   console.log(arguments);
+    /// }
+    /// ```
+    PreferRestParams,
+    style
+);
+
+impl Rule for PreferRestParams {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IdentifierReference(ident) = node.kind() else {
+            return;
+        };
+        // A declared `arguments` variable or parameter shadows the implicit one.
+        if !is_global_reference(node, "arguments", ctx) {
+            return;
+        }
+        // `arguments.length` and `arguments.callee` are fine.
+        if let Some(AstKind::MemberExpression(member)) = ctx.nodes().parent_kind(node.id()) {
+            if !member.is_computed() && member.object().span() == ident.span {
+                return;
+            }
+        }
+        // Arrow functions don't have their own `arguments`, so look for an enclosing function.
+        let in_function = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .any(|parent| matches!(parent.kind(), AstKind::Function(_)));
+        if in_function {
+            ctx.diagnostic(prefer_rest_params_diagnostic(ident.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "arguments;",
+        "function foo(arguments) { arguments; }",
+        "function foo() { var arguments; arguments; }",
+        "var foo = () => arguments;",
+        "function foo(...args) { args; }",
+        "function foo() { arguments.length; }",
+        "function foo() { arguments.callee; }",
+        "function foo() { foo.arguments; }",
+        "function foo() { const { arguments } = bar; arguments; }",
+        "function foo() { arguments.length; } function bar(arguments) { arguments; }",
+    ];
+
+    let fail = vec![
+        "function foo() { arguments; }",
+        "function foo() { arguments[0]; }",
+        "function foo() { arguments[1]; }",
+        "function foo() { arguments[Symbol.iterator]; }",
+        "function foo() { Array.prototype.slice.call(arguments, 1); }",
+        "function foo() { return [...arguments]; }",
+        "function foo() { return () => arguments; }",
+        "const foo = function () { bar.apply(this, arguments); };",
+        "class A { foo() { return arguments; } }",
+        "({ foo() { return arguments; } })",
+    ];
+
+    Tester::new(PreferRestParams::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,292 @@
+use oxc_ast::{
+    ast::{BinaryExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_template_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected string concatenation.")
+        .with_help("Use a template literal instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferTemplate;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require template literals instead of string concatenation.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Template literals are easier to read than a chain of `+` operators, and make it obvious
+    /// where values are inserted into a string.
+    ///
+    /// A concatenation is reported if it joins a string or template literal with something
+    /// else. Concatenations of literals only are left to `no-useless-concat`, and additions
+    /// without any string literal, e.g. `a + 1`, are never reported.
+    ///
+    /// The fix keeps the order of evaluation: additions without a string literal, such as
+    /// `a + b` in `a + b + "c"`, become a single placeholder `${a + b}`. It is not offered for
+    /// concatenations with comments, or with string literals using octal escape sequences,
+    /// which are not allowed in template literals.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const str = "Hello, " + name + "!";
+    /// const str = "Time: " + (12 * 60 * 60 * 1000);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const str = "Hello World!";
+    /// const str = `Hello, ${name}!`;
+    /// const str = `Time: ${12 * 60 * 60 * 1000}`;
+    /// const str = "Hello, " + "World!";
+    /// ```
+    PreferTemplate,
+    style,
+    conditional_fix
+);
+
+impl Rule for PreferTemplate {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(binary) = node.kind() else {
+            return;
+        };
+        if binary.operator != BinaryOperator::Addition {
+            return;
+        }
+        // Only the outermost `+` of a chain is reported.
+        let parent = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+        if let Some(AstKind::BinaryExpression(parent)) = parent.map(AstNode::kind) {
+            if parent.operator == BinaryOperator::Addition {
+                return;
+            }
+        }
+        if !has_string_literal(binary) || !has_non_string_literal(binary) {
+            return;
+        }
+
+        ctx.diagnostic_with_fix(prefer_template_diagnostic(binary.span), |fixer| {
+            if ctx.semantic().trivias().has_comments_between(binary.span) {
+                return fixer.noop();
+            }
+            let mut template = String::from("`");
+            if push_template_parts(&mut template, binary, ctx).is_none() {
+                return fixer.noop();
+            }
+            template.push('`');
+            fixer.replace(binary.span, template)
+        });
+    }
+}
+
+fn is_string_literal(expr: &Expression) -> bool {
+    matches!(
+        expr.without_parentheses(),
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_)
+    )
+}
+
+fn as_concatenation<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b BinaryExpression<'a>> {
+    match expr.without_parentheses() {
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            Some(binary)
+        }
+        _ => None,
+    }
+}
+
+/// Whether some operand of the `+` chain `binary` is a string or template literal.
+fn has_string_literal(binary: &BinaryExpression) -> bool {
+    [&binary.left, &binary.right].into_iter().any(|operand| match as_concatenation(operand) {
+        Some(binary) => has_string_literal(binary),
+        None => is_string_literal(operand),
+    })
+}
+
+/// Whether some operand of the `+` chain `binary` is not a string or template literal.
+fn has_non_string_literal(binary: &BinaryExpression) -> bool {
+    [&binary.left, &binary.right].into_iter().any(|operand| match as_concatenation(operand) {
+        Some(binary) => has_non_string_literal(binary),
+        None => !is_string_literal(operand),
+    })
+}
+
+/// Append the contents of a template literal equivalent to `binary` to `template`. Returns
+/// `None` if a string literal cannot be converted.
+fn push_template_parts(
+    template: &mut String,
+    binary: &BinaryExpression,
+    ctx: &LintContext,
+) -> Option<()> {
+    for operand in [&binary.left, &binary.right] {
+        let operand = operand.without_parentheses();
+        match operand {
+            Expression::BinaryExpression(binary)
+                if binary.operator == BinaryOperator::Addition && has_string_literal(binary) =>
+            {
+                push_template_parts(template, binary, ctx)?;
+            }
+            Expression::StringLiteral(literal) => {
+                let raw = ctx.source_range(literal.span);
+                let quote = raw.chars().next()?;
+                push_text(template, &escape_string_contents(&raw[1..raw.len() - 1], quote)?);
+            }
+            Expression::TemplateLiteral(literal) => {
+                let raw = ctx.source_range(literal.span);
+                push_text(template, &raw[1..raw.len() - 1]);
+            }
+            // Additions without a string literal are evaluated before being concatenated.
+            _ => {
+                template.push_str("${");
+                template.push_str(ctx.source_range(operand.span()));
+                template.push('}');
+            }
+        }
+    }
+    Some(())
+}
+
+/// Append `text` to `template`, escaping a `{` that would start a placeholder together with a
+/// `$` at the end of `template`, e.g. for `"$" + "{" + a`.
+fn push_text(template: &mut String, text: &str) {
+    if text.starts_with('{') && ends_with_unescaped(template, '$') {
+        template.push('\\');
+    }
+    template.push_str(text);
+}
+
+fn ends_with_unescaped(text: &str, c: char) -> bool {
+    let Some(rest) = text.strip_suffix(c) else {
+        return false;
+    };
+    (rest.len() - rest.trim_end_matches('\\').len()) % 2 == 0
+}
+
+/// The contents of a string literal quoted with `quote` as contents of a template literal:
+/// backticks and `${` are escaped, and escaped quotes are not. Returns `None` for octal escape
+/// sequences, which are not allowed in template literals.
+fn escape_string_contents(contents: &str, quote: char) -> Option<String> {
+    let mut escaped = String::with_capacity(contents.len());
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '0' if !chars.peek().is_some_and(char::is_ascii_digit) => escaped.push_str("\\0"),
+                '0'..='9' => return None,
+                c if c == quote => escaped.push(c),
+                c => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+            },
+            '`' => escaped.push_str("\\`"),
+            '$' if chars.peek() == Some(&'{') => escaped.push_str("\\$"),
+            c => escaped.push(c),
+        }
+    }
+    Some(escaped)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "'use strict';",
+        "var foo = 'foo' + '\\0';",
+        "var foo = 'bar';",
+        "var foo = 'bar' + 'baz';",
+        "var foo = foo + +'100';",
+        "var foo = `bar`;",
+        "var foo = `hello, ${name}!`;",
+        "var foo = `foo` + `bar` + \"hoge\";",
+        "var foo = `foo` +\n    `bar` +\n    \"hoge\";",
+        "var foo = 1 + 2;",
+        "var foo = a + b + 1;",
+        "var foo = a - 'b';",
+        "var foo = a * 'b' * c;",
+        "var foo = tag`a` + tag`b`;",
+    ];
+
+    let fail = vec![
+        "var foo = 'hello, ' + name + '!';",
+        "var foo = bar + 'baz';",
+        "var foo = bar + `baz`;",
+        "var foo = +100 + 'yen';",
+        "var foo = 'bar' + baz;",
+        "var foo = '￥' + (n * 1000) + '-'",
+        "var foo = 'aaa' + aaa; var bar = 'bbb' + bbb;",
+        "var string = (number + 1) + 'px';",
+        "var foo = 'bar' + baz + 'qux';",
+        "var foo = '0 backslashes: ${bar}' + baz;",
+        "var foo = '1 backslash: \\${bar}' + baz;",
+        "var foo = 'a' + (b + 'c') + d;",
+        "var foo = 1 + 2 + 'a';",
+        "var foo = 'a' + 1 + 2;",
+        "var foo = 'a`b' + c;",
+        "var foo = \"a'b\\\"\" + c;",
+        "var foo = 'a\\'b' + c;",
+        "var foo = '$' + '{' + a;",
+        "var foo = `a${b}` + c + 'd';",
+        "var foo = 'a' + tag`b` + 'c';",
+        "var foo = 'a' /* comment */ + b;",
+        "var foo = '\\1' + b;",
+        "foo('a' + b);",
+        "(a + 'b').length;",
+    ];
+
+    let fix = vec![
+        ("var foo = 'hello, ' + name + '!';", "var foo = `hello, ${name}!`;", None),
+        ("var foo = bar + 'baz';", "var foo = `${bar}baz`;", None),
+        ("var foo = bar + `baz`;", "var foo = `${bar}baz`;", None),
+        ("var foo = +100 + 'yen';", "var foo = `${+100}yen`;", None),
+        ("var foo = '￥' + (n * 1000) + '-'", "var foo = `￥${n * 1000}-`", None),
+        (
+            "var foo = 'aaa' + aaa; var bar = 'bbb' + bbb;",
+            "var foo = `aaa${aaa}`; var bar = `bbb${bbb}`;",
+            None,
+        ),
+        ("var string = (number + 1) + 'px';", "var string = `${number + 1}px`;", None),
+        ("var foo = 'bar' + baz + 'qux';", "var foo = `bar${baz}qux`;", None),
+        (
+            "var foo = '0 backslashes: ${bar}' + baz;",
+            "var foo = `0 backslashes: \\${bar}${baz}`;",
+            None,
+        ),
+        (
+            "var foo = '1 backslash: \\${bar}' + baz;",
+            "var foo = `1 backslash: \\${bar}${baz}`;",
+            None,
+        ),
+        ("var foo = 'a' + (b + 'c') + d;", "var foo = `a${b}c${d}`;", None),
+        ("var foo = 1 + 2 + 'a';", "var foo = `${1 + 2}a`;", None),
+        ("var foo = 'a' + 1 + 2;", "var foo = `a${1}${2}`;", None),
+        ("var foo = 'a`b' + c;", "var foo = `a\\`b${c}`;", None),
+        ("var foo = \"a'b\\\"\" + c;", "var foo = `a'b\"${c}`;", None),
+        ("var foo = 'a\\'b' + c;", "var foo = `a'b${c}`;", None),
+        ("var foo = '$' + '{' + a;", "var foo = `$\\{${a}`;", None),
+        ("var foo = `a${b}` + c + 'd';", "var foo = `a${b}${c}d`;", None),
+        ("var foo = 'a' + tag`b` + 'c';", "var foo = `a${tag`b`}c`;", None),
+        ("var foo = 'a' /* comment */ + b;", "var foo = 'a' /* comment */ + b;", None),
+        ("var foo = '\\1' + b;", "var foo = '\\1' + b;", None),
+        ("foo('a' + b);", "foo(`a${b}`);", None),
+        ("(a + 'b').length;", "(`${a}b`).length;", None),
+    ];
+
+    Tester::new(PreferTemplate::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -50,8 +50,11 @@ declare_oxc_lint!(
     /// // success
     /// var num = parseInt("071", 10);  // 71
     /// ```
+    ///
+    /// In the default `"always"` mode, calls without a radix get a suggestion to add `10`.
     Radix,
-    pedantic
+    pedantic,
+    conditional_suggestion
 );
 
 impl Rule for Radix {
@@ -108,7 +111,15 @@ impl Radix {
             0 => ctx.diagnostic(missing_parameters(call_expr.span)),
             1 => {
                 if matches!(&self.radix_type, RadixType::Always) {
-                    ctx.diagnostic(missing_radix(call_expr.span));
+                    let argument = &call_expr.arguments[0];
+                    ctx.diagnostic_with_suggestion(missing_radix(call_expr.span), |fixer| {
+                        if argument.is_spread() {
+                            return fixer.noop();
+                        }
+                        fixer
+                            .insert_text_after(argument, ", 10")
+                            .with_message("Add radix parameter `10` for parsing decimal numbers.")
+                    });
                 }
             }
             _ => {
@@ -226,5 +237,14 @@ fn test() {
         ("{ let Number; } (Number?.parseInt)();", None),
     ];
 
-    Tester::new(Radix::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"parseInt("10");"#, r#"parseInt("10", 10);"#, None),
+        (r#"parseInt("10",);"#, r#"parseInt("10", 10,);"#, None),
+        (r#"parseInt((0, "10"));"#, r#"parseInt((0, "10"), 10);"#, None),
+        (r#"Number.parseInt("10");"#, r#"Number.parseInt("10", 10);"#, None),
+        (r#"parseInt?.("10");"#, r#"parseInt?.("10", 10);"#, None),
+        ("parseInt(...args);", "parseInt(...args);", None),
+    ];
+
+    Tester::new(Radix::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
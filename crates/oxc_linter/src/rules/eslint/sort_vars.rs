@@ -43,9 +43,16 @@ declare_oxc_lint!(
     /// var a, b, c, d;
     /// var B, a, c;
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `ignoreCase` (default `false`): compare names case-insensitively.
+    ///
+    /// The fixer only reorders declarations whose initializers are all literals, since moving
+    /// any other initializer could change the order of its side effects.
     SortVars,
     pedantic,
-    pending
+    conditional_fix
 );
 
 impl Rule for SortVars {
@@ -68,17 +75,39 @@ impl Rule for SortVars {
             return;
         };
 
-        let mut previous: Option<&VariableDeclarator> = None;
-        for current in var_decl
+        let declarations = var_decl
             .declarations
             .iter()
             .filter(|decl| matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_)))
-        {
+            .collect::<Vec<_>>();
+
+        let mut fixed = false;
+        let mut previous: Option<&VariableDeclarator> = None;
+        for &current in &declarations {
             if let Some(previous) = previous {
                 if self.get_sortable_name(previous).cmp(&self.get_sortable_name(current))
                     == Ordering::Greater
                 {
-                    ctx.diagnostic(sort_vars_diagnostic(current.span));
+                    // all declarations are sorted by the first fix
+                    if fixed {
+                        ctx.diagnostic(sort_vars_diagnostic(current.span));
+                    } else {
+                        fixed = true;
+                        ctx.diagnostic_with_fix(sort_vars_diagnostic(current.span), |fixer| {
+                            if declarations.iter().any(|decl| {
+                                decl.init.as_ref().is_some_and(|init| !init.is_literal())
+                            }) {
+                                return fixer.noop();
+                            }
+                            fixer.replace(
+                                Span::new(
+                                    declarations[0].span.start,
+                                    declarations[declarations.len() - 1].span.end,
+                                ),
+                                self.sorted_text(&declarations, ctx),
+                            )
+                        });
+                    }
                 }
             }
 
@@ -88,6 +117,24 @@ impl Rule for SortVars {
 }
 
 impl SortVars {
+    /// The source text from the first to the last of `declarations`, with the declarations
+    /// sorted and the text between them kept in place.
+    fn sorted_text(&self, declarations: &[&VariableDeclarator], ctx: &LintContext) -> String {
+        let mut sorted = declarations.to_vec();
+        sorted.sort_by(|a, b| self.get_sortable_name(a).cmp(&self.get_sortable_name(b)));
+
+        let mut text = String::new();
+        for (i, decl) in sorted.iter().enumerate() {
+            text.push_str(ctx.source_range(decl.span));
+            if let Some(next) = declarations.get(i + 1) {
+                text.push_str(
+                    ctx.source_range(Span::new(declarations[i].span.end, next.span.start)),
+                );
+            }
+        }
+        text
+    }
+
     fn get_sortable_name<'a>(&self, decl: &VariableDeclarator<'a>) -> Cow<'a, str> {
         let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else {
             unreachable!();
@@ -196,7 +243,7 @@ fn test() {
         ("var c, a = b = 0", None),
     ];
 
-    let _fix = vec![
+    let fix = vec![
         ("var b, a", "var a, b", None),
         ("var b , a", "var a , b", None),
         ("var b=10, a=20;", "var a=20, b=10;", None),
@@ -220,7 +267,13 @@ fn test() {
             Some(serde_json::json!([{ "ignoreCase": true }])),
         ),
         ("var {} = 1, b, a", "var {} = 1, a, b", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("var b=10, a=f();", "var b=10, a=f();", None),
+        ("var b=10, a=b;", "var b=10, a=b;", None),
+        ("var b = 0, a = `${b}`;", "var b = 0, a = `${b}`;", None),
+        ("var b = f(), c, d, a;", "var b = f(), c, d, a;", None),
+        ("var c, a = b = 0", "var c, a = b = 0", None),
+        ("var c, b, a;", "var a, b, c;", None),
     ];
 
-    Tester::new(SortVars::NAME, pass, fail).test_and_snapshot();
+    Tester::new(SortVars::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -12,8 +12,8 @@ use oxc_syntax::{
 
 use crate::{context::LintContext, rule::Rule};
 
-fn no_cycle_diagnostic(span: Span, paths: &str) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Dependency cycle detected")
+fn no_cycle_diagnostic(span: Span, cycle: &str, paths: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Dependency cycle detected: {cycle}"))
         .with_help(format!("These paths form a cycle: \n{paths}"))
         .with_label(span)
 }
@@ -25,8 +25,7 @@ pub struct NoCycle {
     max_depth: u32,
     /// ignore type only imports
     ignore_types: bool,
-    /// ignore external modules
-    #[allow(unused)]
+    /// ignore cycles through external modules
     ignore_external: bool,
     /// Allow cyclic dependency if there is at least one dynamic import in the chain
     #[allow(unused)]
@@ -58,6 +57,18 @@ declare_oxc_lint!(
     ///
     /// It is common to import an `undefined` value that is caused by a cyclic dependency.
     ///
+    /// ### Options
+    ///
+    /// - `maxDepth`: the maximum number of imports to follow from this module. Defaults to
+    ///   infinity.
+    /// - `ignoreTypes`: do not follow imports that only import types.
+    /// - `ignoreExternal`: do not follow imports of external modules, i.e. modules in
+    ///   `node_modules`.
+    ///
+    /// Imports are followed through the module graph built by the linter, whose specifiers
+    /// are resolved with the linter's [`ModuleResolver`](crate::ModuleResolver). Cycles through
+    /// files that are not linted, but can be resolved, are reported as well.
+    ///
     /// ### Example
     /// ```javascript
     /// // dep-b.js
@@ -105,6 +116,7 @@ impl Rule for NoCycle {
 
         let mut stack = Vec::new();
         let ignore_types = self.ignore_types;
+        let ignore_external = self.ignore_external;
         let visitor_result = ModuleGraphVisitorBuilder::default()
             .max_depth(self.max_depth)
             .filter(move |(key, val): (&CompactStr, &Arc<ModuleRecord>), parent: &ModuleRecord| {
@@ -112,14 +124,15 @@ impl Rule for NoCycle {
                 let is_node_module = path
                     .components()
                     .any(|c| matches!(c, Component::Normal(p) if p == OsStr::new("node_modules")));
-                let is_type_import = !ignore_types
+                if ignore_external && is_node_module {
+                    return false;
+                }
+                !ignore_types
                     || !parent
                         .import_entries
                         .iter()
                         .filter(|entry| entry.module_request.name() == key)
-                        .all(|entry| entry.is_type);
-
-                is_node_module || is_type_import
+                        .all(|entry| entry.is_type)
             })
             .event(|event, (key, val), _| match event {
                 ModuleGraphVisitorEvent::Enter => {
@@ -153,7 +166,12 @@ impl Rule for NoCycle {
                 })
                 .collect::<Vec<_>>()
                 .join("\n");
-            ctx.diagnostic(no_cycle_diagnostic(span, &help));
+            let cycle = stack
+                .iter()
+                .map(|(specifier, _)| specifier.as_str())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            ctx.diagnostic(no_cycle_diagnostic(span, &cycle, &help));
         }
     }
 }
@@ -214,6 +232,7 @@ fn test() {
             r#"import { foo } from "./typescript/ts-types-only-importing-multiple-types";"#,
             Some(json!([{"ignoreTypes":true}])),
         ),
+        (r#"import { foo } from "cycle-pkg""#, Some(json!([{"ignoreExternal":true}]))),
         (
             r#"import { foo } from "./typescript/ts-types-depth-two";"#,
             Some(json!([{"ignoreTypes":true}])),
@@ -331,6 +350,8 @@ fn test() {
             r#"import { foo } from "./typescript/ts-types-some-type-imports";"#,
             Some(json!([{"ignoreTypes":true}])),
         ),
+        (r#"import { foo } from "cycle-pkg""#, None),
+        (r#"import { foo } from "cycle-pkg""#, Some(json!([{"ignoreExternal":false}]))),
     ];
 
     Tester::new(NoCycle::NAME, pass, fail)
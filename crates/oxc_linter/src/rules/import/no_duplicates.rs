@@ -1,74 +1,118 @@
+use std::borrow::Cow;
+
 use itertools::Itertools;
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement};
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
-use oxc_syntax::module_record::{ImportImportName, RequestedModule};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{ImportImportName, ModuleRecord, RequestedModule};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, fixer::FixBuilder, rule::Rule};
 
-use crate::{context::LintContext, rule::Rule};
+fn no_duplicates_diagnostic(labels: Vec<LabeledSpan>) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Forbid repeated import of the same module in multiple places")
+        .with_help("Merge the imports into the first import of the module.")
+        .with_labels(labels)
+}
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
 #[derive(Debug, Default, Clone)]
 pub struct NoDuplicates {
     prefer_inline: bool,
+    consider_query_string: bool,
 }
 
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// Reports if a resolved path is imported more than once.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing the same module in several statements makes it harder to see what is imported
+    /// from it. The imports can be merged into one statement.
+    ///
+    /// Type-only imports (`import type`) are checked separately from value imports. Default and
+    /// namespace type imports are checked separately from named type imports, since they cannot
+    /// be combined in one statement.
+    ///
+    /// The fix merges the specifiers of the duplicates into the first import and removes the
+    /// others. It is not applied when a namespace import is involved, when the imports have
+    /// different default imports or import attributes, or when comments next to a removed
+    /// import would be lost or moved.
+    ///
+    /// ### Options
+    ///
+    /// - `preferInline`: When `true`, named type imports are merged into value imports as
+    ///   inline `type` specifiers, e.g. `import { a, type B } from "./foo"`. Defaults to `false`.
+    /// - `considerQueryString`: When `true`, imports of the same path with different query
+    ///   strings, e.g. `./foo?a` and `./foo?b`, are not duplicates. Defaults to `false`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { a } from "./foo";
+    /// import { b } from "./foo";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```typescript
+    /// import { a, b } from "./foo";
+    /// import type { C } from "./foo";
+    /// ```
     NoDuplicates,
-    suspicious
+    suspicious,
+    conditional_fix
 );
 
 impl Rule for NoDuplicates {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0).unwrap_or(&value);
         Self {
-            prefer_inline: value
+            prefer_inline: config
                 .get("preferInline")
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false),
+            consider_query_string: config
+                .get("considerQueryString")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
         }
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.module_record();
 
-        let groups = module_record
+        // Sort by module, then by position, so that the first import of each group is the one
+        // the others are merged into.
+        let requested_modules = module_record
             .requested_modules
             .iter()
-            .map(|(source, requested_modules)| {
-                let resolved_absolute_path = module_record.loaded_modules.get(source).map_or_else(
-                    || source.to_string(),
-                    |module| module.resolved_absolute_path.to_string_lossy().to_string(),
-                );
-                (resolved_absolute_path, requested_modules)
+            .flat_map(|(source, requested_modules)| {
+                let key = self.module_key(source, module_record);
+                requested_modules
+                    .iter()
+                    .map(move |requested_module| (key.clone(), requested_module))
             })
-            .chunk_by(|r| r.0.clone());
-
-        let check_duplicates = |requested_modules: Option<&Vec<&RequestedModule>>| {
-            if let Some(requested_modules) = requested_modules {
-                if requested_modules.len() > 1 {
-                    let labels = requested_modules
-                        .iter()
-                        .map(|requested_module| LabeledSpan::underline(requested_module.span()))
-                        .collect::<Vec<_>>();
-                    ctx.diagnostic(
-                        OxcDiagnostic::warn(
-                            "Forbid repeated import of the same module in multiple places",
-                        )
-                        .with_labels(labels),
-                    );
-                }
-            }
-        };
+            .filter(|(_key, requested_module)| requested_module.is_import())
+            .sorted_by(|(key1, module1), (key2, module2)| {
+                key1.cmp(key2).then_with(|| module1.span().cmp(&module2.span()))
+            })
+            .collect::<Vec<_>>();
+        if requested_modules.len() < 2 {
+            return;
+        }
+
+        let has_type_import = module_record.import_entries.iter().any(|entry| entry.is_type);
+        let import_declarations = import_declarations(ctx);
 
-        for (_path, group) in &groups {
-            let has_type_import = module_record.import_entries.iter().any(|entry| entry.is_type);
+        for (_key, group) in &requested_modules.into_iter().chunk_by(|(key, _)| key.clone()) {
             // When prefer_inline is false, 0 is value, 1 is type named, 2 is type default or type namespace
             // When prefer_inline is true, 0 is value and type named, 2 is type default or type namespace
             let import_entries_maps = group
-                .into_iter()
-                .flat_map(|(_path, requested_modules)| requested_modules)
-                .filter(|requested_module| requested_module.is_import())
+                .map(|(_key, requested_module)| requested_module)
                 .into_group_map_by(|requested_module| {
                     // We should early return if there is no type import
                     if !has_type_import {
@@ -90,10 +134,225 @@ impl Rule for NoDuplicates {
                     0
                 });
 
-            check_duplicates(import_entries_maps.get(&0));
-            check_duplicates(import_entries_maps.get(&1));
-            check_duplicates(import_entries_maps.get(&2));
+            for kind in 0..=2 {
+                if let Some(requested_modules) = import_entries_maps.get(&kind) {
+                    check_duplicates(requested_modules, &import_declarations, ctx);
+                }
+            }
+        }
+    }
+}
+
+impl NoDuplicates {
+    /// The key imports are grouped by: the resolved path if the module was resolved, and the
+    /// specifier otherwise. The query string is only part of the key with `considerQueryString`.
+    fn module_key(&self, source: &str, module_record: &ModuleRecord) -> String {
+        let (path, query) = match source.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (source, None),
+        };
+        let resolved = module_record.loaded_modules.get(source).map_or_else(
+            || path.to_string(),
+            |module| module.resolved_absolute_path.to_string_lossy().to_string(),
+        );
+        match query {
+            Some(query) if self.consider_query_string => format!("{resolved}?{query}"),
+            _ => resolved,
+        }
+    }
+}
+
+/// Top-level import declarations, by the span of their source.
+fn import_declarations<'a>(ctx: &LintContext<'a>) -> FxHashMap<Span, &'a ImportDeclaration<'a>> {
+    let Some(program) = ctx.nodes().root_node().and_then(|root| root.kind().as_program()) else {
+        return FxHashMap::default();
+    };
+    program
+        .body
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Statement::ImportDeclaration(decl) => Some((decl.source.span, &**decl)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_duplicates<'a>(
+    requested_modules: &[&RequestedModule],
+    import_declarations: &FxHashMap<Span, &'a ImportDeclaration<'a>>,
+    ctx: &LintContext<'a>,
+) {
+    if requested_modules.len() < 2 {
+        return;
+    }
+    let labels = requested_modules
+        .iter()
+        .map(|requested_module| LabeledSpan::underline(requested_module.span()))
+        .collect::<Vec<_>>();
+    let diagnostic = no_duplicates_diagnostic(labels);
+
+    let declarations = requested_modules
+        .iter()
+        .map(|requested_module| import_declarations.get(&requested_module.span()).copied())
+        .collect::<Option<Vec<_>>>();
+    let builder = declarations.and_then(|declarations| {
+        let (first, rest) = declarations.split_first()?;
+        merge_imports(first, rest, ctx)
+    });
+    match builder {
+        Some(builder) => ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.build_fix(builder)),
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// Merge the specifiers of the imports `rest` into `first`, and remove them.
+///
+/// Returns `None` if the imports can't be merged safely.
+fn merge_imports<'a>(
+    first: &ImportDeclaration<'a>,
+    rest: &[&ImportDeclaration<'a>],
+    ctx: &LintContext<'a>,
+) -> Option<FixBuilder<'a>> {
+    if first.with_clause.is_some()
+        || rest.iter().any(|decl| decl.with_clause.is_some() || has_problematic_comments(decl, ctx))
+    {
+        return None;
+    }
+
+    let mut default = None;
+    let mut named = vec![];
+    for specifier in first.specifiers.iter().flatten() {
+        match specifier {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                default = Some(specifier.local.name.as_str());
+            }
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                named.push(Cow::Borrowed(ctx.source_range(specifier.span)));
+            }
+            // A namespace import can't be combined with named imports.
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+        }
+    }
+    let first_named_count = named.len();
+    let first_default = default;
+
+    for decl in rest {
+        // A type-only import merged into a value import needs its specifiers marked as `type`.
+        let inline_type = match (first.import_kind.is_type(), decl.import_kind.is_type()) {
+            (true, false) => return None,
+            (false, true) => true,
+            _ => false,
+        };
+        for specifier in decl.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    let name = specifier.local.name.as_str();
+                    match default {
+                        _ if inline_type => return None,
+                        Some(default) if default != name => return None,
+                        Some(_) => {}
+                        None => default = Some(name),
+                    }
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let text = ctx.source_range(specifier.span);
+                    let text =
+                        if inline_type { Cow::Owned(format!("type {text}")) } else { text.into() };
+                    if !named.contains(&text) {
+                        named.push(text);
+                    }
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+            }
+        }
+    }
+
+    let mut builder = FixBuilder::new();
+    let new_default = if first_default.is_none() { default } else { None };
+    let new_named = named[first_named_count..].join(", ");
+    match &first.specifiers {
+        // `import "foo"`
+        None => {
+            let specifiers = match (new_default, new_named.is_empty()) {
+                (Some(default), true) => default.to_string(),
+                (Some(default), false) => format!("{default}, {{{new_named}}}"),
+                (None, false) => format!("{{{new_named}}}"),
+                (None, true) => String::new(),
+            };
+            if !specifiers.is_empty() {
+                builder.insert_before(first.source.span, format!("{specifiers} from "));
+            }
+        }
+        Some(specifiers) => {
+            // The opening brace of `import { ... }` or `import def, { ... }`, if there is one.
+            let open_brace = ctx
+                .source_range(Span::new(first.span.start, first.source.span.start))
+                .find('{')
+                .map(|offset| first.span.start + u32::try_from(offset).unwrap());
+            let last_named = specifiers.iter().rev().find_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier.span),
+                _ => None,
+            });
+            if !new_named.is_empty() {
+                match (last_named, open_brace) {
+                    (Some(last_named), _) => {
+                        builder.insert_after(last_named, format!(", {new_named}"));
+                    }
+                    (None, Some(open_brace)) => {
+                        builder.insert_after(Span::empty(open_brace + 1), new_named);
+                    }
+                    // `import def from "foo"`
+                    (None, None) => {
+                        let default_span = specifiers.first()?.span();
+                        builder.insert_after(default_span, format!(", {{{new_named}}}"));
+                    }
+                }
+            }
+            if let Some(default) = new_default {
+                // `first` has named specifiers or empty braces, since it has no default import.
+                builder.insert_before(Span::empty(open_brace?), format!("{default}, "));
+            }
+        }
+    }
+
+    for decl in rest {
+        builder.remove(removal_span(decl.span, ctx.source_text()));
+    }
+    Some(builder)
+}
+
+/// Whether there are comments in, on the same line as, or on the line right above `decl`,
+/// which would be lost or attached to other code when `decl` is removed.
+#[allow(clippy::cast_possible_truncation)]
+fn has_problematic_comments(decl: &ImportDeclaration, ctx: &LintContext) -> bool {
+    let source_text = ctx.source_text();
+    let line_start = source_text[..decl.span.start as usize].rfind('\n').map_or(0, |i| i + 1);
+    let previous_line_start = line_start
+        .checked_sub(1)
+        .map_or(0, |end| source_text[..end].rfind('\n').map_or(0, |i| i + 1));
+    let line_end = source_text[decl.span.end as usize..]
+        .find('\n')
+        .map_or(source_text.len(), |i| decl.span.end as usize + i);
+    ctx.semantic()
+        .trivias()
+        .comments_range(previous_line_start as u32..line_end as u32)
+        .next()
+        .is_some()
+}
+
+/// The span to remove for an import: its whole line, if it is the only code on it.
+#[allow(clippy::cast_possible_truncation)]
+fn removal_span(span: Span, source_text: &str) -> Span {
+    let before = &source_text[..span.start as usize];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let after = &source_text[span.end as usize..];
+    match after.find('\n') {
+        Some(newline)
+            if before[line_start..].trim().is_empty() && after[..newline].trim().is_empty() =>
+        {
+            Span::new(line_start as u32, span.end + newline as u32 + 1)
         }
+        _ => span,
     }
 }
 
@@ -108,8 +367,10 @@ fn test() {
         (r"import { x } from './foo'; import { y } from './bar'", None),
         (r#"import foo from "234artaf"; import { shoop } from "234q25ad""#, None),
         // r#"import { x } from './foo'; import type { y } from './foo'"#,
-        // TODO: considerQueryString
-        // r#"import x from './bar?optionX'; import y from './bar?optionY';"#,
+        (
+            r"import x from './bar?optionX'; import y from './bar?optionY';",
+            Some(json!([{ "considerQueryString": true }])),
+        ),
         (r"import x from './foo'; import y from './bar';", None),
         // TODO: separate namespace
         // r#"import * as ns from './foo'; import {y} from './foo'"#,
@@ -330,9 +591,81 @@ fn test() {
             r"import {AValue} from './foo'; import type {AType} from './foo'",
             Some(json!({ "preferInline": true })),
         ),
+        (
+            r"import x from './bar.js?optionX'; import y from './bar?optionX';",
+            Some(json!([{ "considerQueryString": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        (r"import { x } from './foo'; import { y } from './foo'", r"import { x, y } from './foo'; ", None),
+        (
+            r"import {x} from './foo'; import {y} from './foo'; import { z } from './foo'",
+            r"import {x, y, z} from './foo';  ",
+            None,
+        ),
+        (r"import {x} from './foo'; import {} from './foo'", r"import {x} from './foo'; ", None),
+        (
+            r"import {a,b} from './foo'; import { c } from './foo'; import {d,e} from './foo'",
+            r"import {a,b, c, d, e} from './foo';  ",
+            None,
+        ),
+        (r"import { } from './foo'; import {x} from './foo'", r"import {x } from './foo'; ", None),
+        (r"import './foo'; import {x} from './foo'", r"import {x} from './foo'; ", None),
+        (r"import'./foo'; import {x} from './foo'", r"import{x} from './foo'; ", None),
+        (r"import './foo'; import def, {x} from './foo'", r"import def, {x} from './foo'; ", None),
+        (r"import def from './foo'; import {x} from './foo'", r"import def, {x} from './foo'; ", None),
+        (r"import {x} from './foo'; import def from './foo'", r"import def, {x} from './foo'; ", None),
+        (
+            r"import {x} from './foo'; import def, {y} from './foo'",
+            r"import def, {x, y} from './foo'; ",
+            None,
+        ),
+        (
+            "import { Foo } from './foo';\n        import { Bar } from './foo';\n        export const value = {}",
+            "import { Foo, Bar } from './foo';\n        export const value = {}",
+            None,
+        ),
+        (
+            "import {x} from './foo'\n        // comment\n\n        import {y} from './foo'",
+            "import {x, y} from './foo'\n        // comment\n\n        ",
+            None,
+        ),
+        (
+            r"import {type x} from './foo'; import type {y} from './foo'",
+            r"import {type x, type y} from './foo'; ",
+            None,
+        ),
+        (
+            r"import {AValue} from './foo'; import type {AType} from './foo'",
+            r"import {AValue, type AType} from './foo'; ",
+            Some(json!({ "preferInline": true })),
+        ),
+        // not fixed
+        (
+            r"import * as ns1 from './foo'; import * as ns2 from './foo'",
+            r"import * as ns1 from './foo'; import * as ns2 from './foo'",
+            None,
+        ),
+        (
+            r"import type x from './foo'; import type y from './foo'",
+            r"import type x from './foo'; import type y from './foo'",
+            None,
+        ),
+        (
+            r"import {a} from './foo'; import { b/*,c*/ } from './foo'",
+            r"import {a} from './foo'; import { b/*,c*/ } from './foo'",
+            None,
+        ),
+        (
+            "import {x} from './foo'\n        // some-tool-disable-next-line\n        import {y} from './foo'",
+            "import {x} from './foo'\n        // some-tool-disable-next-line\n        import {y} from './foo'",
+            None,
+        ),
     ];
 
     Tester::new(NoDuplicates::NAME, pass, fail)
+        .expect_fix(fix)
         .change_rule_path("index.ts")
         .with_import_plugin(true)
         .test_and_snapshot();
@@ -0,0 +1,591 @@
+use std::cmp::Ordering;
+
+use cow_utils::CowUtils;
+use oxc_ast::ast::{ImportDeclaration, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::NODEJS_BUILTINS;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn order_diagnostic(source: &str, before: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{source}` import should occur before import of `{before}`"))
+        .with_label(span)
+}
+
+fn missing_newline_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn newline_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+fn newline_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Rank of each import type, indexed by `ImportType as usize`.
+    ranks: [usize; ImportType::COUNT],
+    alphabetize: Alphabetize,
+    newlines_between: NewlinesBetween,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            ranks: ranks_from_groups(&[
+                vec![ImportType::Builtin],
+                vec![ImportType::External],
+                vec![ImportType::Parent],
+                vec![ImportType::Sibling],
+                vec![ImportType::Index],
+            ]),
+            alphabetize: Alphabetize::default(),
+            newlines_between: NewlinesBetween::default(),
+        }
+    }
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Alphabetize {
+    order: AlphabetizeOrder,
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    Never,
+}
+
+/// What kind of module an import refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    Unknown,
+}
+
+impl ImportType {
+    const COUNT: usize = 7;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Classifies an import source by its path.
+    ///
+    /// This only looks at the specifier itself: relative paths are split into parent, sibling
+    /// and index imports, `@/` and `~/` aliases are treated as internal, and bare specifiers are
+    /// either Node.js builtins or external packages.
+    fn of(source: &str) -> Self {
+        if matches!(source, "." | "./" | "./index" | "./index.js" | "./index.ts") {
+            return Self::Index;
+        }
+        if source == ".." || source.starts_with("../") {
+            return Self::Parent;
+        }
+        if source.starts_with("./") {
+            return Self::Sibling;
+        }
+        if source.starts_with("@/") || source.starts_with("~/") {
+            return Self::Internal;
+        }
+        if source.starts_with('/') || source.is_empty() {
+            return Self::Unknown;
+        }
+        let module = source.strip_prefix("node:").unwrap_or(source);
+        let module = module.split('/').next().unwrap_or(module);
+        if source.starts_with("node:") || NODEJS_BUILTINS.contains(&module) {
+            return Self::Builtin;
+        }
+        Self::External
+    }
+}
+
+/// Types listed in `groups` get the rank of their group; all others share the rank after the
+/// last group.
+fn ranks_from_groups(groups: &[Vec<ImportType>]) -> [usize; ImportType::COUNT] {
+    let mut ranks = [groups.len(); ImportType::COUNT];
+    for (rank, group) in groups.iter().enumerate() {
+        for ty in group {
+            ranks[*ty as usize] = rank;
+        }
+    }
+    ranks
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a convention in the order of `import` statements.
+    ///
+    /// Imports are sorted into groups by the kind of module they refer to: Node.js builtins,
+    /// external packages, internal aliases (`@/` and `~/`), parent directories, siblings and
+    /// the index of the current directory.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order makes it easy to see at a glance what a module depends on.
+    ///
+    /// ### Options
+    ///
+    /// - `groups`: the order of the groups. Each entry is a type name or an array of type names
+    ///   that share a group. Types that are left out are placed after all listed groups.
+    ///   Defaults to `["builtin", "external", "parent", "sibling", "index"]`.
+    /// - `alphabetize`: `{ "order": "ignore" | "asc" | "desc", "caseInsensitive": boolean }`
+    ///   sorts the imports of each group by their source.
+    /// - `newlines-between`: `"ignore"`, `"always"` or `"never"`. Whether import groups must be
+    ///   separated by an empty line. With `"always"` and `"never"`, empty lines inside a group
+    ///   are reported.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import foo from './foo';
+    /// import path from 'path';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import path from 'path';
+    /// import foo from './foo';
+    /// ```
+    Order,
+    style,
+    fix
+);
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = OrderConfig::default();
+        let Some(options) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+
+        if let Some(groups) = options.get("groups").and_then(serde_json::Value::as_array) {
+            let groups = groups
+                .iter()
+                .map(|group| match group {
+                    serde_json::Value::String(name) => {
+                        ImportType::from_name(name).into_iter().collect::<Vec<_>>()
+                    }
+                    serde_json::Value::Array(names) => names
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .filter_map(ImportType::from_name)
+                        .collect(),
+                    _ => vec![],
+                })
+                .filter(|group| !group.is_empty())
+                .collect::<Vec<_>>();
+            config.ranks = ranks_from_groups(&groups);
+        }
+
+        if let Some(alphabetize) = options.get("alphabetize") {
+            config.alphabetize.order =
+                match alphabetize.get("order").and_then(serde_json::Value::as_str) {
+                    Some("asc") => AlphabetizeOrder::Asc,
+                    Some("desc") => AlphabetizeOrder::Desc,
+                    _ => AlphabetizeOrder::Ignore,
+                };
+            config.alphabetize.case_insensitive = alphabetize
+                .get("caseInsensitive")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default();
+        }
+
+        config.newlines_between =
+            match options.get("newlines-between").and_then(serde_json::Value::as_str) {
+                Some("always") => NewlinesBetween::Always,
+                Some("never") => NewlinesBetween::Never,
+                _ => NewlinesBetween::Ignore,
+            };
+
+        Self(Box::new(config))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(program) = ctx.nodes().root_node().and_then(|root| root.kind().as_program())
+        else {
+            return;
+        };
+
+        // Top-level imports, and whether each one directly follows the previous import.
+        let mut imports: Vec<(&ImportDeclaration, bool)> = vec![];
+        let mut previous_is_import = false;
+        for statement in &program.body {
+            if let Statement::ImportDeclaration(decl) = statement {
+                imports.push((decl, previous_is_import));
+                previous_is_import = true;
+            } else {
+                previous_is_import = false;
+            }
+        }
+        if imports.len() < 2 {
+            return;
+        }
+
+        let decls = imports.iter().map(|(decl, _)| *decl).collect::<Vec<_>>();
+        let contiguous = imports.iter().skip(1).all(|(_, adjacent)| *adjacent);
+        let mut fix = contiguous.then(|| self.sorted_imports_fix(&decls, program.span.end, ctx));
+
+        let mut out_of_order = false;
+        for (i, current) in decls.iter().enumerate() {
+            let Some(before) =
+                decls[..i].iter().find(|previous| self.compare(previous, current).is_gt())
+            else {
+                continue;
+            };
+            out_of_order = true;
+            let diagnostic = order_diagnostic(
+                current.source.value.as_str(),
+                before.source.value.as_str(),
+                current.span,
+            );
+            // The whole block is rewritten at once, so the fix goes with the first report.
+            match fix.take().flatten() {
+                Some((span, text)) => {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, text));
+                }
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+
+        if self.newlines_between == NewlinesBetween::Ignore {
+            return;
+        }
+        for (i, (current, adjacent)) in imports.iter().enumerate().skip(1) {
+            if !adjacent {
+                continue;
+            }
+            let previous = imports[i - 1].0;
+            self.check_newlines_between(previous, current, !out_of_order, ctx);
+        }
+    }
+}
+
+impl Order {
+    fn rank(&self, decl: &ImportDeclaration) -> usize {
+        self.ranks[ImportType::of(decl.source.value.as_str()) as usize]
+    }
+
+    fn compare(&self, a: &ImportDeclaration, b: &ImportDeclaration) -> Ordering {
+        self.rank(a).cmp(&self.rank(b)).then_with(|| {
+            let (a, b) = (a.source.value.as_str(), b.source.value.as_str());
+            let ordering = if self.alphabetize.case_insensitive {
+                a.cow_to_lowercase().cmp(&b.cow_to_lowercase())
+            } else {
+                a.cmp(b)
+            };
+            match self.alphabetize.order {
+                AlphabetizeOrder::Ignore => Ordering::Equal,
+                AlphabetizeOrder::Asc => ordering,
+                AlphabetizeOrder::Desc => ordering.reverse(),
+            }
+        })
+    }
+
+    /// Rewrites a block of contiguous imports in sorted order.
+    ///
+    /// Every import moves together with the comments on the lines directly above it and the
+    /// comments on the rest of its line. The whitespace between imports stays where it is.
+    /// Returns `None` if a comment between two imports does not belong to either of them.
+    fn sorted_imports_fix(
+        &self,
+        decls: &[&ImportDeclaration],
+        program_end: u32,
+        ctx: &LintContext,
+    ) -> Option<(Span, String)> {
+        let source_text = ctx.source_text();
+        let trivias = ctx.semantic().trivias();
+
+        let mut chunks = Vec::with_capacity(decls.len());
+        for (i, decl) in decls.iter().enumerate() {
+            let mut start = decl.span.start;
+            // Comments above the first import stay at the top of the file.
+            if i > 0 {
+                let previous_end = decls[i - 1].span.end;
+                let comments = trivias.comments_range(previous_end..decl.span.start);
+                for comment in comments.rev() {
+                    let gap = &source_text[comment.real_span_end() as usize..start as usize];
+                    let before =
+                        &source_text[previous_end as usize..comment.real_span_start() as usize];
+                    if !gap.trim().is_empty()
+                        || gap.matches('\n').count() > 1
+                        || !before.contains('\n')
+                    {
+                        break;
+                    }
+                    start = comment.real_span_start();
+                }
+            }
+
+            let mut end = decl.span.end;
+            let limit = decls.get(i + 1).map_or(program_end, |next| next.span.start);
+            for comment in trivias.comments_range(decl.span.end..limit) {
+                let gap = &source_text[end as usize..comment.real_span_start() as usize];
+                if !gap.trim().is_empty() || gap.contains('\n') {
+                    break;
+                }
+                end = comment.real_span_end();
+            }
+
+            chunks.push((*decl, Span::new(start, end)));
+        }
+
+        let separators = chunks
+            .windows(2)
+            .map(|pair| ctx.source_range(Span::new(pair[0].1.end, pair[1].1.start)))
+            .collect::<Vec<_>>();
+        if separators.iter().any(|separator| !separator.trim().is_empty()) {
+            return None;
+        }
+
+        let span = Span::new(chunks[0].1.start, chunks[chunks.len() - 1].1.end);
+        let mut sorted = chunks.clone();
+        sorted.sort_by(|(a, _), (b, _)| self.compare(a, b));
+
+        let mut text = String::with_capacity(span.size() as usize);
+        for (i, (_, chunk)) in sorted.iter().enumerate() {
+            text.push_str(ctx.source_range(*chunk));
+            if let Some(separator) = separators.get(i) {
+                text.push_str(separator);
+            }
+        }
+        Some((span, text))
+    }
+
+    fn check_newlines_between(
+        &self,
+        previous: &ImportDeclaration,
+        current: &ImportDeclaration,
+        fixable: bool,
+        ctx: &LintContext,
+    ) {
+        let gap = Span::new(previous.span.end, current.span.start);
+        let text = ctx.source_range(gap);
+        let lines = text.split('\n').collect::<Vec<_>>();
+        // The first line is the rest of the previous import's line and the last one is the
+        // indentation of the current import.
+        let interior = lines.get(1..lines.len().saturating_sub(1)).unwrap_or_default();
+        let empty_lines = interior.iter().filter(|line| line.trim().is_empty()).count();
+        let same_group = self.rank(previous) == self.rank(current);
+
+        if same_group || self.newlines_between == NewlinesBetween::Never {
+            if empty_lines == 0 {
+                return;
+            }
+            let diagnostic = if same_group {
+                newline_within_group_diagnostic(previous.span)
+            } else {
+                newline_between_groups_diagnostic(previous.span)
+            };
+            if fixable && text.trim().is_empty() {
+                // Keep the indentation of the current import.
+                let indentation = lines.last().copied().unwrap_or_default();
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(gap, format!("\n{indentation}"))
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+            return;
+        }
+
+        if empty_lines > 0 {
+            return;
+        }
+        let diagnostic = missing_newline_diagnostic(previous.span);
+        match text.find('\n') {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(offset) if fixable => {
+                let position = gap.start + offset as u32;
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.insert_text_before_range(Span::new(position, position), "\n")
+                });
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"
+                import fs from "fs";
+                import foo from "foo";
+                import bar from "../bar";
+                import baz from "./baz";
+                import index from "./";
+            "#,
+            None,
+        ),
+        (r#"import path from "node:path"; import fs from "fs";"#, None),
+        (r#"import fs from "fs"; import { a } from "@scope/pkg"; import b from "b";"#, None),
+        (
+            r#"
+                import a from "./a";
+                const b = 1;
+                import fs from "fs";
+            "#,
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r#"import foo from "foo"; import fs from "fs";"#,
+            Some(json!([{ "groups": [["builtin", "external"]] }])),
+        ),
+        (
+            r#"import a from "./a"; import u from "@/utils";"#,
+            Some(json!([{ "groups": ["sibling"] }])),
+        ),
+        (
+            r#"import a from "a"; import B from "B"; import c from "c";"#,
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r#"import c from "c"; import b from "b"; import a from "./a";"#,
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            "import fs from \"fs\";\n\nimport foo from \"foo\";\nimport bar from \"bar\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from \"fs\";\nimport foo from \"foo\";",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\nimport path from \"path\";",
+            Some(json!([{ "newlines-between": "ignore" }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"import foo from "./foo"; import fs from "fs";"#, None),
+        (r#"import bar from "../bar"; import baz from "./baz"; import fs from "fs";"#, None),
+        (r#"import index from "./"; import bar from "../bar";"#, None),
+        (
+            r#"import b from "b"; import a from "a";"#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r#"import a from "a"; import B from "B";"#,
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": false } }])),
+        ),
+        (r#"import a from "./a"; const b = 1; import fs from "fs";"#, None),
+        (
+            "import fs from \"fs\";\nimport foo from \"foo\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\nimport foo from \"foo\";",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\nimport path from \"path\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        ("import foo from \"foo\";\n// a comment\n\nimport fs from \"fs\";", None),
+    ];
+
+    let fix = vec![
+        (
+            r#"import foo from "./foo"; import fs from "fs";"#,
+            r#"import fs from "fs"; import foo from "./foo";"#,
+            None,
+        ),
+        (
+            "import bar from \"../bar\";\nimport baz from \"./baz\";\nimport fs from \"fs\";",
+            "import fs from \"fs\";\nimport bar from \"../bar\";\nimport baz from \"./baz\";",
+            None,
+        ),
+        (
+            "// header\nimport foo from \"./foo\";\n// file system\nimport fs from \"fs\"; // builtin\n",
+            "// header\n// file system\nimport fs from \"fs\"; // builtin\nimport foo from \"./foo\";\n",
+            None,
+        ),
+        (
+            r#"import b from "b"; import a from "a";"#,
+            r#"import a from "a"; import b from "b";"#,
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r#"import a from "./a"; const b = 1; import fs from "fs";"#,
+            r#"import a from "./a"; const b = 1; import fs from "fs";"#,
+            None,
+        ),
+        (
+            "import foo from \"foo\";\n// a comment\n\nimport fs from \"fs\";",
+            "import foo from \"foo\";\n// a comment\n\nimport fs from \"fs\";",
+            None,
+        ),
+        (
+            "import fs from \"fs\";\nimport foo from \"foo\";",
+            "import fs from \"fs\";\n\nimport foo from \"foo\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\nimport foo from \"foo\";",
+            "import fs from \"fs\";\nimport foo from \"foo\";",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import fs from \"fs\";\n\n  import path from \"path\";",
+            "import fs from \"fs\";\n  import path from \"path\";",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
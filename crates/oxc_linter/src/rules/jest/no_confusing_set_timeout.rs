@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cow_utils::CowUtils;
 use oxc_ast::{ast::MemberExpression, AstKind};
@@ -87,6 +87,7 @@ impl Rule for NoConfusingSetTimeout {
 
         let mut jest_reference_id_list: Vec<(ReferenceId, Span)> = vec![];
         let mut seen_jest_set_timeout = false;
+        let mut unordered_jest_references = HashSet::new();
 
         for reference_ids in scopes.root_unresolved_references_ids() {
             collect_jest_reference_id(reference_ids, &mut jest_reference_id_list, ctx);
@@ -106,6 +107,7 @@ impl Rule for NoConfusingSetTimeout {
                 reference_id_list,
                 &jest_reference_id_list,
                 &mut seen_jest_set_timeout,
+                &mut unordered_jest_references,
                 &id_to_jest_node_map,
             );
         }
@@ -116,6 +118,7 @@ impl Rule for NoConfusingSetTimeout {
                 reference_id_list.iter().copied(),
                 &jest_reference_id_list,
                 &mut seen_jest_set_timeout,
+                &mut unordered_jest_references,
                 &id_to_jest_node_map,
             );
         }
@@ -151,6 +154,7 @@ fn handle_jest_set_time_out<'a>(
     reference_id_list: impl Iterator<Item = ReferenceId>,
     jest_reference_id_list: &Vec<(ReferenceId, Span)>,
     seen_jest_set_timeout: &mut bool,
+    unordered_jest_references: &mut HashSet<ReferenceId>,
     id_to_jest_node_map: &HashMap<AstNodeId, &PossibleJestNode<'a, '_>>,
 ) {
     let nodes = ctx.nodes();
@@ -167,7 +171,10 @@ fn handle_jest_set_time_out<'a>(
         if !is_jest_call(ctx.semantic().reference_name(reference)) {
             if is_jest_fn_call(parent_node, id_to_jest_node_map, ctx) {
                 for (jest_reference_id, span) in jest_reference_id_list {
-                    if jest_reference_id > &reference_id {
+                    // Report each misplaced reference once, not once per earlier jest call.
+                    if jest_reference_id > &reference_id
+                        && unordered_jest_references.insert(*jest_reference_id)
+                    {
                         ctx.diagnostic(no_unorder_set_timeout_diagnostic(*span));
                     }
                 }
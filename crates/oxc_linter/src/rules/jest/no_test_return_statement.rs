@@ -73,21 +73,10 @@ fn check_call_expression<'a>(
         return;
     }
 
+    // Function expressions are checked when visiting the function itself.
     for argument in &call_expr.arguments {
-        let Some(arg_expr) = argument.as_expression() else {
-            continue;
-        };
-        match arg_expr {
-            Expression::ArrowFunctionExpression(arrow_expr) => {
-                check_test_return_statement(&arrow_expr.body, ctx);
-            }
-            Expression::FunctionExpression(func_expr) => {
-                let Some(func_body) = &func_expr.body else {
-                    continue;
-                };
-                check_test_return_statement(func_body, ctx);
-            }
-            _ => continue,
+        if let Some(Expression::ArrowFunctionExpression(arrow_expr)) = argument.as_expression() {
+            check_test_return_statement(&arrow_expr.body, ctx);
         }
     }
 }
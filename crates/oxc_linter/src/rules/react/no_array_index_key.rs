@@ -0,0 +1,227 @@
+use oxc_ast::{
+    ast::{
+        Argument, CallExpression, Expression, FormalParameters, JSXAttributeItem, JSXAttributeValue,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+
+fn no_array_index_key_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Do not use Array index in keys")
+        .with_help(
+            "Use a stable, unique identifier of the item instead of its position in the array.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayIndexKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Warn if an element uses an Array index in its `key`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It's a bad idea to use the array index since it doesn't uniquely identify your elements.
+    /// In cases where the array is sorted or an element is added to the beginning of the array,
+    /// the index will be changed even though the element representing that index may be the
+    /// same. This results in unnecessary renders and subtle bugs with component state.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// things.map((thing, index) => <Hello key={index} />);
+    /// things.forEach((thing, index) => { otherThings.push(<Hello key={`thing-${index}`} />) });
+    /// things.reduce((acc, thing, index) => [...acc, <Hello key={index + 1} />], []);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// things.map((thing) => <Hello key={thing.id} />);
+    /// ```
+    NoArrayIndexKey,
+    perf
+);
+
+impl Rule for NoArrayIndexKey {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() else {
+            return;
+        };
+        if !attr.name.as_identifier().is_some_and(|ident| ident.name == "key") {
+            return;
+        }
+        let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+            return;
+        };
+        let Some(expr) = container.expression.as_expression() else {
+            return;
+        };
+
+        if uses_array_index(expr, ctx) {
+            ctx.diagnostic(no_array_index_key_diagnostic(container.span));
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Whether `expr` is the index parameter of an iteration callback, either directly
+/// (`index`), in a template literal (`` `foo-${index}` ``), in an arithmetic expression
+/// (`index + 1`) or stringified (`index.toString()`, `String(index)`).
+fn uses_array_index<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => get_declaration_of_variable(ident, ctx)
+            .is_some_and(|declaration| is_array_index_param(declaration, ctx)),
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().any(|expr| uses_array_index(expr, ctx))
+        }
+        Expression::BinaryExpression(binary) => {
+            uses_array_index(&binary.left, ctx) || uses_array_index(&binary.right, ctx)
+        }
+        Expression::CallExpression(call) => is_stringified_index(call, ctx),
+        _ => false,
+    }
+}
+
+/// `index.toString()` or `String(index)`
+fn is_stringified_index<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>) -> bool {
+    if let Some(member) = call.callee.as_member_expression() {
+        return call.arguments.is_empty()
+            && member.static_property_name() == Some("toString")
+            && uses_array_index(member.object(), ctx);
+    }
+    call.callee.is_specific_id("String")
+        && call.arguments.len() == 1
+        && call.arguments[0].as_expression().is_some_and(|arg| uses_array_index(arg, ctx))
+}
+
+/// Whether `declaration` is the index parameter of a callback passed to an array iteration
+/// method, e.g. `index` in `things.map((thing, index) => ...)`.
+fn is_array_index_param<'a>(declaration: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let AstKind::FormalParameter(param) = declaration.kind() else {
+        return false;
+    };
+    let mut parents = ctx.nodes().iter_parents(declaration.id()).skip(1);
+    let Some(AstKind::FormalParameters(params)) = parents.next().map(AstNode::kind) else {
+        return false;
+    };
+    let Some(callback) = parents.next() else {
+        return false;
+    };
+    if !matches!(callback.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return false;
+    }
+    let Some(call) = parents
+        .find(|node| {
+            !matches!(node.kind(), AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+        })
+        .and_then(|node| match node.kind() {
+            AstKind::CallExpression(call) => Some(call),
+            _ => None,
+        })
+    else {
+        return false;
+    };
+
+    let Some((callback_position, index_position)) = iteration_method_positions(call) else {
+        return false;
+    };
+    let is_callback = call.arguments.get(callback_position).is_some_and(|arg| {
+        matches!(arg, Argument::FunctionExpression(_) | Argument::ArrowFunctionExpression(_))
+            && arg.span() == callback.kind().span()
+    });
+    is_callback && param_position(params, param.span) == Some(index_position)
+}
+
+/// Returns the position of the callback argument and of the index parameter of that callback
+/// if `call` is an array iteration method.
+fn iteration_method_positions(call: &CallExpression) -> Option<(usize, usize)> {
+    let member = call.callee.get_inner_expression().as_member_expression()?;
+    let method = member.static_property_name()?;
+
+    // `Children.map(children, (child, index) => ...)`, `React.Children.map(...)`
+    let object = member.object().get_inner_expression();
+    let is_children =
+        object.is_specific_id("Children") || object.is_specific_member_access("React", "Children");
+    if is_children {
+        return matches!(method, "map" | "forEach").then_some((1, 1));
+    }
+
+    match method {
+        "every" | "filter" | "find" | "findIndex" | "findLast" | "findLastIndex" | "flatMap"
+        | "forEach" | "map" | "some" => Some((0, 1)),
+        "reduce" | "reduceRight" => Some((0, 2)),
+        _ => None,
+    }
+}
+
+fn param_position(params: &FormalParameters, span: Span) -> Option<usize> {
+    params.items.iter().position(|item| item.span == span)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<Foo key=\"foo\" />;",
+        "<Foo key={i} />;",
+        "<Foo key />;",
+        "<Foo key={`foo-${i}`} />;",
+        "<Foo key={'foo-' + i} />;",
+        "foo.bar((baz, i) => <Foo key={i} />)",
+        "foo.bar((bar, i) => <Foo key={`foo-${i}`} />)",
+        "foo.bar((bar, i) => <Foo key={'foo-' + i} />)",
+        "foo.map((baz) => <Foo key={baz.id} />)",
+        "foo.map((baz, i) => <Foo key={baz.id} />)",
+        "foo.map((baz, i) => <Foo key={'foo' + baz.id} />)",
+        "foo.map((baz, i) => <Foo key={`foo${baz.id}`} />)",
+        "foo.map((item, i) => <Foo key={item.getIndex(i)} />)",
+        "foo.map((item) => { const i = 0; return <Foo key={i} />; })",
+        "foo.map((item, i) => bar.map((i) => <Foo key={i} />))",
+        "foo.reduce((a, b) => a.concat(<Foo key={b.id} />), [])",
+        "foo.reduce((a, b, i) => a.concat(<Foo key={b} />), [])",
+        "foo.map(function(item, i) { return <Foo key={item} />; })",
+        "foo.map((item, i) => <Foo data-index={i} />)",
+        "foo.map(bar, (item, i) => <Foo key={i} />)",
+    ];
+
+    let fail = vec![
+        "foo.map((bar, i) => <Foo key={i} />)",
+        "[{}, {}].map((bar, i) => <Foo key={i} />)",
+        "foo.map((bar, anything) => <Foo key={anything} />)",
+        "foo.map((bar, i) => <Foo key={`foo-${i}`} />)",
+        "foo.map((bar, i) => <Foo key={'foo-' + i} />)",
+        "foo.map((bar, i) => <Foo key={'foo-' + i + '-bar'} />)",
+        "foo.map((bar, i) => <Foo key={i + 1} />)",
+        "foo.map((bar, i) => <Foo key={i.toString()} />)",
+        "foo.map((bar, i) => <Foo key={String(i)} />)",
+        "foo.map(function(bar, i) { return <Foo key={i} />; })",
+        "foo.map((bar, i) => { const el = <Foo key={i} />; return el; })",
+        "foo?.map((bar, i) => <Foo key={i} />)",
+        "foo.forEach((bar, i) => { baz.push(<Foo key={i} />); })",
+        "foo.filter((bar, i) => <Foo key={i} />)",
+        "foo.some((bar, i) => <Foo key={i} />)",
+        "foo.every((bar, i) => <Foo key={i} />)",
+        "foo.find((bar, i) => <Foo key={i} />)",
+        "foo.findIndex((bar, i) => <Foo key={i} />)",
+        "foo.flatMap((bar, i) => <Foo key={i} />)",
+        "foo.reduce((a, b, i) => a.concat(<Foo key={i} />), [])",
+        "foo.reduceRight((a, b, i) => a.concat(<Foo key={i} />), [])",
+        "Children.map(this.props.children, (child, index) => <Foo key={index} />)",
+        "React.Children.map(this.props.children, (child, index) => <Foo key={index} />)",
+        "Children.forEach(this.props.children, (child, index) => { a.push(<Foo key={index} />); })",
+    ];
+
+    Tester::new(NoArrayIndexKey::NAME, pass, fail).test_and_snapshot();
+}
@@ -10,6 +10,7 @@ use oxc_cfg::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNodeId, AstNodes};
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::AssignmentOperator;
 
 use crate::{
@@ -35,19 +36,31 @@ mod diagnostics {
         .with_error_code_scope(SCOPE)
     }
 
-    pub(super) fn conditional_hook(span: Span, hook_name: &str) -> OxcDiagnostic {
+    /// `structure` is the innermost conditional around the hook call, e.g. "an `if` statement".
+    /// Without one, the hook is called after an early `return`, `throw` or `break`.
+    pub(super) fn conditional_hook(
+        span: Span,
+        hook_name: &str,
+        structure: Option<&str>,
+    ) -> OxcDiagnostic {
+        let location = match structure {
+            Some(structure) => format!(" inside {structure}"),
+            None => ", possibly after an early return".to_string(),
+        };
         OxcDiagnostic::warn(format!(
-            "React Hook {hook_name:?} is called conditionally. React Hooks must be \
+            "React Hook {hook_name:?} is called conditionally{location}. React Hooks must be \
             called in the exact same order in every component render."
         ))
         .with_label(span)
         .with_error_code_scope(SCOPE)
     }
 
-    pub(super) fn loop_hook(span: Span, hook_name: &str) -> OxcDiagnostic {
+    /// `structure` is the innermost loop around the hook call, e.g. "a `for` loop".
+    pub(super) fn loop_hook(span: Span, hook_name: &str, structure: Option<&str>) -> OxcDiagnostic {
+        let structure = structure.unwrap_or("a loop");
         OxcDiagnostic::warn(format!(
             "React Hook {hook_name:?} may be executed more than once. Possibly \
-            because it is called in a loop. React Hooks must be called in the \
+            because it is called in {structure}. React Hooks must be called in the \
             exact same order in every component render."
         ))
         .with_label(span)
@@ -242,12 +255,14 @@ impl Rule for RulesOfHooks {
 
         // Is this node cyclic?
         if cfg.is_cyclic(node_cfg_id) {
-            return ctx.diagnostic(diagnostics::loop_hook(span, hook_name));
+            let structure = enclosing_control_structure(nodes, node, parent_func, true);
+            return ctx.diagnostic(diagnostics::loop_hook(span, hook_name, structure));
         }
 
         if has_conditional_path_accept_throw(cfg, parent_func, node) {
+            let structure = enclosing_control_structure(nodes, node, parent_func, false);
             #[allow(clippy::needless_return)]
-            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name));
+            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name, structure));
         }
     }
 }
@@ -323,6 +338,49 @@ fn has_conditional_path_accept_throw(
     })
 }
 
+/// Describes the innermost loop (if `is_loop`) or conditional around the hook call `node`,
+/// inside of `func`. Parts that are always evaluated, such as the test of an `if` statement,
+/// are not inside of the structure.
+fn enclosing_control_structure(
+    nodes: &AstNodes,
+    node: &AstNode,
+    func: &AstNode,
+    is_loop: bool,
+) -> Option<&'static str> {
+    let span = node.kind().span();
+    let outside = |part: Span| !part.contains_inclusive(span);
+    nodes.ancestors(node.id()).take_while(|id| *id != func.id()).find_map(|id| {
+        let structure = match nodes.kind(id) {
+            AstKind::ForStatement(stmt)
+                if stmt.init.as_ref().map_or(true, |init| outside(init.span())) =>
+            {
+                (true, "a `for` loop")
+            }
+            AstKind::ForInStatement(stmt) if outside(stmt.right.span()) => {
+                (true, "a `for...in` loop")
+            }
+            AstKind::ForOfStatement(stmt) if outside(stmt.right.span()) => {
+                (true, "a `for...of` loop")
+            }
+            AstKind::WhileStatement(_) => (true, "a `while` loop"),
+            AstKind::DoWhileStatement(_) => (true, "a `do...while` loop"),
+            AstKind::IfStatement(stmt) if outside(stmt.test.span()) => (false, "an `if` statement"),
+            AstKind::ConditionalExpression(expr) if outside(expr.test.span()) => {
+                (false, "a conditional expression")
+            }
+            AstKind::LogicalExpression(expr) if outside(expr.left.span()) => {
+                (false, "a logical expression")
+            }
+            AstKind::SwitchStatement(stmt) if outside(stmt.discriminant.span()) => {
+                (false, "a `switch` statement")
+            }
+            AstKind::TryStatement(_) => (false, "a `try` statement"),
+            _ => return None,
+        };
+        (structure.0 == is_loop).then_some(structure.1)
+    })
+}
+
 fn parent_func<'a>(nodes: &'a AstNodes<'a>, node: &AstNode) -> Option<&'a AstNode<'a>> {
     nodes.ancestors(node.id()).map(|id| nodes.get_node(id)).find(|it| it.kind().is_function_like())
 }
@@ -1514,6 +1572,22 @@ fn test() {
         //         });
         //     }
         // " ,
+        // The message names the loop or conditional around the hook.
+        "
+            function useHook() {
+                for (const item of items) {
+                    useState(item);
+                }
+            }
+        ",
+        "
+            function useHook() {
+                switch (a) {
+                    case 1:
+                        useState();
+                }
+            }
+        ",
     ];
 
     Tester::new(RulesOfHooks::NAME, pass, fail).test_and_snapshot();
@@ -19,6 +19,13 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Using the `Array` type directly is not idiomatic. Instead, use the array type `T[]` or `Array<T>`.
     ///
+    /// ### Options
+    /// - `default` (default `"array"`): the style for mutable arrays, one of `"array"` (`T[]`),
+    ///   `"generic"` (`Array<T>`) or `"array-simple"` (`T[]` for simple element types, `Array<T>`
+    ///   for others such as unions and function types).
+    /// - `readonly`: the style for readonly arrays, `readonly T[]` or `ReadonlyArray<T>`. Defaults
+    ///   to the value of `default`.
+    ///
     /// ### Example
     /// ```typescript
     /// const arr: Array<number> = new Array<number>();
@@ -182,9 +189,10 @@ fn type_needs_parentheses(type_param: &TSType) -> bool {
             if let TSTypeName::IdentifierReference(identifier_reference) = &node.type_name {
                 return identifier_reference.name.as_str() == "ReadonlyArray";
             }
-            true
+            false
         }
         TSType::TSUnionType(_)
+        | TSType::TSConditionalType(_)
         | TSType::TSFunctionType(_)
         | TSType::TSIntersectionType(_)
         | TSType::TSTypeOperatorType(_)
@@ -276,7 +284,8 @@ fn check_and_report_error_array(
     if type_params.as_ref().unwrap().params.len() != 1 {
         return;
     }
-    let first_type_param = type_params.as_ref().unwrap().params.first().unwrap();
+    let first_type_param =
+        type_params.as_ref().unwrap().params.first().unwrap().without_parenthesized();
     if matches!(config, ArrayOption::ArraySimple) && !is_simple_type(first_type_param) {
         return;
     }
@@ -352,13 +361,7 @@ fn is_simple_type(ts_type: &TSType) -> bool {
                     );
                 }
             } else {
-                if node.type_parameters.is_some() {
-                    return false;
-                }
-                if let TSTypeName::IdentifierReference(_) = &node.type_name {
-                    return true;
-                }
-                return false;
+                return node.type_parameters.is_none();
             }
             false
         }
@@ -1476,7 +1479,11 @@ fn test() {
             "type barIntersection = Array<string & number>;",
             Some(serde_json::json!([{"default":"array-simple"}])),
         ),
-        // ("let v: Array<fooName.BarType> = [{ bar: 'bar' }];", "let v: fooName.BarType[] = [{ bar: 'bar' }];", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+            "let v: fooName.BarType[] = [{ bar: 'bar' }];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         // ("let w: fooName.BazType<string>[] = [['baz']];", "let w: Array<fooName.BazType<string>> = [['baz']];", Some(serde_json::json!([{"default":"array-simple"}]))),
         (
             "let x: Array<undefined> = [undefined] as undefined[];",
@@ -1639,6 +1646,26 @@ fn test() {
             "const foo: readonly (new (...args: any[]) => void)[] = [];",
             Some(serde_json::json!([{"default":"array"}])),
         ),
+        (
+            "type Foo<T> = Array<T extends string ? 'a' : 'b'>;",
+            "type Foo<T> = (T extends string ? 'a' : 'b')[];",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
+        (
+            "let a: Array<(string | number)> = [];",
+            "let a: (string | number)[] = [];",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
+        (
+            "let a: ReadonlyArray<(string)> = [];",
+            "let a: readonly string[] = [];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
+        (
+            "let a: Array<Foo.Bar> = [];",
+            "let a: Foo.Bar[] = [];",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
     ];
 
     Tester::new(ArrayType::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
@@ -165,8 +165,13 @@ impl Rule for ConsistentTypeDefinitions {
                 _ => {}
             },
 
+            // `export default interface` is fixed with its export declaration above.
             AstKind::TSInterfaceDeclaration(decl)
-                if self.config == ConsistentTypeDefinitionsConfig::Type =>
+                if self.config == ConsistentTypeDefinitionsConfig::Type
+                    && !matches!(
+                        ctx.nodes().parent_kind(node.id()),
+                        Some(AstKind::ExportDefaultDeclaration(_))
+                    ) =>
             {
                 let start = if decl.declare { decl.span.start + 8 } else { decl.span.start };
 
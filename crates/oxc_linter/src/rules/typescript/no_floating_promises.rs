@@ -0,0 +1,277 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression, UnaryOperator},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+
+fn no_floating_promises_diagnostic(span: Span, ignore_void: bool) -> OxcDiagnostic {
+    let help = if ignore_void {
+        "Await the promise, return it, handle its rejection with `.catch()`, or mark it as intentionally not awaited with the `void` operator."
+    } else {
+        "Await the promise, return it, or handle its rejection with `.catch()`."
+    };
+    OxcDiagnostic::warn("Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.")
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromises {
+    ignore_void: bool,
+    ignore_iife: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true, ignore_iife: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Promise-like statements to be handled appropriately.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A "floating" Promise is one that is created without any code set up to handle any errors
+    /// it might throw. Floating Promises can cause several issues, such as improperly sequenced
+    /// operations, ignored Promise rejections, and more.
+    ///
+    /// This rule does not have access to type information. It recognizes Promises syntactically:
+    /// calls to `async` functions declared in the same file, `new Promise(...)`, `Promise`
+    /// static methods such as `Promise.all(...)`, and `.then()`, `.catch()` and `.finally()`
+    /// chains.
+    ///
+    /// ### Options
+    ///
+    /// - `ignoreVoid` (default `true`): allow marking a Promise as intentionally not awaited
+    ///   with the `void` operator.
+    /// - `ignoreIIFE` (default `false`): ignore immediately invoked `async` functions.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// returnsPromise().then(() => {});
+    ///
+    /// Promise.reject('value').catch();
+    ///
+    /// Promise.reject('value').finally();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// await promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// void returnsPromise();
+    ///
+    /// returnsPromise().then(
+    ///   () => {},
+    ///   () => {},
+    /// );
+    ///
+    /// Promise.reject('value').catch(() => {});
+    /// ```
+    NoFloatingPromises,
+    suspicious
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: Value) -> Self {
+        let default = Self::default();
+        let config = value.get(0);
+        let get_bool =
+            |key: &str| config.and_then(|config| config.get(key)).and_then(Value::as_bool);
+        Self {
+            ignore_void: get_bool("ignoreVoid").unwrap_or(default.ignore_void),
+            ignore_iife: get_bool("ignoreIIFE").unwrap_or(default.ignore_iife),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        if self.is_unhandled(&stmt.expression, ctx) {
+            ctx.diagnostic(no_floating_promises_diagnostic(stmt.span, self.ignore_void));
+        }
+    }
+}
+
+impl NoFloatingPromises {
+    /// Whether `expr` is a Promise whose rejection is not handled.
+    fn is_unhandled<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                !self.ignore_void && self.is_unhandled(&unary.argument, ctx)
+            }
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.iter().any(|expr| self.is_unhandled(expr, ctx))
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.is_unhandled(&cond.consequent, ctx) || self.is_unhandled(&cond.alternate, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_unhandled(&logical.left, ctx) || self.is_unhandled(&logical.right, ctx)
+            }
+            Expression::CallExpression(call) => {
+                if let Some(member) = call.callee.get_inner_expression().as_member_expression() {
+                    match member.static_property_name() {
+                        Some("catch") if !call.arguments.is_empty() => return false,
+                        Some("then") if call.arguments.len() >= 2 => return false,
+                        Some("finally") => return self.is_unhandled(member.object(), ctx),
+                        _ => {}
+                    }
+                }
+                self.is_promise_call(call, ctx)
+            }
+            expr => self.is_promise(expr, ctx),
+        }
+    }
+
+    /// Whether `expr` evaluates to a Promise.
+    fn is_promise<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::CallExpression(call) => self.is_promise_call(call, ctx),
+            Expression::NewExpression(new_expr) => is_global_promise(&new_expr.callee, ctx),
+            Expression::Identifier(ident) => {
+                let Some(declaration) = get_declaration_of_variable(ident, ctx) else {
+                    return false;
+                };
+                match declaration.kind() {
+                    // Don't follow `const a = b;` to avoid cycles.
+                    AstKind::VariableDeclarator(declarator) => {
+                        declarator.init.as_ref().is_some_and(|init| {
+                            !matches!(init.get_inner_expression(), Expression::Identifier(_))
+                                && self.is_promise(init, ctx)
+                        })
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn is_promise_call<'a>(&self, call: &CallExpression<'a>, ctx: &LintContext<'a>) -> bool {
+        match call.callee.get_inner_expression() {
+            // `(async () => {})()`
+            Expression::ArrowFunctionExpression(arrow) => arrow.r#async && !self.ignore_iife,
+            Expression::FunctionExpression(func) => func.r#async && !self.ignore_iife,
+            Expression::Identifier(ident) => {
+                let Some(declaration) = get_declaration_of_variable(ident, ctx) else {
+                    return false;
+                };
+                match declaration.kind() {
+                    AstKind::Function(func) => func.r#async,
+                    AstKind::VariableDeclarator(declarator) => {
+                        declarator.init.as_ref().is_some_and(is_async_function)
+                    }
+                    _ => false,
+                }
+            }
+            callee => callee.as_member_expression().is_some_and(|member| {
+                match member.static_property_name() {
+                    Some("then" | "catch" | "finally") => true,
+                    // `Promise.all(...)`, `Promise.resolve(...)`
+                    Some("all" | "allSettled" | "any" | "race" | "reject" | "resolve") => {
+                        is_global_promise(member.object(), ctx)
+                    }
+                    _ => false,
+                }
+            }),
+        }
+    }
+}
+
+fn is_async_function(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::ArrowFunctionExpression(arrow) => arrow.r#async,
+        Expression::FunctionExpression(func) => func.r#async,
+        _ => false,
+    }
+}
+
+fn is_global_promise(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            ident.name == "Promise" && ctx.semantic().is_reference_to_global_variable(ident)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function test() { await Promise.resolve('value'); }", None),
+        ("async function test() { Promise.resolve('value').then(() => {}, () => {}); }", None),
+        ("async function test() { Promise.resolve('value').catch(() => {}); }", None),
+        (
+            "async function test() { Promise.resolve('value').finally(() => {}).catch(() => {}); }",
+            None,
+        ),
+        ("async function test() { return Promise.resolve('value'); }", None),
+        ("async function test() { void Promise.resolve('value'); }", None),
+        ("async function test() { const promise = new Promise(resolve => resolve()); await promise; }", None),
+        ("async function foo() {} async function test() { await foo(); }", None),
+        ("async function foo() {} foo().catch(() => {});", None),
+        ("async function foo() {} void foo();", None),
+        ("const foo = async () => {}; foo().then(() => {}, () => {});", None),
+        ("function foo() {} foo();", None),
+        ("const foo = () => {}; foo();", None),
+        ("foo();", None),
+        ("foo.bar();", None),
+        ("const x = Promise.resolve();", None),
+        ("class Promise {} Promise.resolve();", None),
+        ("const Promise = { resolve() {} }; Promise.resolve();", None),
+        ("(async () => { await foo(); })();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("(async function () { await foo(); })();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("(() => {})();", None),
+        ("async function test() { foo && await Promise.resolve(); }", None),
+    ];
+
+    let fail = vec![
+        ("async function test() { Promise.resolve('value'); }", None),
+        ("async function test() { Promise.resolve('value').then(() => {}); }", None),
+        ("async function test() { Promise.resolve('value').catch(); }", None),
+        ("async function test() { Promise.resolve('value').finally(() => {}); }", None),
+        ("async function test() { new Promise(resolve => resolve()); }", None),
+        ("async function test() { Promise.all([p1, p2]); }", None),
+        ("async function foo() {} foo();", None),
+        ("const foo = async () => {}; foo();", None),
+        ("const foo = async function () {}; foo().then(() => {});", None),
+        ("async function foo() {} foo().finally(() => {});", None),
+        ("const promise = new Promise(resolve => resolve()); promise;", None),
+        ("async function foo() {} cond ? foo() : bar();", None),
+        ("async function foo() {} bar && foo();", None),
+        ("async function foo() {} (bar(), foo());", None),
+        ("foo.then(() => {});", None),
+        ("(async () => { await foo(); })();", None),
+        ("(async function () { await foo(); })();", None),
+        ("async function foo() {} void foo();", Some(json!([{ "ignoreVoid": false }]))),
+        ("void Promise.resolve();", Some(json!([{ "ignoreVoid": false }]))),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,9 +1,9 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::MemberExpression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::RuleFix, rule::Rule, AstNode};
 
 #[derive(Debug, Default, Clone)]
 pub struct NoNonNullAssertion;
@@ -15,6 +15,11 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// TypeScript's ! non-null assertion operator asserts to the type system that an expression is non-nullable, as in not null or undefined. Using assertions to tell the type system new information is often a sign that code is not fully type-safe. It's generally better to structure program logic so that TypeScript understands when values may be nullable.
     ///
+    /// When the asserted expression is the object of a member access or the
+    /// callee of a call, a suggestion is offered to replace the assertion with
+    /// an optional chain, e.g. `x!.y` with `x?.y`. This changes the type of
+    /// the whole expression, so it is never applied automatically.
+    ///
     /// ### Example
     /// ```ts
     /// x!;
@@ -23,6 +28,7 @@ declare_oxc_lint!(
     /// ```
     NoNonNullAssertion,
     restriction,
+    suggestion
 );
 
 fn no_non_null_assertion_diagnostic(span: Span) -> OxcDiagnostic {
@@ -31,10 +37,57 @@ fn no_non_null_assertion_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn with_suggestion_message(fix: RuleFix<'_>) -> RuleFix<'_> {
+    fix.with_message("Replace the non-null assertion with an optional chain.")
+}
+
 impl Rule for NoNonNullAssertion {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSNonNullExpression(expr) = node.kind() else { return };
-        ctx.diagnostic(no_non_null_assertion_diagnostic(expr.span));
+        // Report the `!` itself, not the whole asserted expression.
+        let bang = Span::new(expr.span.end - 1, expr.span.end);
+        let diagnostic = no_non_null_assertion_diagnostic(bang);
+
+        match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MemberExpression(member)) if member.object().span() == expr.span => {
+                ctx.diagnostic_with_suggestions(diagnostic, |fixer| {
+                    let fix = if member.optional() {
+                        // `x!?.y` -> `x?.y`
+                        Some(fixer.delete_range(bang))
+                    } else if let MemberExpression::StaticMemberExpression(member) = member {
+                        // `x!.y` -> `x?.y`. The `.` may be on a later line, so it is
+                        // replaced together with the `!` and anything in between.
+                        let between = Span::new(bang.end, member.property.span.start);
+                        let text = between.source_text(ctx.source_text());
+                        if ctx.semantic().trivias().has_comments_between(between) {
+                            None
+                        } else {
+                            text.find('.').map(|dot| {
+                                let end = bang.end + u32::try_from(dot).unwrap() + 1;
+                                let whitespace = &text[..dot];
+                                fixer.replace(Span::new(bang.start, end), format!("{whitespace}?."))
+                            })
+                        }
+                    } else {
+                        // `x![y]` -> `x?.[y]`
+                        Some(fixer.replace(bang, "?."))
+                    };
+                    fix.map(with_suggestion_message).into_iter().collect()
+                });
+            }
+            Some(AstKind::CallExpression(call)) if call.callee.span() == expr.span => {
+                ctx.diagnostic_with_suggestions(diagnostic, |fixer| {
+                    // `x!?.()` -> `x?.()` and `x!()` -> `x?.()`
+                    let fix = if call.optional {
+                        fixer.delete_range(bang)
+                    } else {
+                        fixer.replace(bang, "?.")
+                    };
+                    vec![with_suggestion_message(fix)]
+                });
+            }
+            _ => ctx.diagnostic(diagnostic),
+        }
     }
 
     fn should_run(&self, ctx: &LintContext) -> bool {
@@ -85,7 +138,27 @@ fn test() {
         	 // comment
         	     /* comment */ ['y']
         	      ",
+        "a!.b!.c;",
+        "a!.b!.c!;",
+    ];
+
+    let suggestions = vec![
+        ("x!;", vec![]),
+        ("x.y!;", vec![]),
+        ("x!.y;", vec!["x?.y;"]),
+        ("x![y];", vec!["x?.[y];"]),
+        ("x.y.z!();", vec!["x.y.z?.();"]),
+        ("x!?.y.z;", vec!["x?.y.z;"]),
+        ("x!?.[y].z;", vec!["x?.[y].z;"]),
+        ("x.y.z!?.();", vec!["x.y.z?.();"]),
+        ("x!!.y;", vec!["x!?.y;"]),
+        ("x!\n.y;", vec!["x\n?.y;"]),
+        ("x!\n// comment\n.y;", vec![]),
+        ("a!.b!.c;", vec!["a!.b?.c;", "a?.b!.c;"]),
+        ("a!.b!.c!;", vec!["a!.b?.c!;", "a?.b!.c!;"]),
     ];
 
-    Tester::new(NoNonNullAssertion::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoNonNullAssertion::NAME, pass, fail)
+        .expect_suggestions(suggestions)
+        .test_and_snapshot();
 }
@@ -0,0 +1,389 @@
+use oxc_ast::{
+    ast::{
+        BindingPatternKind, Expression, LogicalOperator, TSLiteral, TSSignature, TSType,
+        VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+
+fn always_truthy_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary conditional, value is always truthy.").with_label(span)
+}
+
+fn always_falsy_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary conditional, value is always falsy.").with_label(span)
+}
+
+fn unnecessary_optional_chain_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary optional chain on a non-nullish value.")
+        .with_help("Use `.` instead of `?.`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnnecessaryCondition {
+    allow_constant_loop_conditions: bool,
+}
+
+// doc: https://typescript-eslint.io/rules/no-unnecessary-condition
+// code: https://github.com/typescript-eslint/typescript-eslint/blob/main/packages/eslint-plugin/src/rules/no-unnecessary-condition.ts
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow conditionals whose value is always truthy or always falsy, and optional chains
+    /// on values that are never nullish.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A condition that is always truthy or always falsy makes one of its branches dead code,
+    /// and an optional chain on a value that is never `null` or `undefined` suggests a check
+    /// that cannot fail. Both usually point to a mistake or to leftover code.
+    ///
+    /// This rule does not use type information. It only reports conditions that can be decided
+    /// from the source: literals, `const` variables initialized with literals, and variables,
+    /// parameters and properties of type literals whose type annotation is always truthy, always
+    /// falsy or never nullish. Non-null assertions (`a!`) are considered never nullish.
+    ///
+    /// JavaScript files carry no type annotations to decide from, so the rule only runs on
+    /// TypeScript files and does nothing elsewhere.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "typescript/no-unnecessary-condition": ["error", { "allowConstantLoopConditions": true }]
+    /// ```
+    ///
+    /// - `allowConstantLoopConditions` (default `false`): allow loop conditions that are always
+    ///   truthy, e.g. `while (true)`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const x: string = "a";
+    /// if (x) {}
+    ///
+    /// function foo(a: { b: string }) {
+    ///     return a?.b;
+    /// }
+    ///
+    /// function bar(c: null) {
+    ///     return c && c.d;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// function foo(x: string) {
+    ///     if (x) {}
+    /// }
+    ///
+    /// function bar(a?: { b: string }) {
+    ///     return a?.b;
+    /// }
+    /// ```
+    NoUnnecessaryCondition,
+    nursery
+);
+
+impl Rule for NoUnnecessaryCondition {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_constant_loop_conditions = value
+            .get(0)
+            .and_then(|config| config.get("allowConstantLoopConditions"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { allow_constant_loop_conditions }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(stmt) => check_condition(&stmt.test, false, ctx),
+            AstKind::ConditionalExpression(expr) => check_condition(&expr.test, false, ctx),
+            AstKind::LogicalExpression(expr)
+                if matches!(expr.operator, LogicalOperator::And | LogicalOperator::Or) =>
+            {
+                check_condition(&expr.left, false, ctx);
+            }
+            AstKind::WhileStatement(stmt) => self.check_loop_condition(&stmt.test, false, ctx),
+            // `do {} while (false)` is a common way to run a block once.
+            AstKind::DoWhileStatement(stmt) => self.check_loop_condition(&stmt.test, true, ctx),
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    self.check_loop_condition(test, false, ctx);
+                }
+            }
+            AstKind::MemberExpression(member) if member.optional() => {
+                if is_never_nullish(member.object(), ctx) {
+                    ctx.diagnostic(unnecessary_optional_chain_diagnostic(member.span()));
+                }
+            }
+            AstKind::CallExpression(call) if call.optional => {
+                if is_never_nullish(&call.callee, ctx) {
+                    ctx.diagnostic(unnecessary_optional_chain_diagnostic(call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUnnecessaryCondition {
+    fn check_loop_condition<'a>(
+        &self,
+        test: &Expression<'a>,
+        allow_falsy: bool,
+        ctx: &LintContext<'a>,
+    ) {
+        if self.allow_constant_loop_conditions && truthiness(test, ctx) == Some(true) {
+            return;
+        }
+        check_condition(test, allow_falsy, ctx);
+    }
+}
+
+/// Report `test` if it is always truthy, or always falsy unless `allow_falsy` is set.
+fn check_condition<'a>(test: &Expression<'a>, allow_falsy: bool, ctx: &LintContext<'a>) {
+    match truthiness(test, ctx) {
+        Some(true) => ctx.diagnostic(always_truthy_diagnostic(test.span())),
+        Some(false) if !allow_falsy => ctx.diagnostic(always_falsy_diagnostic(test.span())),
+        _ => {}
+    }
+}
+
+/// Whether `expr` is always truthy (`Some(true)`) or always falsy (`Some(false)`).
+fn truthiness<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<bool> {
+    match expr.without_parentheses() {
+        Expression::TSAsExpression(expr) => type_truthiness(&expr.type_annotation),
+        Expression::TSSatisfiesExpression(expr) => truthiness(&expr.expression, ctx),
+        expr @ (Expression::Identifier(_) | Expression::StaticMemberExpression(_)) => {
+            if let Some(ty) = declared_type(expr, ctx) {
+                if let Some(truthiness) = type_truthiness(ty) {
+                    return Some(truthiness);
+                }
+            }
+            let Expression::Identifier(ident) = expr else {
+                return None;
+            };
+            let declaration = get_declaration_of_variable(ident, ctx)?;
+            match declaration.kind() {
+                AstKind::VariableDeclarator(decl)
+                    if decl.kind == VariableDeclarationKind::Const
+                        && matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_)) =>
+                {
+                    decl.init.as_ref().and_then(literal_truthiness)
+                }
+                _ => None,
+            }
+        }
+        expr => literal_truthiness(expr),
+    }
+}
+
+/// Truthiness of literals, and of expressions that always create an object.
+fn literal_truthiness(expr: &Expression) -> Option<bool> {
+    match expr.without_parentheses() {
+        Expression::ObjectExpression(_)
+        | Expression::ArrayExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::NewExpression(_) => Some(true),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasi().map(|quasi| !quasi.is_empty())
+        }
+        expr => expr.to_boolean(),
+    }
+}
+
+/// Whether every value of type `ty` is truthy (`Some(true)`) or falsy (`Some(false)`).
+fn type_truthiness(ty: &TSType) -> Option<bool> {
+    match ty {
+        TSType::TSNullKeyword(_) | TSType::TSUndefinedKeyword(_) | TSType::TSVoidKeyword(_) => {
+            Some(false)
+        }
+        TSType::TSObjectKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSTypeLiteral(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSTupleType(_)
+        | TSType::TSFunctionType(_)
+        | TSType::TSConstructorType(_) => Some(true),
+        TSType::TSLiteralType(ty) => match &ty.literal {
+            TSLiteral::BooleanLiteral(lit) => Some(lit.value),
+            TSLiteral::NullLiteral(_) => Some(false),
+            TSLiteral::NumericLiteral(lit) => Some(lit.value != 0.0),
+            TSLiteral::BigIntLiteral(lit) => Some(!lit.is_zero()),
+            TSLiteral::StringLiteral(lit) => Some(!lit.value.is_empty()),
+            _ => None,
+        },
+        TSType::TSParenthesizedType(ty) => type_truthiness(&ty.type_annotation),
+        TSType::TSUnionType(ty) => {
+            let mut types = ty.types.iter().map(type_truthiness);
+            let first = types.next()??;
+            types.all(|truthiness| truthiness == Some(first)).then_some(first)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` can never be `null` or `undefined`.
+fn is_never_nullish<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.without_parentheses() {
+        Expression::TSNonNullExpression(_) => true,
+        Expression::TSAsExpression(expr) => is_never_nullish_type(&expr.type_annotation),
+        expr @ (Expression::Identifier(_) | Expression::StaticMemberExpression(_)) => {
+            declared_type(expr, ctx).is_some_and(is_never_nullish_type)
+        }
+        expr => literal_truthiness(expr) == Some(true),
+    }
+}
+
+/// Whether no value of type `ty` is `null` or `undefined`.
+fn is_never_nullish_type(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSBigIntKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSTemplateLiteralType(_)
+        | TSType::TSTypeLiteral(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSTupleType(_)
+        | TSType::TSFunctionType(_)
+        | TSType::TSConstructorType(_) => true,
+        TSType::TSLiteralType(ty) => !matches!(ty.literal, TSLiteral::NullLiteral(_)),
+        TSType::TSParenthesizedType(ty) => is_never_nullish_type(&ty.type_annotation),
+        TSType::TSUnionType(ty) => ty.types.iter().all(is_never_nullish_type),
+        _ => false,
+    }
+}
+
+/// The type annotation of the variable or parameter `expr` refers to, or of the property
+/// `expr` accesses on an object whose type is a type literal. Optional parameters and
+/// properties have no declared type, since they may be `undefined`.
+fn declared_type<'a, 'b>(
+    expr: &'b Expression<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b TSType<'a>> {
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => {
+            let declaration = get_declaration_of_variable(ident, ctx)?;
+            let pattern = match declaration.kind() {
+                AstKind::VariableDeclarator(decl) => &decl.id,
+                AstKind::FormalParameter(param) => &param.pattern,
+                _ => return None,
+            };
+            if pattern.optional || !matches!(pattern.kind, BindingPatternKind::BindingIdentifier(_))
+            {
+                return None;
+            }
+            pattern.type_annotation.as_ref().map(|annotation| &annotation.type_annotation)
+        }
+        Expression::StaticMemberExpression(member) if !member.optional => {
+            let TSType::TSTypeLiteral(object_type) = declared_type(&member.object, ctx)? else {
+                return None;
+            };
+            object_type.members.iter().find_map(|signature| match signature {
+                TSSignature::TSPropertySignature(prop)
+                    if !prop.optional
+                        && prop
+                            .key
+                            .static_name()
+                            .is_some_and(|name| name == member.property.name.as_str()) =>
+                {
+                    prop.type_annotation.as_ref().map(|annotation| &annotation.type_annotation)
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(x: string) { if (x) {} }", None),
+        ("function foo(x: number | null) { if (x) {} }", None),
+        ("function foo(x?: object) { if (x) {} }", None),
+        ("function foo(x: Foo) { if (x) {} }", None),
+        ("function foo(x: object | null) { return x && x.a; }", None),
+        ("let x = 'a'; x = ''; if (x) {}", None),
+        ("let x: string = 'a'; if (x) {}", None),
+        ("const x = foo(); if (x) {}", None),
+        ("const x = `${a}`; if (x) {}", None),
+        ("const { x } = { x: 1 }; if (x) {}", None),
+        ("function foo(a?: { b: string }) { return a?.b; }", None),
+        ("function foo(a: { b: string } | undefined) { return a?.b; }", None),
+        ("function foo(a: { b?: { c: string } }) { return a.b?.c; }", None),
+        ("function foo(a: Foo) { return a?.b; }", None),
+        ("function foo(a: any) { return a?.b; }", None),
+        ("function foo(a: unknown) { if (a) {} }", None),
+        ("function foo(f?: () => void) { f?.(); }", None),
+        ("function foo(a: string) { return a ?? 'b'; }", None),
+        ("while (true) {}", Some(serde_json::json!([{ "allowConstantLoopConditions": true }]))),
+        ("for (; true; ) {}", Some(serde_json::json!([{ "allowConstantLoopConditions": true }]))),
+        ("do {} while (false);", None),
+        ("if (a) {}", None),
+    ];
+
+    let fail = vec![
+        ("const x: string = 'a'; if (x) {}", None),
+        ("const x = 'a'; if (x) {}", None),
+        ("const x = ''; x ? a : b;", None),
+        ("const x = 0; if (x) {}", None),
+        ("const x = {}; if (x) {}", None),
+        ("function foo(x: object) { if (x) {} }", None),
+        ("function foo(x: { a: string }) { return x && x.a; }", None),
+        ("function foo(x: null) { return x || y; }", None),
+        ("function foo(x: undefined | null) { if (x) {} }", None),
+        ("function foo(x: 'a' | 'b') { if (x) {} }", None),
+        ("function foo(x: (() => void)) { if (x) {} }", None),
+        ("function foo(a: { b: [] }) { if (a.b) {} }", None),
+        ("if (a as null) {}", None),
+        ("if (true) {}", None),
+        ("while (true) {}", None),
+        ("for (; true; ) {}", None),
+        ("do {} while (true);", None),
+        ("while (false) {}", None),
+        ("function foo(a: { b: string }) { return a?.b; }", None),
+        ("function foo(a: string) { return a?.length; }", None),
+        ("function foo(a: { b: { c: string } }) { return a.b?.c; }", None),
+        ("function foo(a: Foo | undefined) { return a!?.b; }", None),
+        ("function foo(a: string[]) { return a?.[0]; }", None),
+        ("function foo(f: () => void) { f?.(); }", None),
+    ];
+
+    Tester::new(NoUnnecessaryCondition::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_without_types() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const x = 'a'; if (x) {}", None),
+        ("if (true) {}", None),
+        ("while (true) {}", None),
+        ("const x = {}; x?.a;", None),
+    ];
+
+    let fail = vec![];
+
+    Tester::new(NoUnnecessaryCondition::NAME, pass, fail).change_rule_path_extension("js").test();
+}
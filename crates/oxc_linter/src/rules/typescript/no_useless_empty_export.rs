@@ -1,4 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{ExportNamedDeclaration, Statement},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -19,6 +22,12 @@ declare_oxc_lint!(
     ///
     /// Disallow empty exports that don't change anything in a module file.
     ///
+    /// ### Why is this bad?
+    ///
+    /// An empty `export {}` is sometimes used to mark a file as a module. It is redundant once
+    /// the file has another import or export, and can be removed. An empty export that is the
+    /// only module syntax of the file is allowed.
+    ///
     /// ### Example
     ///
     /// ### Bad
@@ -37,19 +46,31 @@ declare_oxc_lint!(
     fix
 );
 
+/// `export {}`. `export {} from 'mod'` is not empty, it imports `mod` for its side effects.
+fn is_empty_export(decl: &ExportNamedDeclaration) -> bool {
+    decl.declaration.is_none() && decl.specifiers.is_empty() && decl.source.is_none()
+}
+
 impl Rule for NoUselessEmptyExport {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ExportNamedDeclaration(decl) = node.kind() else { return };
-        if decl.declaration.is_some() || !decl.specifiers.is_empty() {
+        if !is_empty_export(decl) {
             return;
         }
-        let module_record = ctx.module_record();
-        if module_record.exported_bindings.is_empty()
-            && module_record.local_export_entries.is_empty()
-            && module_record.indirect_export_entries.is_empty()
-            && module_record.star_export_entries.is_empty()
-            && module_record.export_default.is_none()
-        {
+        let Some(program) = ctx.nodes().root_node().and_then(|node| node.kind().as_program())
+        else {
+            return;
+        };
+        let has_other_import_or_export = program.body.iter().any(|statement| match statement {
+            Statement::ImportDeclaration(_)
+            | Statement::ExportAllDeclaration(_)
+            | Statement::ExportDefaultDeclaration(_)
+            | Statement::TSExportAssignment(_)
+            | Statement::TSImportEqualsDeclaration(_) => true,
+            Statement::ExportNamedDeclaration(decl) => !is_empty_export(decl),
+            _ => false,
+        });
+        if !has_other_import_or_export {
             return;
         }
         ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(decl.span), |fixer| {
@@ -78,6 +99,10 @@ fn test() {
             export = {};
         ",
         "export {};",
+        "export {};\nexport {};",
+        "export {} from '_';",
+        "export {} from '_';\nexport const _ = {};",
+        "const _ = {};\nexport {};",
     ];
 
     let fail = vec![
@@ -108,10 +133,26 @@ fn test() {
             export { _ };
             export {};
         ",
-        // "
-        // import _ = require('_');
-        // export {};
-        // ",
+        "
+            import _ = require('_');
+            export {};
+        ",
+        "
+            import _ from '_';
+            export {};
+        ",
+        "
+            import {} from '_';
+            export {};
+        ",
+        "
+            export = {};
+            export {};
+        ",
+        "
+            export {} from '_';
+            export {};
+        ",
     ];
 
     let fix = vec![
@@ -121,7 +162,9 @@ fn test() {
         ("const _ = {};export default _;export {};", "const _ = {};export default _;"),
         ("export {};const _ = {};export default _;", "const _ = {};export default _;"),
         ("const _ = {};export { _ };export {};", "const _ = {};export { _ };"),
-        // ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import _ from '_';export {};", "import _ from '_';"),
+        ("export {};import _ from '_';export {};", "import _ from '_';"),
     ];
 
     Tester::new(NoUselessEmptyExport::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
@@ -7,7 +7,9 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::invert_condition, context::LintContext, fixer::FixBuilder, rule::Rule, AstNode,
+};
 
 fn no_negated_condition_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected negated condition.")
@@ -53,12 +55,13 @@ declare_oxc_lint!(
     /// ```
     NoNegatedCondition,
     pedantic,
-    pending
+    conditional_suggestion
 );
 
 impl Rule for NoNegatedCondition {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let stmt_test = match node.kind() {
+        // The test, followed by the branches that are swapped when inverting it.
+        let (stmt_test, branches) = match node.kind() {
             AstKind::IfStatement(if_stmt) => {
                 let Some(if_stmt_alternate) = &if_stmt.alternate else {
                     return;
@@ -68,17 +71,29 @@ impl Rule for NoNegatedCondition {
                     return;
                 }
 
-                if_stmt.test.without_parentheses()
-            }
-            AstKind::ConditionalExpression(conditional_expr) => {
-                conditional_expr.test.without_parentheses()
+                // Swapping statements that aren't blocks may break ASI, e.g. in
+                // `if (!a) b(); else c()`.
+                let branches = match (&if_stmt.consequent, if_stmt_alternate) {
+                    (
+                        Statement::BlockStatement(consequent),
+                        Statement::BlockStatement(alternate),
+                    ) => Some((consequent.span, alternate.span)),
+                    _ => None,
+                };
+
+                (&if_stmt.test, branches)
             }
+            AstKind::ConditionalExpression(conditional_expr) => (
+                &conditional_expr.test,
+                Some((conditional_expr.consequent.span(), conditional_expr.alternate.span())),
+            ),
             _ => {
                 return;
             }
         };
 
-        match stmt_test {
+        let test = stmt_test.without_parentheses();
+        match test {
             Expression::UnaryExpression(unary_expr) => {
                 if unary_expr.operator != UnaryOperator::LogicalNot {
                     return;
@@ -97,7 +112,27 @@ impl Rule for NoNegatedCondition {
             }
         }
 
-        ctx.diagnostic(no_negated_condition_diagnostic(stmt_test.span()));
+        let diagnostic = no_negated_condition_diagnostic(test.span());
+        let Some((consequent, alternate)) = branches else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            let mut inverted = invert_condition(stmt_test, ctx);
+            // `return!a ? b : c` -> `return a ? c : b`
+            if ctx.source_text()[..stmt_test.span().start as usize]
+                .ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+                && inverted.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+            {
+                inverted.insert(0, ' ');
+            }
+            let mut builder = FixBuilder::new();
+            builder
+                .replace(stmt_test.span(), inverted)
+                .replace(consequent, fixer.source_range(alternate))
+                .replace(alternate, fixer.source_range(consequent));
+            fixer.build_fix(builder).with_message("Invert the condition and swap the branches.")
+        });
     }
 }
 
@@ -142,5 +177,22 @@ fn test() {
         r"(!!a) ? b() : c();",
     ];
 
-    Tester::new(NoNegatedCondition::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"if (!a) {b()} else {c()}", r"if (a) {c()} else {b()}"),
+        (r"if (a !== b) { c(); } else { d(); }", r"if (a === b) { d(); } else { c(); }"),
+        (r"if (!(a && b)) {c()} else {d()}", r"if (a && b) {d()} else {c()}"),
+        (r"if((( !a ))) {b()} else {c()}", r"if(a) {c()} else {b()}"),
+        (r"!a ? b : c", r"a ? c : b"),
+        (r"a != b ? c : d", r"a == b ? d : c"),
+        (r"!(( a )) ? b : c", r"a ? c : b"),
+        (r"!(a = b) ? c : d", r"(a = b) ? d : c"),
+        (r"!a ? b : c ? d : e", r"a ? c ? d : e : b"),
+        (r"function a() {return!a ? b : c}", r"function a() {return a ? c : b}"),
+        (r"function a() {return!(( a )) ? b : c}", r"function a() {return a ? c : b}"),
+        (r"(!!a) ? b() : c();", r"!a ? c() : b();"),
+        // Only blocks are swapped.
+        (r"if(!a) b(); else c()", r"if(!a) b(); else c()"),
+    ];
+
+    Tester::new(NoNegatedCondition::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -219,9 +219,22 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -
             }
             // foo(...[ ])
             AstKind::Argument(_) => {
-                ctx.diagnostic_with_fix(spread_in_arguments(span), |fixer| {
-                    fix_by_removing_array_spread(fixer, array_expr, spread_elem)
-                });
+                let diagnostic = spread_in_arguments(span);
+                // `foo(...[a, , b])` passes `undefined` for the hole.
+                let has_holes = array_expr
+                    .elements
+                    .iter()
+                    .any(|el| matches!(el, ArrayExpressionElement::Elision(_)));
+                // Removing the only argument is safe, other arguments need their commas removed.
+                let is_removable = !array_expr.elements.is_empty()
+                    || argument_count(parent_parent, ctx) == Some(1);
+                if has_holes || !is_removable {
+                    ctx.diagnostic(diagnostic);
+                } else {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fix_by_inlining_array_arguments(fixer, spread_elem, array_expr)
+                    });
+                }
                 true
             }
             _ => false,
@@ -432,6 +445,30 @@ fn fix_by_removing_array_spread<'a, S: GetSpan>(
     fixer.replace(iterable.span(), fixer.source_range(spread.argument.span()))
 }
 
+/// Creates a fix that replaces `...[a, b]` with `a, b` in call arguments.
+///
+/// ## Examples
+/// - `foo(...[1, 2, 3])` -> `foo(1, 2, 3)`
+/// - `foo(...[a, b,])` -> `foo(a, b)`
+fn fix_by_inlining_array_arguments<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    spread: &SpreadElement<'a>,
+    array: &ArrayExpression<'a>,
+) -> RuleFix<'a> {
+    let arguments =
+        array.elements.iter().map(|el| fixer.source_range(el.span())).collect::<Vec<_>>();
+    fixer.replace(spread.span, arguments.join(", "))
+}
+
+/// Number of arguments of the call or `new` expression that `argument` belongs to.
+fn argument_count(argument: &AstNode, ctx: &LintContext) -> Option<usize> {
+    match ctx.nodes().parent_kind(argument.id())? {
+        AstKind::CallExpression(call) => Some(call.arguments.len()),
+        AstKind::NewExpression(new_expr) => Some(new_expr.arguments.len()),
+        _ => None,
+    }
+}
+
 /// Creates a fix that replaces `{...spread}` with `spread`, when `spread` is an
 /// object literal
 ///
@@ -717,6 +754,14 @@ fn test() {
         ("const obj = {a, ...{b,c}}", "const obj = {a, b,c}"),
         ("const obj = {a, ...{b,c,}}", "const obj = {a, b,c}"),
         ("const obj = { a, ...{ b, c }, ...{ d } }", "const obj = { a,  b, c,  d }"),
+        // call arguments
+        ("Math.max(...[1,2,3])", "Math.max(1, 2, 3)"),
+        ("foo(...[a, b,])", "foo(a, b)"),
+        ("foo(a, ...[b, ...c], d)", "foo(a, b, ...c, d)"),
+        ("new Foo(...[a])", "new Foo(a)"),
+        ("foo(...[])", "foo()"),
+        ("foo(a, ...[])", "foo(a, ...[])"),
+        ("foo(...[a, , b])", "foo(...[a, , b])"),
         // iterable spread
         (r"const promise = Promise.any([...iterable])", r"const promise = Promise.any(iterable)"),
         (r"new Map([...iterable])", r"new Map(iterable)"),
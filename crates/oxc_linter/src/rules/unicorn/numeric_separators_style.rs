@@ -110,7 +110,7 @@ impl Rule for NumericSeparatorsStyle {
 
                 let formatted = self.format_bigint(number, raw);
 
-                if formatted.len() != number.span.size() as usize {
+                if formatted != raw {
                     ctx.diagnostic_with_fix(
                         numeric_separators_style_diagnostic(number.span),
                         |fixer| fixer.replace(number.span, formatted),
@@ -486,9 +486,16 @@ fn test_bigint() {
         "const foo = 1_9_223n",
         "const foo = 80_7n",
         "const foo = 123456789_100n",
+        // Misplaced separators that do not change the length
+        "const foo = 1_23_4444n",
+        "const foo = 0xA_BCDn",
     ];
 
-    let fix = vec![("const foo = 1_9_223n", "const foo = 19_223n", None)];
+    let fix = vec![
+        ("const foo = 1_9_223n", "const foo = 19_223n", None),
+        ("const foo = 1_23_4444n", "const foo = 1_234_444n", None),
+        ("const foo = 0xA_BCDn", "const foo = 0xAB_CDn", None),
+    ];
 
     Tester::new(NumericSeparatorsStyle::NAME, pass, fail).expect_fix(fix).test();
 }
@@ -2,25 +2,33 @@ use std::{
     collections::HashMap,
     ffi::OsStr,
     fs,
+    io::{self, Write},
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
 };
 
 use dashmap::DashMap;
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
+use oxc_span::SourceType;
+use rayon::{
+    iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelBridge},
+    prelude::ParallelIterator,
+};
 use rustc_hash::FxHashSet;
 
 use crate::{
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    resolver::{ModuleResolver, NodeModuleResolver},
+    sort_and_dedup_diagnostics,
     utils::read_to_string,
-    Fixer, Linter, Message,
+    Fixer, LintCache, Linter, Message,
 };
 
 pub struct LintServiceOptions {
@@ -32,6 +40,18 @@ pub struct LintServiceOptions {
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     tsconfig: Option<PathBuf>,
+
+    /// Diagnostics from previous runs, re-used for files that have not changed
+    cache: Option<Arc<LintCache>>,
+
+    /// Maximum number of paths linted at the same time
+    max_in_flight: Option<usize>,
+
+    /// Resolves import specifiers when the import plugin is enabled
+    resolver: Option<Arc<dyn ModuleResolver>>,
+
+    /// Print fixes as a unified diff instead of writing them to disk
+    fix_dry_run: bool,
 }
 
 impl LintServiceOptions {
@@ -40,7 +60,15 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cache: None,
+            fix_dry_run: false,
+            max_in_flight: None,
+            resolver: None,
+        }
     }
 
     #[inline]
@@ -58,6 +86,53 @@ impl LintServiceOptions {
         self
     }
 
+    /// Re-use diagnostics stored in `cache` for files whose contents have not
+    /// changed, and store the diagnostics of every other file in it.
+    ///
+    /// The cache should be created with [`Linter::config_hash`] of the linter
+    /// the service runs. Files that need cross-file analysis (when the import
+    /// plugin is enabled) or that are processed by the partial loader are never
+    /// cached.
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<LintCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Lint at most `max_in_flight` paths at the same time, to bound the memory held by
+    /// source texts and semantic models of files that are being linted.
+    ///
+    /// `max_in_flight` workers take the next path in order as soon as they finish their current
+    /// one. Dependencies parsed by the import plugin are not counted. Without a limit, all paths
+    /// are handed to the thread pool at once.
+    #[inline]
+    #[must_use]
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight.max(1));
+        self
+    }
+
+    /// Resolve import specifiers with `resolver` instead of a [`NodeModuleResolver`].
+    ///
+    /// The resolver is only used when the import plugin is enabled. `tsconfig` is ignored
+    /// when a resolver is given.
+    #[inline]
+    #[must_use]
+    pub fn with_resolver(mut self, resolver: Arc<dyn ModuleResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    /// Print the fixes of each file to stdout as a unified diff, see [`Fixer::diff`], instead
+    /// of writing the fixed code to disk. All diagnostics are reported, including fixable ones.
+    #[inline]
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -90,12 +165,29 @@ impl LintService {
     }
 
     /// # Panics
+    ///
+    /// Diagnostics are sent to `tx_error` as soon as each file is linted, so their order
+    /// depends on scheduling. Use [`DiagnosticService::with_ordered_output`] for a stable
+    /// output order.
     pub fn run(&self, tx_error: &DiagnosticSender) {
-        self.runtime
-            .paths
-            .iter()
-            .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        let paths = &self.runtime.ordered_paths;
+        match self.runtime.max_in_flight {
+            Some(max_in_flight) => {
+                // A shared cursor instead of fixed batches, so one slow file does not hold up
+                // the paths after it.
+                let next_path = AtomicUsize::new(0);
+                (0..max_in_flight).into_par_iter().for_each_with(&self.runtime, |runtime, _| {
+                    while let Some(path) = paths.get(next_path.fetch_add(1, Ordering::Relaxed)) {
+                        runtime.process_path(path, tx_error);
+                    }
+                });
+            }
+            None => {
+                paths.par_iter().for_each_with(&self.runtime, |runtime, path| {
+                    runtime.process_path(path, tx_error);
+                });
+            }
+        }
         tx_error.send(None).unwrap();
     }
 
@@ -114,7 +206,8 @@ impl LintService {
             .flat_map(|path| {
                 let source_type = SourceType::from_path(path).unwrap();
                 self.runtime.init_cache_state(path);
-                self.runtime.process_source(
+                self.runtime.process_source_cached(
+                    self.runtime.lint_cache.as_deref(),
                     path,
                     allocator,
                     source_text,
@@ -157,45 +250,42 @@ pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
+    /// All paths to lint, sorted and deduplicated, in the order they are handed to the thread pool
+    ordered_paths: Vec<Box<Path>>,
+    max_in_flight: Option<usize>,
     linter: Linter,
-    resolver: Option<Resolver>,
+    resolver: Option<Arc<dyn ModuleResolver>>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    lint_cache: Option<Arc<LintCache>>,
+    fix_dry_run: bool,
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let resolver = linter.options().plugins.has_import().then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            options.resolver.unwrap_or_else(|| {
+                let tsconfig = options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json")));
+                Arc::new(NodeModuleResolver::new(tsconfig))
+            })
         });
+        let mut ordered_paths = options.paths;
+        ordered_paths.sort_unstable();
+        ordered_paths.dedup();
         Self {
             cwd: options.cwd,
-            paths: options.paths.iter().cloned().collect(),
+            paths: ordered_paths.iter().cloned().collect(),
+            ordered_paths,
+            max_in_flight: options.max_in_flight,
             linter,
             resolver,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            lint_cache: options.cache,
+            fix_dry_run: options.fix_dry_run,
         }
     }
 
-    fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = tsconfig.and_then(|path| {
-            if path.is_file() {
-                Some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
-            } else {
-                None
-            }
-        });
-
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            condition_names: vec!["module".into(), "require".into()],
-            tsconfig,
-            ..ResolveOptions::default()
-        })
-    }
-
     fn get_source_type_and_text(
         path: &Path,
         ext: &str,
@@ -252,21 +342,41 @@ impl Runtime {
             return;
         }
 
+        // Diagnostics of partially loaded files and files linted with the import plugin depend
+        // on more than the file's own contents, so they cannot be cached.
+        let lint_cache = self.lint_cache.as_deref().filter(|_| {
+            !is_processed_by_partial_loader && !self.linter.options().plugins.has_import()
+        });
+
         for JavaScriptSource { source_text, source_type, .. } in sources {
             let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+            let mut messages = self.process_source_cached(
+                lint_cache,
+                path,
+                &allocator,
+                source_text,
+                source_type,
+                true,
+                tx_error,
+            );
 
             // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
             if !is_processed_by_partial_loader && self.linter.options().fix.is_some() {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+                if self.fix_dry_run {
+                    let file_name = path.strip_prefix(&self.cwd).unwrap_or(path).to_string_lossy();
+                    let diff = Fixer::new(source_text, messages.clone()).diff(&file_name);
+                    io::stdout().lock().write_all(diff.as_bytes()).unwrap();
+                } else {
+                    let fix_result = Fixer::new(source_text, messages).fix();
+                    fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+                    messages = fix_result.messages;
+                }
             }
 
             if !messages.is_empty() {
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(Into::into).collect();
+                let mut errors = messages.into_iter().map(Into::into).collect();
+                sort_and_dedup_diagnostics(&mut errors);
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
                 let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
@@ -274,6 +384,52 @@ impl Runtime {
         }
     }
 
+    /// Lint a source, reusing the diagnostics stored in `lint_cache`.
+    ///
+    /// If the cached diagnostics are incomplete because the configuration changed, only the
+    /// rules that are still pending run on the source.
+    #[allow(clippy::too_many_arguments)]
+    fn process_source_cached<'a>(
+        &self,
+        lint_cache: Option<&LintCache>,
+        path: &Path,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        check_syntax_errors: bool,
+        tx_error: &DiagnosticSender,
+    ) -> Vec<Message<'a>> {
+        let Some(cache) = lint_cache else {
+            return self.process_source(
+                path,
+                allocator,
+                source_text,
+                source_type,
+                check_syntax_errors,
+                None,
+                tx_error,
+            );
+        };
+        let (mut messages, pending): (Vec<Message<'a>>, _) =
+            match cache.get_partial(path, source_text) {
+                Some((messages, pending)) if pending.is_empty() => return messages,
+                Some((messages, pending)) => (messages, Some(pending)),
+                None => (vec![], None),
+            };
+        messages.extend(self.process_source(
+            path,
+            allocator,
+            source_text,
+            source_type,
+            check_syntax_errors,
+            pending.as_deref(),
+            tx_error,
+        ));
+        cache.insert(path, source_text, &messages);
+        messages
+    }
+
+    /// Lint a source with all rules, or only the rules whose error codes are in `rules`.
     #[allow(clippy::too_many_arguments)]
     fn process_source<'a>(
         &self,
@@ -282,6 +438,7 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
+        rules: Option<&[String]>,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
         let ret = Parser::new(allocator, source_text, source_type)
@@ -323,14 +480,13 @@ impl Runtime {
                 .requested_modules
                 .keys()
                 .par_bridge()
-                .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                .map_with(self.resolver.as_deref().unwrap(), |resolver, specifier| {
+                    resolver.resolve(dir, specifier).map(|path| (specifier, path))
                 })
                 .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    let Some(target_module_record_ref) = self.module_map.get(path) else {
+                .for_each_with(tx_error, |tx_error, (specifier, path)| {
+                    self.process_path(&path, tx_error);
+                    let Some(target_module_record_ref) = self.module_map.get(path.as_path()) else {
                         return;
                     };
                     let ModuleState::Resolved(target_module_record) =
@@ -388,7 +544,11 @@ impl Runtime {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
-        self.linter.run(path, Rc::new(semantic_ret.semantic))
+        let semantic = Rc::new(semantic_ret.semantic);
+        match rules {
+            Some(rules) => self.linter.run_rules(path, semantic, rules),
+            None => self.linter.run(path, semantic),
+        }
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
@@ -37,8 +37,8 @@ impl RuleTable {
         let default_rules = Linter::default()
             .rules
             .into_iter()
-            .map(|rule| rule.name())
-            .collect::<FxHashSet<&str>>();
+            .map(|rule| (rule.plugin_name(), rule.name()))
+            .collect::<FxHashSet<(&str, &str)>>();
 
         let mut rows = RULES
             .iter()
@@ -49,7 +49,7 @@ impl RuleTable {
                     documentation: rule.documentation(),
                     plugin: rule.plugin_name().to_string(),
                     category: rule.category(),
-                    turned_on_by_default: default_rules.contains(name),
+                    turned_on_by_default: default_rules.contains(&(rule.plugin_name(), name)),
                     autofix: rule.fix(),
                 }
             })
@@ -10,8 +10,9 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    fixer::FixKind, options::LintPluginOptions, rules::RULES, AllowWarnDeny, Fixer, LintService,
-    LintServiceOptions, Linter, OxlintConfig, OxlintOptions, RuleEnum, RuleWithSeverity,
+    fixer::FixKind, options::LintPluginOptions, sort_and_dedup_diagnostics, AllowWarnDeny, Fixer,
+    LintPlugin, LintService, LintServiceOptions, Linter, Message, OxlintConfig, OxlintOptions,
+    RuleEnum, RuleRegistry, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -159,12 +160,115 @@ where
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ExpectSuggestions {
+    /// Source code being tested
+    source: String,
+    /// Expected source code after applying each suggestion on its own, in the
+    /// order the suggestions are reported.
+    expected: Vec<String>,
+    rule_config: Option<Value>,
+}
+
+impl<S: Into<String>> From<(S, Vec<S>, Option<Value>)> for ExpectSuggestions {
+    fn from((source, expected, rule_config): (S, Vec<S>, Option<Value>)) -> Self {
+        Self {
+            source: source.into(),
+            expected: expected.into_iter().map(Into::into).collect(),
+            rule_config,
+        }
+    }
+}
+
+impl<S: Into<String>> From<(S, Vec<S>)> for ExpectSuggestions {
+    fn from((source, expected): (S, Vec<S>)) -> Self {
+        Self::from((source, expected, None))
+    }
+}
+
+/// The kind of a case in a fixture file, see [`Tester::from_fixture`].
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FixtureCaseKind {
+    Pass,
+    Fail,
+}
+
+/// A single line of a fixture file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureCase {
+    kind: FixtureCaseKind,
+    code: String,
+    /// Rule configuration, e.g. `[{ "destructuring": "all" }]`
+    #[serde(default)]
+    options: Option<Value>,
+    /// Expected source code after fixes are applied, only for failing cases
+    #[serde(default)]
+    output: Option<String>,
+}
+
+/// Test cases read from a fixture file.
+#[derive(Debug, Default)]
+struct Fixture {
+    pass: Vec<TestCase>,
+    fail: Vec<TestCase>,
+    fix: Vec<ExpectFix>,
+}
+
+impl Fixture {
+    /// Parse a fixture in JSON Lines format. Empty lines and lines starting with `//` are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a line is not a valid case, or a passing case has an `output`.
+    fn parse(source_text: &str, name: &str) -> Self {
+        let mut fixture = Self::default();
+        for (line_number, line) in source_text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let case: FixtureCase = serde_json::from_str(line).unwrap_or_else(|err| {
+                panic!("Invalid case in fixture {name} at line {}: {err}", line_number + 1)
+            });
+            let test_case = TestCase {
+                source: case.code.clone(),
+                rule_config: case.options.clone(),
+                ..TestCase::default()
+            };
+            match case.kind {
+                FixtureCaseKind::Pass => {
+                    assert!(
+                        case.output.is_none(),
+                        "Passing case in fixture {name} at line {} has an output",
+                        line_number + 1
+                    );
+                    fixture.pass.push(test_case);
+                }
+                FixtureCaseKind::Fail => {
+                    fixture.fail.push(test_case);
+                    if let Some(output) = case.output {
+                        fixture.fix.push(ExpectFix::from((case.code, output, case.options)));
+                    }
+                }
+            }
+        }
+        fixture
+    }
+}
+
 pub struct Tester {
     rule_name: &'static str,
+    /// Plugin of the rule under test, for rule names used by more than one plugin. Taken from
+    /// the path of the rule's test, e.g. `unicorn` for `src/rules/unicorn/no_lonely_if.rs`.
+    plugin_name: Option<&'static str>,
     rule_path: PathBuf,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
     expect_fix: Vec<ExpectFix>,
+    expect_suggestions: Vec<ExpectSuggestions>,
     snapshot: String,
     /// Suffix added to end of snapshot name.
     ///
@@ -178,9 +282,11 @@ pub struct Tester {
     // nextjs_plugin: bool,
     // react_perf_plugin: bool,
     plugins: LintPluginOptions,
+    registry: RuleRegistry,
 }
 
 impl Tester {
+    #[track_caller]
     pub fn new<T: Into<TestCase>>(
         rule_name: &'static str,
         expect_pass: Vec<T>,
@@ -194,17 +300,46 @@ impl Tester {
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
             rule_name,
+            plugin_name: caller_plugin_name(),
             rule_path,
             expect_pass,
             expect_fail,
             expect_fix: vec![],
+            expect_suggestions: vec![],
             snapshot: String::new(),
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPluginOptions::none(),
+            registry: RuleRegistry::default(),
         }
     }
 
+    /// Create a tester with the cases of the fixture file `fixtures/rules/{file_name}`, instead
+    /// of inlining large tables of cases in the rule's test.
+    ///
+    /// Each line of the file is a JSON object describing one case:
+    ///
+    /// ```text
+    /// // Comments and empty lines are skipped.
+    /// {"kind": "pass", "code": "const x = 1;"}
+    /// {"kind": "fail", "code": "let x = 1;", "output": "const x = 1;"}
+    /// {"kind": "fail", "code": "let { a } = obj;", "options": [{ "destructuring": "all" }]}
+    /// ```
+    ///
+    /// Failing cases with an `output` are also checked as fix cases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be read or contains an invalid case.
+    #[track_caller]
+    pub fn from_fixture(rule_name: &'static str, file_name: &str) -> Self {
+        let path = env::current_dir().unwrap().join("fixtures/rules").join(file_name);
+        let source_text = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read fixture {}: {err}", path.display()));
+        let Fixture { pass, fail, fix } = Fixture::parse(&source_text, file_name);
+        Self::new(rule_name, pass, fail).expect_fix(fix)
+    }
+
     /// Change the path
     pub fn change_rule_path(mut self, path: &str) -> Self {
         self.rule_path = self.current_working_directory.join(path);
@@ -257,6 +392,16 @@ impl Tester {
         self
     }
 
+    /// Register a third-party plugin, so that its rules can be tested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin cannot be registered.
+    pub fn with_plugin(mut self, plugin: &dyn LintPlugin) -> Self {
+        self.registry.register(plugin).unwrap();
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -284,10 +429,34 @@ impl Tester {
         self
     }
 
+    /// Add cases that should offer suggestions for problems found in the
+    /// source code.
+    ///
+    /// Each suggestion is applied to the source code on its own. These cases
+    /// will fail if the results do not match the expected ones, in order.
+    ///
+    /// ```
+    /// use oxc_linter::tester::Tester;
+    ///
+    /// let suggestions = vec![
+    ///     // source, expected results, rule_config?
+    ///     ("'\\8'", vec!["'8'", "'\\\\8'"]),
+    /// ];
+    ///
+    /// Tester::new("no-nonoctal-decimal-escape", vec![], vec![])
+    ///     .expect_suggestions(suggestions)
+    ///     .test();
+    /// ```
+    pub fn expect_suggestions<S: Into<ExpectSuggestions>>(mut self, expect: Vec<S>) -> Self {
+        self.expect_suggestions = expect.into_iter().map(Into::into).collect::<Vec<_>>();
+        self
+    }
+
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
         self.test_fix();
+        self.test_suggestions();
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -335,12 +504,34 @@ impl Tester {
                     expected, fixed_str,
                     r#"Expected "{source}" to be fixed into "{expected}""#
                 ),
+                // Cases that expect the source to stay unchanged may not report anything.
+                TestResult::Passed if expected == source => {}
                 TestResult::Passed => panic!("Expected a fix, but test passed: {source}"),
                 TestResult::Failed => panic!("Expected a fix, but test failed: {source}"),
             }
         }
     }
 
+    fn test_suggestions(&mut self) {
+        for ExpectSuggestions { source, expected, rule_config } in self.expect_suggestions.clone() {
+            let allocator = Allocator::default();
+            let messages =
+                self.lint(&allocator, &source, rule_config, &None, None, ExpectFixKind::None);
+            let suggested = messages
+                .into_iter()
+                .flat_map(|message| {
+                    let error = message.error;
+                    message
+                        .suggestions
+                        .into_iter()
+                        .map(move |suggestion| Message::new(error.clone(), Some(suggestion.fix)))
+                })
+                .map(|message| Fixer::new(&source, vec![message]).fix().fixed_code.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(expected, suggested, r#"Unexpected suggestions for "{source}""#);
+        }
+    }
+
     fn run(
         &mut self,
         source_text: &str,
@@ -350,6 +541,48 @@ impl Tester {
         fix: ExpectFixKind,
     ) -> TestResult {
         let allocator = Allocator::default();
+        let result = self.lint(&allocator, source_text, rule_config, eslint_config, path, fix);
+
+        if result.is_empty() {
+            return TestResult::Passed;
+        }
+
+        if fix.is_some() {
+            let fix_result = Fixer::new(source_text, result).fix();
+            return TestResult::Fixed(fix_result.fixed_code.to_string());
+        }
+
+        let diagnostic_path = if self.plugins.import {
+            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+        } else {
+            &self.rule_path
+        }
+        .to_string_lossy();
+
+        let handler = GraphicalReportHandler::new()
+            .with_links(false)
+            .with_theme(GraphicalTheme::unicode_nocolor());
+        let mut diagnostics = result.into_iter().map(|message| message.error).collect();
+        sort_and_dedup_diagnostics(&mut diagnostics);
+        for diagnostic in diagnostics {
+            let diagnostic = diagnostic.with_source_code(NamedSource::new(
+                diagnostic_path.clone(),
+                source_text.to_string(),
+            ));
+            handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
+        }
+        TestResult::Failed
+    }
+
+    fn lint<'a>(
+        &self,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        rule_config: Option<Value>,
+        eslint_config: &Option<Value>,
+        path: Option<PathBuf>,
+        fix: ExpectFixKind,
+    ) -> Vec<Message<'a>> {
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
         let options = OxlintOptions::default()
             .with_fix(fix.into())
@@ -384,41 +617,28 @@ impl Tester {
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
-
-        if result.is_empty() {
-            return TestResult::Passed;
-        }
-
-        if fix.is_some() {
-            let fix_result = Fixer::new(source_text, result).fix();
-            return TestResult::Fixed(fix_result.fixed_code.to_string());
-        }
-
-        let diagnostic_path = if self.plugins.import {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
-
-        let handler = GraphicalReportHandler::new()
-            .with_links(false)
-            .with_theme(GraphicalTheme::unicode_nocolor());
-        for diagnostic in result {
-            let diagnostic = diagnostic.error.with_source_code(NamedSource::new(
-                diagnostic_path.clone(),
-                source_text.to_string(),
-            ));
-            handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
-        }
-        TestResult::Failed
+        lint_service.run_source(allocator, source_text, false, tx_error)
     }
 
     fn find_rule(&self) -> &RuleEnum {
-        RULES
-            .iter()
-            .find(|rule| rule.name() == self.rule_name)
+        self.registry
+            .rules()
+            .find(|rule| {
+                rule.name() == self.rule_name
+                    && self
+                        .plugin_name
+                        .map_or(true, |plugin_name| rule.plugin_name() == plugin_name)
+            })
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
+
+/// The plugin directory of the built-in rule whose test created the [`Tester`], e.g. `unicorn` for
+/// `src/rules/unicorn/no_lonely_if.rs`. Tests outside of `src/rules` have no plugin.
+#[track_caller]
+fn caller_plugin_name() -> Option<&'static str> {
+    let file = Path::new(std::panic::Location::caller().file());
+    let mut components = file.components().map(|component| component.as_os_str().to_str());
+    components.find(|component| *component == Some("rules"))?;
+    components.next().flatten()
+}
@@ -0,0 +1,88 @@
+use oxc_span::Span;
+
+/// A single line of source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLine<'a> {
+    /// Text of the line, without its line terminator.
+    pub text: &'a str,
+    /// Span of [`SourceLine::text`].
+    pub span: Span,
+    /// Span of the line terminator ending this line. Empty for the last line.
+    pub terminator: Span,
+}
+
+impl<'a> SourceLine<'a> {
+    /// The line terminator ending this line, e.g. `"\n"` or `"\r\n"`. Empty for the last line.
+    pub fn terminator_text(&self, source_text: &'a str) -> &'a str {
+        self.terminator.source_text(source_text)
+    }
+
+    /// Whether the line is empty or only contains whitespace.
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// Split `source_text` into lines, recognizing every ECMAScript line terminator
+/// (`\n`, `\r\n`, `\r`, `\u{2028}` and `\u{2029}`).
+///
+/// Unlike [`str::lines`], the spans of the line contents and their terminators are kept, so
+/// rules can report on and fix exact ranges. A trailing line terminator yields a final empty
+/// line.
+pub fn source_lines(source_text: &str) -> Vec<SourceLine<'_>> {
+    let mut lines = vec![];
+    let mut line_start = 0;
+    let mut chars = source_text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let terminator_len = match c {
+            '\r' if chars.peek().is_some_and(|(_, next)| *next == '\n') => {
+                chars.next();
+                2
+            }
+            '\n' | '\r' => 1,
+            '\u{2028}' | '\u{2029}' => c.len_utf8(),
+            _ => continue,
+        };
+        lines.push(new_line(source_text, line_start, i, i + terminator_len));
+        line_start = i + terminator_len;
+    }
+    lines.push(new_line(source_text, line_start, source_text.len(), source_text.len()));
+    lines
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn new_line(source_text: &str, start: usize, end: usize, terminator_end: usize) -> SourceLine<'_> {
+    SourceLine {
+        text: &source_text[start..end],
+        span: Span::new(start as u32, end as u32),
+        terminator: Span::new(end as u32, terminator_end as u32),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+
+    use super::source_lines;
+
+    #[test]
+    fn splits_on_all_line_terminators() {
+        let source_text = "a\nb\r\nc\rd\u{2028}e";
+        let lines = source_lines(source_text);
+        let texts = lines.iter().map(|line| line.text).collect::<Vec<_>>();
+        assert_eq!(texts, vec!["a", "b", "c", "d", "e"]);
+        let terminators =
+            lines.iter().map(|line| line.terminator_text(source_text)).collect::<Vec<_>>();
+        assert_eq!(terminators, vec!["\n", "\r\n", "\r", "\u{2028}", ""]);
+        assert_eq!(lines[1].span, Span::new(2, 3));
+    }
+
+    #[test]
+    fn trailing_terminator_yields_empty_line() {
+        let lines = source_lines("a\n");
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].is_blank());
+        assert!(lines[1].terminator.is_empty());
+        assert_eq!(source_lines("").len(), 1);
+    }
+}
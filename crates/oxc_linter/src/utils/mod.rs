@@ -2,10 +2,12 @@ mod config;
 mod express;
 mod jest;
 mod jsdoc;
+mod lines;
 mod nextjs;
 mod promise;
 mod react;
 mod react_perf;
+mod regex;
 mod tree_shaking;
 mod unicorn;
 mod vitest;
@@ -13,8 +15,8 @@ mod vitest;
 use std::{io, path::Path};
 
 pub use self::{
-    config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*, react_perf::*,
-    tree_shaking::*, unicorn::*, vitest::*,
+    config::*, express::*, jest::*, jsdoc::*, lines::*, nextjs::*, promise::*, react::*,
+    react_perf::*, regex::*, tree_shaking::*, unicorn::*, vitest::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
@@ -0,0 +1,285 @@
+use oxc_ast::ast::{Argument, NewExpression, RegExpLiteral, RegExpPattern};
+use oxc_regular_expression::ast::{
+    CapturingGroup, CharacterClass, CharacterClassContents, Disjunction, IndexedReference,
+    NamedReference, Pattern, Quantifier, Term,
+};
+use oxc_span::Span;
+
+/// Why the pattern of a regular expression literal is not available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegexError {
+    /// The pattern is not a valid regular expression. The error is reported by
+    /// `no-invalid-regexp`.
+    Invalid,
+    /// The pattern was not parsed, because the parser was run without
+    /// `parse_regular_expression`.
+    Unparsed,
+}
+
+/// A backreference in a regular expression, e.g. `\1` or `\k<name>`.
+#[derive(Debug, Clone, Copy)]
+pub enum Backreference<'r, 'a> {
+    Indexed(&'r IndexedReference),
+    Named(&'r NamedReference<'a>),
+}
+
+impl Backreference<'_, '_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Indexed(reference) => reference.span,
+            Self::Named(reference) => reference.span,
+        }
+    }
+}
+
+/// The parsed pattern of a regular expression, with its groups, character classes,
+/// quantifiers and backreferences collected in source order.
+///
+/// Rules that need to inspect a regular expression should use this instead of scanning the
+/// pattern text.
+#[derive(Debug)]
+pub struct RegexAst<'r, 'a> {
+    pub pattern: &'r Pattern<'a>,
+    /// Capturing groups, named or unnamed. Non-capturing groups `(?:...)` are not included.
+    pub groups: Vec<&'r CapturingGroup<'a>>,
+    /// Character classes, including classes nested in other classes with the `v` flag.
+    pub classes: Vec<&'r CharacterClass<'a>>,
+    pub quantifiers: Vec<&'r Quantifier<'a>>,
+    pub backreferences: Vec<Backreference<'r, 'a>>,
+}
+
+impl<'r, 'a> RegexAst<'r, 'a> {
+    /// Collect the nodes of `pattern`, e.g. one parsed with
+    /// [`oxc_regular_expression::PatternParser`] from a `RegExp` constructor argument.
+    pub fn new(pattern: &'r Pattern<'a>) -> Self {
+        let mut ast = Self {
+            pattern,
+            groups: vec![],
+            classes: vec![],
+            quantifiers: vec![],
+            backreferences: vec![],
+        };
+        ast.collect_disjunction(&pattern.body);
+        ast
+    }
+
+    fn collect_disjunction(&mut self, disjunction: &'r Disjunction<'a>) {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                self.collect_term(term);
+            }
+        }
+    }
+
+    fn collect_term(&mut self, term: &'r Term<'a>) {
+        match term {
+            Term::LookAroundAssertion(assertion) => self.collect_disjunction(&assertion.body),
+            Term::Quantifier(quantifier) => {
+                self.quantifiers.push(quantifier);
+                self.collect_term(&quantifier.body);
+            }
+            Term::CharacterClass(class) => self.collect_class(class),
+            Term::CapturingGroup(group) => {
+                self.groups.push(group);
+                self.collect_disjunction(&group.body);
+            }
+            Term::IgnoreGroup(group) => self.collect_disjunction(&group.body),
+            Term::IndexedReference(reference) => {
+                self.backreferences.push(Backreference::Indexed(reference));
+            }
+            Term::NamedReference(reference) => {
+                self.backreferences.push(Backreference::Named(reference));
+            }
+            Term::BoundaryAssertion(_)
+            | Term::Character(_)
+            | Term::Dot(_)
+            | Term::CharacterClassEscape(_)
+            | Term::UnicodePropertyEscape(_) => {}
+        }
+    }
+
+    fn collect_class(&mut self, class: &'r CharacterClass<'a>) {
+        self.classes.push(class);
+        for contents in &class.body {
+            if let CharacterClassContents::NestedCharacterClass(nested) = contents {
+                self.collect_class(nested);
+            }
+        }
+    }
+}
+
+/// The parsed pattern of the regular expression `literal`.
+///
+/// # Errors
+///
+/// Returns an error if the pattern is invalid or was not parsed.
+pub fn parse_regex_literal<'r, 'a>(
+    literal: &'r RegExpLiteral<'a>,
+) -> Result<RegexAst<'r, 'a>, RegexError> {
+    match &literal.regex.pattern {
+        RegExpPattern::Pattern(pattern) => Ok(RegexAst::new(pattern)),
+        RegExpPattern::Invalid(_) => Err(RegexError::Invalid),
+        RegExpPattern::Raw(_) => Err(RegexError::Unparsed),
+    }
+}
+
+/// The pattern and flags of `new RegExp(pattern, flags)`, if both are known statically.
+///
+/// Both arguments must be string literals or template literals without expressions. A missing
+/// flags argument is the empty string. Returns `None` for dynamic patterns or flags, e.g.
+/// `new RegExp(source)` or `new RegExp("a", flags)`, and for callees other than `RegExp`.
+pub fn regex_pattern_of<'a>(new_expr: &NewExpression<'a>) -> Option<(&'a str, &'a str)> {
+    if !new_expr.callee.is_specific_id("RegExp") {
+        return None;
+    }
+    match new_expr.arguments.as_slice() {
+        [pattern] => Some((static_string(pattern)?, "")),
+        [pattern, flags, ..] => Some((static_string(pattern)?, static_string(flags)?)),
+        [] => None,
+    }
+}
+
+fn static_string<'a>(argument: &Argument<'a>) -> Option<&'a str> {
+    match argument {
+        Argument::StringLiteral(lit) => Some(lit.value.as_str()),
+        Argument::TemplateLiteral(lit) if lit.is_no_substitution_template() => {
+            lit.quasi().map(|quasi| quasi.as_str())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::{ParseOptions, Parser};
+    use oxc_regular_expression::{ParserOptions, PatternParser};
+    use oxc_span::SourceType;
+
+    use super::{parse_regex_literal, regex_pattern_of, Backreference, RegexAst, RegexError};
+
+    /// Run `f` on the pattern `source_text`, parsed in unicode sets mode if `v` is set.
+    fn with_ast(source_text: &str, v: bool, f: impl FnOnce(&RegexAst)) {
+        let allocator = Allocator::default();
+        let options = if v {
+            ParserOptions::default().with_unicode_sets_mode()
+        } else {
+            ParserOptions::default()
+        };
+        let pattern = PatternParser::new(&allocator, source_text, options).parse().unwrap();
+        f(&RegexAst::new(&pattern));
+    }
+
+    #[test]
+    fn groups() {
+        with_ast("(a)(?:b)(?<c>d(e))(?=(f))", false, |ast| {
+            let names = ast.groups.iter().map(|group| group.name.as_deref()).collect::<Vec<_>>();
+            assert_eq!(names, vec![None, Some("c"), None, None]);
+            assert!(ast.classes.is_empty());
+            assert!(ast.quantifiers.is_empty());
+        });
+    }
+
+    #[test]
+    fn classes_and_quantifiers() {
+        with_ast("[a-z]+[^]?(?:[0-9]{2,})*", false, |ast| {
+            let negated = ast.classes.iter().map(|class| class.negative).collect::<Vec<_>>();
+            assert_eq!(negated, vec![false, true, false]);
+            assert!(ast.classes[1].body.is_empty());
+            let bounds = ast
+                .quantifiers
+                .iter()
+                .map(|quantifier| (quantifier.min, quantifier.max))
+                .collect::<Vec<_>>();
+            assert_eq!(bounds, vec![(1, None), (0, Some(1)), (0, None), (2, None)]);
+        });
+        with_ast("[a[b]]", true, |ast| assert_eq!(ast.classes.len(), 2));
+    }
+
+    #[test]
+    fn backreferences() {
+        with_ast(r"(a)(?<b>c)\1\k<b>", false, |ast| {
+            let references = ast
+                .backreferences
+                .iter()
+                .map(|reference| match reference {
+                    Backreference::Indexed(reference) => reference.index.to_string(),
+                    Backreference::Named(reference) => reference.name.to_string(),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(references, vec!["1", "b"]);
+            assert_eq!(ast.backreferences[0].span().size(), 2);
+        });
+    }
+
+    #[test]
+    fn regex_literal() {
+        let allocator = Allocator::default();
+        let source_text = "/(a)[b]/; /(/u;";
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+        let program = Parser::new(&allocator, source_text, SourceType::default())
+            .with_options(options)
+            .parse()
+            .program;
+        let literals = program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::ExpressionStatement(stmt) => match &stmt.expression {
+                    Expression::RegExpLiteral(literal) => literal,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        let ast = parse_regex_literal(literals[0]).unwrap();
+        assert_eq!(ast.groups.len(), 1);
+        assert_eq!(ast.classes.len(), 1);
+        assert_eq!(parse_regex_literal(literals[1]).unwrap_err(), RegexError::Invalid);
+    }
+
+    #[test]
+    fn new_regexp_pattern() {
+        let allocator = Allocator::default();
+        let source_text = r#"
+            new RegExp("a+", "g");
+            new RegExp(`b\x41`);
+            new RegExp('c', `iu`);
+            new RegExp(source);
+            new RegExp(`${a}b`);
+            new RegExp("d", flags);
+            new RegExp(...args);
+            new RegExp();
+            new Foo("e");
+        "#;
+        let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+        let patterns = program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::ExpressionStatement(stmt) => match &stmt.expression {
+                    Expression::NewExpression(new_expr) => regex_pattern_of(new_expr),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            patterns,
+            vec![
+                Some(("a+", "g")),
+                Some(("bA", "")),
+                Some(("c", "iu")),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+    }
+}
@@ -8,18 +8,28 @@ use syn::{
 };
 
 pub struct LintRuleMeta {
+    /// Name of the rule struct, derived from the last segment of `path`.
     name: syn::Ident,
+    /// Name of the [`RuleEnum`] variant. Usually the same as `name`, but can be set with
+    /// `plugin::rule_name as VariantName` when two plugins have a rule with the same name.
+    variant: syn::Ident,
     path: syn::Path,
 }
 
 impl Parse for LintRuleMeta {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let path = input.parse::<syn::Path>()?;
-        let name = syn::parse_str(
+        let name: syn::Ident = syn::parse_str(
             &path.segments.iter().last().unwrap().ident.to_string().to_case(Case::Pascal),
         )
         .unwrap();
-        Ok(Self { name, path })
+        let variant = if input.peek(syn::Token![as]) {
+            input.parse::<syn::Token![as]>()?;
+            input.parse()?
+        } else {
+            name.clone()
+        };
+        Ok(Self { name, variant, path })
     }
 }
 
@@ -40,13 +50,15 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let AllLintRulesMeta { rules } = metadata;
 
     let mut use_stmts = Vec::with_capacity(rules.len());
+    let mut rule_names = Vec::with_capacity(rules.len());
     let mut struct_names = Vec::with_capacity(rules.len());
     let mut plugin_names = Vec::with_capacity(rules.len());
     let mut ids = Vec::with_capacity(rules.len());
 
     for (i, rule) in rules.iter().enumerate() {
         use_stmts.push(&rule.path);
-        struct_names.push(&rule.name);
+        rule_names.push(&rule.name);
+        struct_names.push(&rule.variant);
         plugin_names.push(
             rule.path
                 .segments
@@ -59,7 +71,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     }
 
     let expanded = quote! {
-        #(pub use self::#use_stmts::#struct_names;)*
+        #(pub use self::#use_stmts::#rule_names as #struct_names;)*
 
         use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta}, AstNode};
         use oxc_semantic::SymbolId;
@@ -67,44 +79,63 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
         #[derive(Debug, Clone)]
         #[allow(clippy::enum_variant_names)]
         pub enum RuleEnum {
-            #(#struct_names(#struct_names)),*
+            #(#struct_names(#struct_names),)*
+            /// A rule registered by a [`LintPlugin`](crate::plugin::LintPlugin).
+            Plugin(crate::plugin::PluginRule),
         }
 
         impl RuleEnum {
             pub fn id(&self) -> usize {
                 match self {
-                    #(Self::#struct_names(_) => #ids),*
+                    #(Self::#struct_names(_) => #ids,)*
+                    Self::Plugin(rule) => rule.id(),
                 }
             }
 
             pub fn name(&self) -> &'static str {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::NAME),*
+                    #(Self::#struct_names(_) => #struct_names::NAME,)*
+                    Self::Plugin(rule) => rule.name(),
                 }
             }
 
             pub fn category(&self) -> RuleCategory {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::CATEGORY),*
+                    #(Self::#struct_names(_) => #struct_names::CATEGORY,)*
+                    Self::Plugin(rule) => rule.category(),
                 }
             }
 
             /// This [`Rule`]'s auto-fix capabilities.
             pub fn fix(&self) -> RuleFixMeta {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                    #(Self::#struct_names(_) => #struct_names::FIX,)*
+                    Self::Plugin(rule) => rule.fix(),
                 }
             }
 
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::documentation()),*
+                    #(Self::#struct_names(_) => #struct_names::documentation(),)*
+                    Self::Plugin(rule) => rule.documentation(),
                 }
             }
 
             pub fn plugin_name(&self) -> &'static str {
                 match self {
-                    #(Self::#struct_names(_) => #plugin_names),*
+                    #(Self::#struct_names(_) => #plugin_names,)*
+                    Self::Plugin(rule) => rule.plugin_name(),
+                }
+            }
+
+            /// JSON Schema of this rule's options. See [`Rule::schema`].
+            pub fn schema(
+                &self,
+                gen: &mut schemars::gen::SchemaGenerator,
+            ) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::schema(gen),)*
+                    Self::Plugin(_) => None,
                 }
             }
 
@@ -112,31 +143,36 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
                         #struct_names::from_configuration(value),
-                    )),*
+                    ),)*
+                    Self::Plugin(rule) => Self::Plugin(rule.read_json(value)),
                 }
             }
 
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run(node, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run(node, ctx),)*
+                    Self::Plugin(rule) => rule.run(node, ctx),
                 }
             }
 
             pub(super) fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx),)*
+                    Self::Plugin(rule) => rule.run_on_symbol(symbol_id, ctx),
                 }
             }
 
             pub(super) fn run_once<'a>(&self, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run_once(ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_once(ctx),)*
+                    Self::Plugin(rule) => rule.run_once(ctx),
                 }
             }
 
             pub(super) fn should_run(&self, ctx: &LintContext) -> bool {
                 match self {
-                    #(Self::#struct_names(rule) => rule.should_run(ctx)),*
+                    #(Self::#struct_names(rule) => rule.should_run(ctx),)*
+                    Self::Plugin(rule) => rule.should_run(ctx),
                 }
             }
         }
@@ -80,7 +80,7 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
     let import_statement = if used_in_test {
         None
     } else {
-        Some(quote! { use crate::{rule::{RuleCategory, RuleMeta, RuleFixMeta}, fixer::FixKind}; })
+        Some(quote! { use oxc_linter::{FixKind, RuleCategory, RuleFixMeta, RuleMeta}; })
     };
 
     let output = quote! {
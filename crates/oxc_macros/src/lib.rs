@@ -45,6 +45,10 @@ mod declare_oxc_lint;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// ## Usage outside of `oxc_linter`
+/// The generated code imports `RuleMeta` and friends from `oxc_linter`, so
+/// crates that provide rules through a `LintPlugin` can use this macro as well.
 /// # Example
 ///
 /// ```
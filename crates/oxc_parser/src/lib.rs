@@ -437,6 +437,7 @@ mod test {
     use std::path::Path;
 
     use oxc_ast::{ast::Expression, CommentKind};
+    use oxc_span::GetSpan;
 
     use super::*;
 
@@ -459,6 +460,30 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn preserve_parens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "((a + b)) * c";
+
+        let expr = Parser::new(&allocator, source, source_type).parse_expression().unwrap();
+        let Expression::BinaryExpression(binary) = expr else { panic!() };
+        let Expression::ParenthesizedExpression(outer) = &binary.left else { panic!() };
+        assert_eq!(outer.span, Span::new(0, 9));
+        let Expression::ParenthesizedExpression(inner) = &outer.expression else { panic!() };
+        assert_eq!(inner.span, Span::new(1, 8));
+        assert_eq!(inner.expression.span(), Span::new(2, 7));
+
+        let options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let expr = Parser::new(&allocator, source, source_type)
+            .with_options(options)
+            .parse_expression()
+            .unwrap();
+        let Expression::BinaryExpression(binary) = expr else { panic!() };
+        assert!(matches!(binary.left, Expression::BinaryExpression(_)));
+        assert_eq!(binary.left.span(), Span::new(2, 7));
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
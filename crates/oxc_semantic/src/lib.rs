@@ -15,6 +15,7 @@ mod label;
 mod module_record;
 mod node;
 mod reference;
+mod rename;
 mod scope;
 mod symbol;
 mod unresolved_stack;
@@ -28,7 +29,10 @@ pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 use class::ClassTable;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
 pub use node::{AstNode, AstNodeId, AstNodes};
-use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
+use oxc_ast::{
+    ast::{AssignmentOperator, IdentifierReference, MemberExpression},
+    AstKind, Trivias,
+};
 use oxc_cfg::ControlFlowGraph;
 use oxc_span::{GetSpan, SourceType, Span};
 pub use oxc_syntax::{
@@ -39,6 +43,7 @@ pub use oxc_syntax::{
 
 pub use crate::{
     reference::{Reference, ReferenceFlags, ReferenceId},
+    rename::{RenameConflict, RenameEdit},
     scope::ScopeTree,
     symbol::{IsGlobalReference, SymbolTable},
 };
@@ -179,6 +184,74 @@ impl<'a> Semantic<'a> {
         self.symbols.get_resolved_references(symbol_id)
     }
 
+    /// Count how often the member `member_name` of a symbol is read and written, e.g. the
+    /// property `x` of `obj` in `obj.x = 1` or `obj["x"]`.
+    ///
+    /// Only direct accesses through resolved references of the symbol are counted. Compound
+    /// assignments (`obj.x += 1`) and updates (`obj.x++`) count as both a read and a write.
+    /// Computed accesses are only counted when the key is a string literal or a template
+    /// literal without expressions.
+    pub fn symbol_member_usage(&self, symbol_id: SymbolId, member_name: &str) -> MemberUsage {
+        let mut usage = MemberUsage::default();
+        for reference in self.symbol_references(symbol_id) {
+            let Some((member, member_id)) = self.member_of_reference(reference) else {
+                continue;
+            };
+            if member.static_property_name() != Some(member_name) {
+                continue;
+            }
+            let (read, write) = self.member_access_kind(member_id);
+            usage.reads += usize::from(read);
+            usage.writes += usize::from(write);
+        }
+        usage
+    }
+
+    /// The member expression that `reference` is the object of, skipping parentheses.
+    fn member_of_reference(
+        &self,
+        reference: &Reference,
+    ) -> Option<(&'a MemberExpression<'a>, AstNodeId)> {
+        let span = self.reference_span(reference);
+        let parent = self
+            .nodes
+            .iter_parents(reference.node_id())
+            .skip(1)
+            .find(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)))?;
+        match parent.kind() {
+            AstKind::MemberExpression(member)
+                if member.object().without_parentheses().span() == span =>
+            {
+                Some((member, parent.id()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the member expression at `member_id` is read, written, or both.
+    fn member_access_kind(&self, member_id: AstNodeId) -> (bool, bool) {
+        let Some(AstKind::SimpleAssignmentTarget(_)) = self.nodes.parent_kind(member_id) else {
+            return (true, false);
+        };
+        let target_id = self.nodes.parent_id(member_id).unwrap();
+        match self.nodes.parent_kind(target_id) {
+            Some(AstKind::UpdateExpression(_)) => (true, true),
+            Some(AstKind::AssignmentTarget(_)) => {
+                let target_id = self.nodes.parent_id(target_id).unwrap();
+                match self.nodes.parent_kind(target_id) {
+                    Some(AstKind::AssignmentExpression(expr))
+                        if expr.operator != AssignmentOperator::Assign =>
+                    {
+                        (true, true)
+                    }
+                    _ => (false, true),
+                }
+            }
+            // Destructuring targets, e.g. `[obj.x] = arr`.
+            _ => (false, true),
+        }
+    }
+
     pub fn symbol_declaration(&self, symbol_id: SymbolId) -> &AstNode<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
@@ -201,6 +274,20 @@ impl<'a> Semantic<'a> {
     }
 }
 
+/// How often a member of a symbol is read and written. See [`Semantic::symbol_member_usage`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemberUsage {
+    pub reads: usize,
+    pub writes: usize,
+}
+
+impl MemberUsage {
+    /// The member is assigned to but its value is never read, e.g. a write-only setter.
+    pub fn is_write_only(&self) -> bool {
+        self.writes > 0 && self.reads == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use oxc_allocator::Allocator;
@@ -7,6 +7,12 @@ pub use oxc_syntax::node::{AstNodeId, NodeFlags};
 use crate::scope::ScopeId;
 
 /// Semantic node contains all the semantic information about an ast node.
+///
+/// Nodes are owned by [`AstNodes`], which lives as long as the [`Semantic`](crate::Semantic)
+/// it belongs to. The [`AstKind`] of a node points into the AST, so it lives as long as the
+/// arena the AST was allocated in (`'a`) and can outlive the node itself. A node does not
+/// know its parent; look it up by [`AstNodeId`] with [`AstNodes::parent_node`] or
+/// [`AstNodes::parent_kind`].
 #[derive(Debug, Clone, Copy)]
 pub struct AstNode<'a> {
     id: AstNodeId,
@@ -120,10 +126,12 @@ impl<'a> AstNodes<'a> {
         self.parent_ids[ast_node_id]
     }
 
+    #[inline]
     pub fn parent_kind(&self, ast_node_id: AstNodeId) -> Option<AstKind<'a>> {
         self.parent_id(ast_node_id).map(|node_id| self.kind(node_id))
     }
 
+    #[inline]
     pub fn parent_node(&self, ast_node_id: AstNodeId) -> Option<&AstNode<'a>> {
         self.parent_id(ast_node_id).map(|node_id| self.get_node(node_id))
     }
@@ -0,0 +1,308 @@
+use oxc_ast::{
+    ast::{AssignmentTargetProperty, BindingPattern, BindingPatternKind},
+    AstKind,
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{reference::ReferenceId, scope::ScopeId, symbol::SymbolId, AstNodeId, Semantic};
+
+/// A text edit that renames one occurrence of a symbol. See [`Semantic::rename_symbol`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameEdit {
+    /// The span of the identifier to replace.
+    pub span: Span,
+    /// The replacement text. This is the new name, except for shorthand properties and
+    /// module specifiers, which are expanded, e.g. `{ foo }` to `{ foo: bar }`.
+    pub replacement: String,
+}
+
+/// Why a symbol cannot be renamed. See [`Semantic::rename_symbol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameConflict {
+    /// The scope the symbol is declared in already has a binding with the new name.
+    Redeclaration(SymbolId),
+    /// A binding with the new name in a nested scope would shadow the renamed symbol at a
+    /// reference to it.
+    Shadowed { symbol_id: SymbolId, reference_id: ReferenceId },
+    /// A reference to another symbol with the new name, or to a global, would resolve to the
+    /// renamed symbol instead.
+    Captured(ReferenceId),
+}
+
+impl Semantic<'_> {
+    /// The edits that rename the symbol `symbol_id` to `new_name`: its declaration,
+    /// redeclarations and all resolved references.
+    ///
+    /// Shorthand properties are expanded to keep the property name, e.g. `{ foo }` becomes
+    /// `{ foo: bar }`, and so are shorthand import and export specifiers, e.g. `export { foo }`
+    /// becomes `export { bar as foo }`. Exported declarations such as `export let foo` change
+    /// the name they are exported as.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RenameConflict`] if the new name would change which symbol an identifier
+    /// refers to. `new_name` is not checked to be a valid identifier.
+    pub fn rename_symbol(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<RenameEdit>, RenameConflict> {
+        self.check_rename(symbol_id, new_name)?;
+        Ok(self.rename_edits(symbol_id, new_name))
+    }
+
+    fn check_rename(&self, symbol_id: SymbolId, new_name: &str) -> Result<(), RenameConflict> {
+        if self.symbols.get_name(symbol_id) == new_name {
+            return Ok(());
+        }
+        let scope_id = self.symbols.get_scope_id(symbol_id);
+        if let Some(other) = self.scopes.get_binding(scope_id, new_name) {
+            return Err(RenameConflict::Redeclaration(other));
+        }
+
+        for &reference_id in self.symbols.get_resolved_reference_ids(symbol_id) {
+            let reference = self.symbols.get_reference(reference_id);
+            let reference_scope_id = self.nodes.get_node(reference.node_id()).scope_id();
+            let shadowing = self
+                .scopes
+                .ancestors(reference_scope_id)
+                .take_while(|&ancestor| ancestor != scope_id)
+                .find_map(|ancestor| self.scopes.get_binding(ancestor, new_name));
+            if let Some(symbol_id) = shadowing {
+                return Err(RenameConflict::Shadowed { symbol_id, reference_id });
+            }
+        }
+
+        let resolved = self
+            .scopes
+            .iter_bindings()
+            .filter(|(_, _, name)| name.as_str() == new_name)
+            .flat_map(|(_, symbol_id, _)| self.symbols.get_resolved_reference_ids(symbol_id));
+        let unresolved =
+            self.scopes.root_unresolved_references().get(new_name).into_iter().flatten();
+        for &reference_id in resolved.chain(unresolved) {
+            let reference = self.symbols.get_reference(reference_id);
+            let target_scope_id = reference.symbol_id().map(|id| self.symbols.get_scope_id(id));
+            let reference_scope_id = self.nodes.get_node(reference.node_id()).scope_id();
+            if self.is_resolved_through(reference_scope_id, target_scope_id, scope_id) {
+                return Err(RenameConflict::Captured(reference_id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether resolving a reference in `reference_scope_id` to a binding in `target_scope_id`,
+    /// or to a global if `None`, passes through the scope `scope_id`.
+    fn is_resolved_through(
+        &self,
+        reference_scope_id: ScopeId,
+        target_scope_id: Option<ScopeId>,
+        scope_id: ScopeId,
+    ) -> bool {
+        for ancestor in self.scopes.ancestors(reference_scope_id) {
+            if Some(ancestor) == target_scope_id {
+                return false;
+            }
+            if ancestor == scope_id {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn rename_edits(&self, symbol_id: SymbolId, new_name: &str) -> Vec<RenameEdit> {
+        let name = self.symbols.get_name(symbol_id);
+        let span = self.symbols.get_span(symbol_id);
+        let mut edits = vec![self.declaration_edit(symbol_id, span, name, new_name)];
+        edits.extend(
+            self.symbols
+                .get_redeclarations(symbol_id)
+                .iter()
+                .filter(|&&redeclaration| redeclaration != span)
+                .map(|&span| RenameEdit { span, replacement: new_name.to_string() }),
+        );
+        edits.extend(
+            self.symbol_references(symbol_id)
+                .map(|reference| self.reference_edit(reference.node_id(), name, new_name)),
+        );
+        edits.sort_by_key(|edit| edit.span.start);
+        edits
+    }
+
+    fn declaration_edit(
+        &self,
+        symbol_id: SymbolId,
+        span: Span,
+        name: &str,
+        new_name: &str,
+    ) -> RenameEdit {
+        let pattern = match self.symbol_declaration(symbol_id).kind() {
+            AstKind::VariableDeclarator(decl) => Some(&decl.id),
+            AstKind::FormalParameter(param) => Some(&param.pattern),
+            AstKind::CatchParameter(param) => Some(&param.pattern),
+            // `import { foo }`
+            AstKind::ImportSpecifier(specifier) if specifier.imported.span() == span => {
+                return RenameEdit { span, replacement: format!("{name} as {new_name}") };
+            }
+            _ => None,
+        };
+        let replacement = if pattern.is_some_and(|pattern| is_shorthand_binding(pattern, span)) {
+            format!("{name}: {new_name}")
+        } else {
+            new_name.to_string()
+        };
+        RenameEdit { span, replacement }
+    }
+
+    fn reference_edit(&self, node_id: AstNodeId, name: &str, new_name: &str) -> RenameEdit {
+        let span = self.nodes.kind(node_id).span();
+        let replacement = match self.nodes.parent_kind(node_id) {
+            // `({ foo })`
+            Some(AstKind::ObjectProperty(property)) if property.shorthand => {
+                format!("{name}: {new_name}")
+            }
+            // `({ foo } = obj)`
+            Some(AstKind::ObjectAssignmentTarget(target))
+                if target.properties.iter().any(|property| {
+                    matches!(
+                        property,
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property)
+                            if property.binding.span == span
+                    )
+                }) =>
+            {
+                format!("{name}: {new_name}")
+            }
+            // `export { foo }`
+            Some(AstKind::ExportSpecifier(specifier)) if specifier.exported.span() == span => {
+                format!("{new_name} as {name}")
+            }
+            _ => new_name.to_string(),
+        };
+        RenameEdit { span, replacement }
+    }
+}
+
+/// Whether the binding identifier at `span` in `pattern` is the value of a shorthand
+/// property, e.g. `foo` in `const { foo = 1 } = obj`.
+fn is_shorthand_binding(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().any(|property| {
+                if property.shorthand && property.key.span() == span {
+                    return true;
+                }
+                is_shorthand_binding(&property.value, span)
+            }) || object
+                .rest
+                .as_ref()
+                .is_some_and(|rest| is_shorthand_binding(&rest.argument, span))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| is_shorthand_binding(element, span))
+                || array
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| is_shorthand_binding(&rest.argument, span))
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            is_shorthand_binding(&assignment.left, span)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SourceType;
+
+    use super::RenameConflict;
+    use crate::SemanticBuilder;
+
+    /// Rename the first symbol named `name` to `new_name` and apply the edits to `source`.
+    fn rename(source: &str, name: &str, new_name: &str) -> Result<String, RenameConflict> {
+        let allocator = Allocator::default();
+        let ret = oxc_parser::Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source).build(program).semantic;
+        let symbol_id = semantic
+            .symbols()
+            .symbol_ids()
+            .find(|&symbol_id| semantic.symbols().get_name(symbol_id) == name)
+            .unwrap();
+        let edits = semantic.rename_symbol(symbol_id, new_name)?;
+        let mut output = source.to_string();
+        for edit in edits.iter().rev() {
+            output
+                .replace_range(edit.span.start as usize..edit.span.end as usize, &edit.replacement);
+        }
+        Ok(output)
+    }
+
+    #[test]
+    fn references() {
+        assert_eq!(
+            rename("let foo = 1; foo++; function f() { return foo; }", "foo", "bar").unwrap(),
+            "let bar = 1; bar++; function f() { return bar; }"
+        );
+        assert_eq!(
+            rename("function foo(a) { return a; } foo(1);", "a", "b").unwrap(),
+            "function foo(b) { return b; } foo(1);"
+        );
+        assert_eq!(rename("var foo; var foo = 1;", "foo", "bar").unwrap(), "var bar; var bar = 1;");
+        assert_eq!(rename("let foo = 1;", "foo", "foo").unwrap(), "let foo = 1;");
+    }
+
+    #[test]
+    fn shorthand() {
+        assert_eq!(
+            rename("let foo = 1; let obj = { foo };", "foo", "bar").unwrap(),
+            "let bar = 1; let obj = { foo: bar };"
+        );
+        assert_eq!(
+            rename("let { foo = 1, a: [{ foo: b }] } = obj; foo;", "foo", "bar").unwrap(),
+            "let { foo: bar = 1, a: [{ foo: b }] } = obj; bar;"
+        );
+        assert_eq!(
+            rename("function f({ foo }) { return foo; }", "foo", "bar").unwrap(),
+            "function f({ foo: bar }) { return bar; }"
+        );
+        assert_eq!(
+            rename("let foo; ({ foo = 1 } = obj);", "foo", "bar").unwrap(),
+            "let bar; ({ foo: bar = 1 } = obj);"
+        );
+        assert_eq!(
+            rename("import { foo } from 'mod'; foo();", "foo", "bar").unwrap(),
+            "import { foo as bar } from 'mod'; bar();"
+        );
+        assert_eq!(
+            rename("let foo = 1; export { foo };", "foo", "bar").unwrap(),
+            "let bar = 1; export { bar as foo };"
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        assert!(matches!(
+            rename("let foo, bar;", "foo", "bar"),
+            Err(RenameConflict::Redeclaration(_))
+        ));
+        assert!(matches!(
+            rename("let foo; function f() { let bar; foo; }", "foo", "bar"),
+            Err(RenameConflict::Shadowed { .. })
+        ));
+        assert!(matches!(
+            rename("let bar; function f() { let foo; bar; }", "foo", "bar"),
+            Err(RenameConflict::Captured(_))
+        ));
+        assert!(matches!(
+            rename("function f() { let foo; console.log(foo); }", "foo", "console"),
+            Err(RenameConflict::Captured(_))
+        ));
+        assert!(rename("function f() { let foo; } function g() { let bar; bar; }", "foo", "bar")
+            .is_ok());
+        assert!(rename("let bar; function f() { let foo; } bar;", "foo", "bar").is_ok());
+    }
+}
@@ -449,3 +449,41 @@ fn test_tagged_templates() {
     .has_number_of_writes(0)
     .test();
 }
+
+#[test]
+fn test_member_usage() {
+    SemanticTester::js("const obj = {}; obj.x = 1; obj.x = 2;")
+        .has_root_symbol("obj")
+        .has_member_usage("x", 0, 2)
+        .test();
+
+    SemanticTester::js("const obj = {}; obj['x'] = 1; console.log(obj[\"x\"], obj[`x`]);")
+        .has_root_symbol("obj")
+        .has_member_usage("x", 2, 1)
+        .test();
+
+    SemanticTester::js("const obj = {}; obj.x += 1; obj['x']++; (obj).x;")
+        .has_root_symbol("obj")
+        .has_member_usage("x", 3, 2)
+        .test();
+
+    SemanticTester::js("const obj = {}; [obj.x] = [1]; ({ a: obj['x'] } = {}); for (obj.x of []);")
+        .has_root_symbol("obj")
+        .has_member_usage("x", 0, 3)
+        .test();
+
+    // Setting a static setter is a write, reading a nested member reads `x`.
+    SemanticTester::js("class Foo { static set x(v) {} } Foo.x = 1; Foo.x.y = 2;")
+        .has_root_symbol("Foo")
+        .has_member_usage("x", 1, 1)
+        .test();
+
+    // Other members, dynamic keys, and uses of `obj` as a key are not counted.
+    SemanticTester::js(
+        "const obj = {}, key = 'x'; obj.y = 1; obj[key] = 1; other[obj] = 1; other.x = obj;",
+    )
+    .has_root_symbol("obj")
+    .has_member_usage("x", 0, 0)
+    .has_member_usage("y", 0, 1)
+    .test();
+}
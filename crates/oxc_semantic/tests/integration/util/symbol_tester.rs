@@ -203,6 +203,27 @@ impl<'a> SymbolTester<'a> {
         self
     }
 
+    /// Check how often the member `member_name` of this symbol is read and written.
+    ///
+    /// See [`Semantic::symbol_member_usage`].
+    pub fn has_member_usage(mut self, member_name: &str, reads: usize, writes: usize) -> Self {
+        self.test_result = match self.test_result {
+            Ok(symbol_id) => {
+                let usage = self.semantic.symbol_member_usage(symbol_id, member_name);
+                if usage.reads == reads && usage.writes == writes {
+                    Ok(symbol_id)
+                } else {
+                    Err(OxcDiagnostic::error(format!(
+                        "Expected {}.{member_name} to have {reads} reads and {writes} writes, but found {} reads and {} writes",
+                        self.target_symbol_name, usage.reads, usage.writes
+                    )))
+                }
+            }
+            e => e,
+        };
+        self
+    }
+
     /// Check that this symbol is exported.
     ///
     /// Export status is checked using the symbol's [`SymbolFlags`], not by
@@ -1,8 +1,10 @@
-use std::{env, path::Path, rc::Rc};
+use std::{env, fs, path::Path, rc::Rc, sync::mpsc};
 
 use oxc_allocator::Allocator;
-use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use oxc_linter::{AllowWarnDeny, FixKind, LintFilter, Linter, OxlintOptions};
+use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use oxc_linter::{
+    AllowWarnDeny, FixKind, LintFilter, LintService, LintServiceOptions, Linter, OxlintOptions,
+};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -57,5 +59,113 @@ fn bench_linter(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(linter, bench_linter);
+/// A fixed set of rules, so that results stay comparable when rules are added to the linter.
+const PIPELINE_RULES: [&str; 12] = [
+    "getter-return",
+    "no-else-return",
+    "no-fallthrough",
+    "no-param-reassign",
+    "no-self-assign",
+    "no-sequences",
+    "no-unsafe-optional-chaining",
+    "no-unused-vars",
+    "no-useless-escape",
+    "no-useless-rename",
+    "prefer-const",
+    "prefer-template",
+];
+
+fn lint_options(rules: &[&str]) -> OxlintOptions {
+    let filter = std::iter::once(LintFilter::new(AllowWarnDeny::Allow, "all").unwrap())
+        .chain(rules.iter().map(|rule| LintFilter::new(AllowWarnDeny::Deny, *rule).unwrap()))
+        .collect();
+    OxlintOptions::default().with_filter(filter)
+}
+
+/// Parse, semantically analyze and lint each test file with [`PIPELINE_RULES`], reporting
+/// throughput in bytes of source text.
+fn bench_lint_pipeline(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("lint_pipeline");
+    let linter = Linter::from_options(lint_options(&PIPELINE_RULES)).unwrap();
+    for file in TestFiles::complicated().files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        group.throughput(Throughput::Bytes(file.source_text.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&file.file_name),
+            &file.source_text,
+            |b, source_text| {
+                b.iter(|| {
+                    let allocator = Allocator::default();
+                    let ret = Parser::new(&allocator, source_text, source_type).parse();
+                    let program = allocator.alloc(ret.program);
+                    let semantic_ret = SemanticBuilder::new(source_text)
+                        .with_trivias(ret.trivias)
+                        .with_cfg(true)
+                        .build_module_record(Path::new(""), program)
+                        .build(program);
+                    linter.run(Path::new(""), Rc::new(semantic_ret.semantic)).len()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Lint each test file with `prefer-const` only, to track the cost of a single rule.
+fn bench_prefer_const(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("prefer_const");
+    let linter = Linter::from_options(lint_options(&["prefer-const"])).unwrap();
+    for file in TestFiles::complicated().files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &file.source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(&file.source_text)
+            .with_trivias(ret.trivias)
+            .with_cfg(true)
+            .build_module_record(Path::new(""), program)
+            .build(program);
+        let semantic = Rc::new(semantic_ret.semantic);
+        group.throughput(Throughput::Bytes(file.source_text.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(&file.file_name), |b| {
+            b.iter(|| linter.run(Path::new(""), Rc::clone(&semantic)));
+        });
+    }
+    group.finish();
+}
+
+/// Lint all test files through `LintService`, in parallel and one file at a time.
+fn bench_lint_service(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("lint_service");
+
+    let dir = env::temp_dir().join("oxc_benchmark_lint_service");
+    fs::create_dir_all(&dir).unwrap();
+    let paths = TestFiles::complicated()
+        .files()
+        .iter()
+        .map(|file| {
+            let path = dir.join(&file.file_name);
+            fs::write(&path, &file.source_text).unwrap();
+            path.into_boxed_path()
+        })
+        .collect::<Vec<_>>();
+
+    for (name, max_in_flight) in [("parallel", None), ("sequential", Some(1))] {
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                let mut options = LintServiceOptions::new(dir.clone(), paths.clone());
+                if let Some(max_in_flight) = max_in_flight {
+                    options = options.with_max_in_flight(max_in_flight);
+                }
+                let service = LintService::new(Linter::default(), options);
+                let (tx_error, rx_error) = mpsc::channel();
+                service.run(&tx_error);
+                rx_error.try_iter().count()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(linter, bench_linter, bench_lint_pipeline, bench_prefer_const, bench_lint_service);
 criterion_main!(linter);